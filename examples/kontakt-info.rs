@@ -43,11 +43,19 @@ fn print_chunk(chunk: &Chunk, indent: usize) -> Result<(), Report> {
             }
         }
         KontaktObject::BParScript(script) => {
-            println!(
+            let params = script.params()?;
+            print!(
                 "BParScript v{:X} {}",
                 script.0.version,
-                script.params()?.description.unwrap_or_default()
+                params.description.unwrap_or_default()
             );
+            if !params.password_hash.is_empty() {
+                print!(" (password protected)");
+            }
+            match params.textfile_name {
+                Some(textfile) => println!(" -> {textfile}"),
+                None => println!(),
+            }
         }
         KontaktObject::BParFXSendLevel(fx) => {
             println!("BParFXSendLevel {:?}", fx);
@@ -3,7 +3,7 @@
 use std::fs::File;
 
 use color_eyre::eyre::{Report, Result};
-use ni_file::NIFile;
+use ni_file::{kontakt::KontaktInstrument, NIFile};
 
 pub fn main() -> Result<(), Report> {
     color_eyre::install()?;
@@ -14,9 +14,24 @@ pub fn main() -> Result<(), Report> {
     };
 
     let file = File::open(&path)?;
-    let _ni = NIFile::read(file)?;
+    let ni = NIFile::read(file)?;
+    let instrument = KontaktInstrument::try_from(&ni)?;
 
-    // .. loop zone data
+    for zone in &instrument.zones {
+        println!(
+            "{:<24} keys {:>3}-{:<3} vel {:>3}-{:<3} root {:>3} {}",
+            instrument.groups[zone.group].name,
+            zone.low_key,
+            zone.high_key,
+            zone.low_velocity,
+            zone.high_velocity,
+            zone.root_key,
+            zone.sample
+                .as_ref()
+                .map(|sample| sample.to_string())
+                .unwrap_or_else(|| String::from("<missing>"))
+        );
+    }
 
     Ok(())
 }
@@ -0,0 +1,41 @@
+// An example which dumps the params of a Kontakt instrument as JSON, or
+// writes edited params back into the instrument.
+//
+// cargo run --features serde --example kontakt-params -- instrument.nki > params.json
+// cargo run --features serde --example kontakt-params -- instrument.nki params.json out.nki
+
+use std::{fs::File, io::Cursor};
+
+use color_eyre::eyre::{Report, Result};
+use ni_file::{
+    kontakt::{InstrumentParams, KontaktChunks},
+    NIFile,
+};
+
+pub fn main() -> Result<(), Report> {
+    color_eyre::install()?;
+
+    let Some(input) = std::env::args().nth(1) else {
+        println!("usage: kontakt-params <FILE> [<PARAMS.json> <OUTPUT>]");
+        return Ok(());
+    };
+
+    let mut file = NIFile::read(File::open(&input)?)?;
+    let mut chunks = KontaktChunks::read(Cursor::new(file.inner_preset()?))?;
+
+    let (Some(json), Some(output)) = (std::env::args().nth(2), std::env::args().nth(3)) else {
+        println!("{}", serde_json::to_string_pretty(&chunks.params()?)?);
+        return Ok(());
+    };
+
+    let params: InstrumentParams = serde_json::from_str(&std::fs::read_to_string(&json)?)?;
+    chunks.set_params(&params)?;
+
+    let mut data = Vec::new();
+    chunks.write(&mut data)?;
+    file.set_inner_preset(&data)?;
+    file.write(File::create(&output)?)?;
+
+    println!("wrote {json} to {output}");
+    Ok(())
+}
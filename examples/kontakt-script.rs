@@ -0,0 +1,47 @@
+// An example which replaces a KSP script of a Kontakt instrument.
+//
+// Without a slot, the script is added to the first empty slot. The title of
+// the slot is kept, new slots are titled after the script file.
+
+use std::{fs::File, io::Cursor, path::Path};
+
+use color_eyre::eyre::{Report, Result};
+use ni_file::{kontakt::KontaktChunks, NIFile};
+
+pub fn main() -> Result<(), Report> {
+    color_eyre::install()?;
+
+    let (Some(input), Some(script), Some(output)) = (
+        std::env::args().nth(1),
+        std::env::args().nth(2),
+        std::env::args().nth(3),
+    ) else {
+        println!("usage: kontakt-script <FILE> <SCRIPT.ksp> <OUTPUT> [SLOT]");
+        return Ok(());
+    };
+
+    let text = std::fs::read_to_string(&script)?;
+    let title = Path::new(&script)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string());
+
+    let mut file = NIFile::read(File::open(&input)?)?;
+    let mut chunks = KontaktChunks::read(Cursor::new(file.inner_preset()?))?;
+
+    let slot = match std::env::args().nth(4) {
+        Some(slot) => {
+            let slot = slot.parse::<usize>()?;
+            chunks.set_script(slot, &text, None)?;
+            slot
+        }
+        None => chunks.add_script(&text, title.as_deref())?,
+    };
+
+    let mut data = Vec::new();
+    chunks.write(&mut data)?;
+    file.set_inner_preset(&data)?;
+    file.write(File::create(&output)?)?;
+
+    println!("wrote {script} to slot {} of {output}", slot + 1);
+    Ok(())
+}
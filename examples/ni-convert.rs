@@ -0,0 +1,65 @@
+// An example which converts Kontakt instruments to open formats.
+//
+// The output format is chosen by the extension of the output file. For
+// DecentSampler presets, the wallpaper is copied from an optional resource
+// container (nkr), or from next to the instrument. SoundFonts embed the samples, which are read relative to
+// the instrument, as do Bitwig multisamples. CSV and JSON files contain the
+// zone map.
+
+use std::{fs::File, path::Path};
+
+use color_eyre::eyre::{eyre, Report, Result};
+use ni_file::{kontakt::KontaktInstrument, nkr::NKRContainer, NIFile};
+
+pub fn main() -> Result<(), Report> {
+    color_eyre::install()?;
+
+    let (Some(input), Some(output)) = (std::env::args().nth(1), std::env::args().nth(2)) else {
+        println!(
+            "usage: ni-convert <FILE> <OUTPUT> [RESOURCES.nkr]\n\nformats: sfz, dspreset, sf2, multisample, csv, json"
+        );
+        return Ok(());
+    };
+
+    let file = File::open(&input)?;
+    let ni = NIFile::read(file)?;
+    let instrument = KontaktInstrument::try_from(&ni)?;
+
+    let instrument_dir = Path::new(&input).parent().unwrap_or(Path::new("."));
+    let output_path = Path::new(&output);
+    let extension = output_path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase());
+
+    match extension.as_deref() {
+        Some("sfz") => std::fs::write(&output, instrument.to_sfz())?,
+        Some("dspreset") => {
+            let mut wallpaper = None;
+            if let Some(source) = &instrument.metadata.wallpaper {
+                let data = match std::env::args().nth(3) {
+                    Some(resources) => NKRContainer::read(File::open(resources)?)?
+                        .wallpaper(source)
+                        .and_then(|file| file.data.clone()),
+                    None => std::fs::read(source.to_path(instrument_dir)).ok(),
+                };
+                if let Some(data) = data {
+                    let path = format!("Resources/{}", source.file_name().unwrap_or_default());
+                    let target = output_path.with_file_name(&path);
+                    std::fs::create_dir_all(target.parent().unwrap())?;
+                    std::fs::write(&target, data)?;
+                    println!("wrote {}", target.display());
+                    wallpaper = Some(path);
+                }
+            }
+            std::fs::write(&output, instrument.to_dspreset(wallpaper.as_deref()))?
+        }
+        Some("sf2") => std::fs::write(&output, instrument.to_sf2(instrument_dir)?)?,
+        Some("multisample") => std::fs::write(&output, instrument.to_multisample(instrument_dir)?)?,
+        Some("csv") => std::fs::write(&output, instrument.to_zone_map_csv())?,
+        Some("json") => std::fs::write(&output, instrument.to_zone_map_json())?,
+        _ => return Err(eyre!("Unsupported output format: {output}")),
+    }
+
+    println!("wrote {output}");
+    Ok(())
+}
@@ -1,26 +1,48 @@
 //
 //  Extract raw InternalPresetData from an NISD container.
 //
+//  Monolith items and ncw files are extracted as wav, with the loop, root
+//  key and mapping of the instrument embedded in `smpl` and `inst` chunks.
+//  With `--scripts`, the KSP scripts of instruments are written to a
+//  `Scripts` directory.
+//
 
-use std::{
-    fs::File,
-    io::{Read, Seek, SeekFrom, Write},
-};
+use std::{fs::File, io::Cursor, path::Path};
 
 use color_eyre::eyre::Result;
-use ni_file::{kontakt::objects::BPatchHeader, nis::schema::Repository, NIFile};
+use ni_file::{
+    kontakt::{export::SampleData, objects::BPatchHeader, KontaktInstrument},
+    nis::schema::Repository,
+    NIFile,
+};
 
 pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     std::env::set_var("RUST_BACKTRACE", "1");
     color_eyre::install()?;
 
     let Some(path) = std::env::args().nth(1) else {
-        println!("usage: ni-extract <FILE>");
+        println!("usage: ni-extract <FILE> [--scripts]");
         return Ok(());
     };
 
     let file = File::open(&path)?;
 
+    if std::env::args().skip(2).any(|arg| arg == "--scripts") {
+        let instrument = KontaktInstrument::try_from(&NIFile::read(File::open(&path)?)?)?;
+        for slot in instrument.extract_scripts(Path::new("Scripts"))? {
+            match &slot.text {
+                Some(_) => println!("Writing: Scripts/{}", slot.file_name()),
+                None => println!("Skipping: {} (no source)", slot.file_name()),
+            }
+            if slot.password_protected {
+                println!("  password protected");
+            }
+            if let Some(textfile) = &slot.textfile_name {
+                println!("  linked to {textfile}");
+            }
+        }
+    }
+
     match NIFile::read(file)? {
         NIFile::NISoundContainer(container) => {
             println!("Detected format: NISound Container\n");
@@ -43,20 +65,42 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Detected format:\t\tMonolith (FileContainer Archive)\n");
 
             let mut file = File::open(&path)?;
-            for item in container.items {
-                println!("Writing: {}", &item.filename);
 
-                file.seek(SeekFrom::Start(
-                    item.file_start_offset + container.file_section_offset,
-                ))?;
-                let mut output = File::create(&item.filename)?;
+            // the instrument of the monolith, for the sample mappings
+            let instrument = container
+                .items
+                .iter()
+                .find(|item| item.filename.to_lowercase().ends_with(".nki"))
+                .and_then(|item| container.read_item(&mut file, item).ok())
+                .and_then(|data| NIFile::read(Cursor::new(data)).ok())
+                .and_then(|ni| KontaktInstrument::try_from(&ni).ok());
+
+            for item in &container.items {
+                let data = container.read_item(&mut file, item)?;
 
-                let mut buf = vec![0u8; item.file_size as usize];
-                file.read_exact(&mut buf)?;
+                if item.filename.to_lowercase().ends_with(".ncw") {
+                    let filename = Path::new(&item.filename).with_extension("wav");
+                    println!("Writing: {}", filename.display());
 
-                output.write_all(&buf)?;
+                    let mapping = instrument
+                        .as_ref()
+                        .and_then(|i| i.sample_mapping(&item.filename));
+                    SampleData::read_ncw(Cursor::new(data))?
+                        .write_wav(File::create(filename)?, mapping.as_ref())?;
+                } else {
+                    println!("Writing: {}", &item.filename);
+                    std::fs::write(&item.filename, data)?;
+                }
             }
         }
+        NIFile::NICompressedWave => {
+            println!("Detected format: NI Compressed Wave\n");
+
+            let filename = Path::new(&path).with_extension("wav");
+            let filename = filename.file_name().unwrap();
+            SampleData::open(Path::new(&path))?.write_wav(File::create(filename)?, None)?;
+            println!("write {}", filename.to_string_lossy());
+        }
         NIFile::NKSContainer(nks) => {
             println!("Detected format: Kontakt Container\n");
             match nks.header {
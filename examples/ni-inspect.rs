@@ -11,7 +11,7 @@ use ni_file::{
     nifile::NIFile,
     nis::{
         schema::{NISObject, Repository},
-        Preset,
+        Preset, SoundInfo,
     },
 };
 
@@ -58,6 +58,10 @@ pub fn main() -> Result<(), Report> {
                     println!("\nEncryptionItem");
                     println!("  is_encrypted: {:?}", encryption_item.is_encrypted);
 
+                    if let Ok(item) = preset.sound_info_item() {
+                        print_sound_info(&item.sound_info);
+                    }
+
                     if !encryption_item.is_encrypted {
                         print_kontakt_patch(preset.patch()?)?;
                     }
@@ -74,8 +78,12 @@ pub fn main() -> Result<(), Report> {
                 _ => todo!(),
             };
         }
-        NIFile::KontaktResource => {
-            println!("Detected format:\tKontaktResource");
+        NIFile::KontaktResource(nkr) => {
+            println!("Detected format:\tKontaktResource\n");
+            println!("Files:");
+            for file in nkr.files {
+                println!("  {}", file.path);
+            }
         }
         NIFile::NICache => {
             println!("Detected format:\tNICache");
@@ -99,6 +107,9 @@ pub fn main() -> Result<(), Report> {
         NIFile::NKSContainer(nks) => {
             println!("Detected format:\tNKS (Native Instruments Kontakt Sound) Container");
             print_kontakt_header(&nks.header);
+            if let Some(Ok(info)) = nks.meta_info.as_ref().map(|m| m.sound_info()) {
+                print_sound_info(&info);
+            }
             print_kontakt_preset(&nks.preset()?)?;
         }
         NIFile::FM8Preset => {
@@ -145,6 +156,15 @@ fn print_preset_properties(preset: Preset) {
     println!("  is_factory_preset:\t{}", preset.is_factory_preset);
 }
 
+fn print_sound_info(info: &SoundInfo) {
+    println!("\nSoundInfo:");
+    println!("  name:\t\t\t{}", info.name);
+    println!("  author:\t\t{}", info.author);
+    println!("  vendor:\t\t{}", info.vendor);
+    println!("  bank_chain:\t\t{}", info.bank_chain.join(" / "));
+    println!("  attributes:\t\t{}", info.attributes.join(", "));
+}
+
 fn print_filetable(ft: &FNTableImpl) {
     println!("\nFNTableImpl:");
 
@@ -273,6 +293,14 @@ fn print_kontakt_preset(preset: &KontaktPreset) -> Result<()> {
             print_kontakt_program(&p.program)?;
             print_filetable(&p.filetable);
         }
+        KontaktPreset::Kon5PreK51(p) => {
+            print_kontakt_program(&p.program)?;
+
+            println!("\nFileNameListPreK51:");
+            for path in p.filetable.sample_filetable.values() {
+                println!("  {path}");
+            }
+        }
         KontaktPreset::Kon6(p) => {
             print_kontakt_program(&p.program)?;
             print_filetable(&p.filetable);
@@ -2,6 +2,7 @@ use crate::{nis::ItemContainer, nks::detect::NKSFileType, read_bytes::ReadBytesE
 
 /// Supported NI filetypes.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NIFileType {
     /// Kontakt Sound Container
     NKSContainer(NKSFileType),
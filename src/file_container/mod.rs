@@ -1,16 +1,20 @@
 use crate::read_bytes::ReadBytesExt;
 use crate::string_reader::StringReader;
-use crate::Error;
+use crate::{Error, NIFile};
 
 const FC_TOC_MARKER_END: u64 = 0xF1F1F1F1F1F1F1F1;
 const FC_MTD_MARKER_START: &[u8; 16] = b"/\\ NI FC MTD  /\\";
 
 /// Kontakt archive that bundles a preset, samples and other files.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NIFileContainer {
     pub file_section_offset: u64,
     pub items: Vec<FileContainerItem>,
+    /// Contents of the preset item (`patch.nki`, `patch.nkm`, ...), if any.
+    pub preset_data: Option<Vec<u8>>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FileContainerItem {
     pub index: u64,
     pub filename: String,
@@ -18,6 +22,16 @@ pub struct FileContainerItem {
     pub file_size: u64,
 }
 
+impl FileContainerItem {
+    /// Whether this item is the Kontakt preset of the monolith.
+    pub fn is_preset(&self) -> bool {
+        let name = self.filename.to_lowercase();
+        [".nki", ".nkm", ".nkb", ".nkp"]
+            .iter()
+            .any(|ext| name.ends_with(ext))
+    }
+}
+
 impl NIFileContainer {
     pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
         // NI FC MTD
@@ -80,10 +94,36 @@ impl NIFileContainer {
 
         let file_section_offset = reader.stream_position()?;
 
-        Ok(Self {
+        let mut container = Self {
             file_section_offset,
             items,
-        })
+            preset_data: None,
+        };
+        if let Some(item) = container.items.iter().find(|item| item.is_preset()) {
+            container.preset_data = Some(container.read_item(&mut reader, item)?);
+        }
+        Ok(container)
+    }
+
+    /// Reads the preset stored in the monolith, usually a NISound container.
+    pub fn preset(&self) -> Result<NIFile, Error> {
+        let data = self
+            .preset_data
+            .as_ref()
+            .ok_or(Error::Static("Monolith does not contain a preset"))?;
+        NIFile::read(std::io::Cursor::new(data))
+    }
+
+    /// Reads the contents of an item from the container.
+    pub fn read_item<R: ReadBytesExt>(
+        &self,
+        mut reader: R,
+        item: &FileContainerItem,
+    ) -> Result<Vec<u8>, Error> {
+        reader.seek(std::io::SeekFrom::Start(
+            self.file_section_offset + item.file_start_offset,
+        ))?;
+        Ok(reader.read_bytes(item.file_size as usize)?)
     }
 }
 
@@ -95,8 +135,16 @@ mod tests {
 
     #[test]
     fn test_filecontainer_nki() -> Result<(), Error> {
-        let file = File::open("tests/data/Containers/FileContainer/files/000-default.nki")?;
-        NIFileContainer::read(file)?;
+        let mut file = File::open("tests/data/Containers/FileContainer/files/000-default.nki")?;
+        let container = NIFileContainer::read(&mut file)?;
+
+        let item = &container.items[0];
+        let data = container.read_item(&mut file, item)?;
+        assert_eq!(data.len() as u64, item.file_size);
+
+        assert!(item.is_preset());
+        assert_eq!(container.preset_data.as_ref(), Some(&data));
+        assert!(matches!(container.preset()?, NIFile::NISoundContainer(_)));
         Ok(())
     }
 
@@ -1,5 +1,6 @@
 use crate::{read_bytes::ReadBytesExt, Error};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FM8EffectSettings;
 
 impl FM8EffectSettings {
@@ -1,6 +1,7 @@
 use crate::{read_bytes::ReadBytesExt, Error};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FM8Matrix;
 
 impl FM8Matrix {
@@ -13,6 +13,7 @@ use crate::{
 const FM8_MAGIC: &[u8; 4] = b"FM8E";
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FM8Preset;
 
 impl FM8Preset {
@@ -1,18 +1,26 @@
-use crate::{read_bytes::ReadBytesExt, Error};
+use crate::{read_bytes::ReadBytesExt, write_bytes::WriteBytesExt, Error};
 
 use super::{
     objects::{
-        BParFX, BParFXSendLevels, BParScript, BParamArrayBParFX8, Bank, FNTableImpl, FXDelay,
-        FileNameListPreK51, GroupList, InsertBus, InternalMod, InternalModArray16, LoopArray,
-        PrivateRawObject, Program, ProgramContainer, ProgramList, QuickBrowseData, SaveSettings,
-        SlotList, StartCriteriaList, VoiceGroup, VoiceGroups, ZoneList,
+        Arpeggiator, BAutomationObject, BDyxMorphGroup, BDyxMorphMap, BGroupSnapshot,
+        BOutputConfiguration, BParFX, BParFXRotator, BParFXSendLevels, BParScript, BParSrcMode,
+        BParamArrayBParFX8, BSnapshot, BSnapshotMetaData, Bank, Envelope, EnvelopeAHDSR,
+        ExternalMod, ExternalModArray32, FNTableImpl, FXACBox, FXCabinet, FXCat, FXChorus,
+        FXCompressor, FXCryWah, FXDStortion, FXDelay, FXDistortion, FXFilter, FXGainer, FXHotSolo,
+        FXLimiter, FXLofi, FXPhaser, FXPhasis, FXPlateReverb, FXSSLGBusComp, FXSkreamer,
+        FXStereoSpread, FileNameListPreK51, Glide, Group, GroupList, InsertBus, InternalMod,
+        InternalModArray16, Loop, LoopArray, OutputPartition, PrivateRawObject, Program,
+        ProgramContainer, ProgramList, QuickBrowseData, SaveSettings, SlotList, StartCriteria,
+        StartCriteriaList, VoiceGroup, VoiceGroups, Zone, ZoneList, FXSSLGEQ, LFO,
     },
     structured_object::StructuredObject,
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chunk {
     pub id: u16,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub data: Vec<u8>,
 }
 
@@ -24,63 +32,69 @@ impl Chunk {
         Ok(Self { id, data })
     }
 
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_u16_le(self.id)?;
+        writer.write_u32_le(self.data.len() as u32)?;
+        writer.write_bytes(&self.data)?;
+        Ok(())
+    }
+
     pub fn into_object(&self) -> Result<KontaktObject, Error> {
         Ok(KontaktObject::try_from(self)?)
     }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KontaktObject {
-    BParModBase,
-    BAutomationObject,
-    OutputPartition,
+    BParModBase(StructuredObject),
+    BAutomationObject(BAutomationObject),
+    OutputPartition(OutputPartition),
     Bank(Bank),
-    BGroup,
-    BLoop,
+    BGroup(Group),
+    BLoop(Loop),
     BParScript(BParScript),
-    BParEnv,
-    BParLFO,
-    BParArp,
-    BParEnvF,
-    BParGlide,
-    BParExternalMod,
+    BParEnv(Envelope),
+    BParLFO(LFO),
+    BParArp(Arpeggiator),
+    BParEnvF(StructuredObject),
+    BParGlide(Glide),
+    BParExternalMod(ExternalMod),
     BParInternalMod(InternalMod),
-    BParSrcMode,
-    BParStartCriteria,
+    BParSrcMode(BParSrcMode),
+    BParStartCriteria(StartCriteria),
     BParFXDelay(FXDelay),
-    BParFXChorus,
-    BParFXFlanger,
-    BParFXGainer,
-    BParFXPhaser,
-    BParFXReverb,
-    BParFXIRC,
+    BParFXChorus(FXChorus),
+    BParFXFlanger(StructuredObject),
+    BParFXGainer(FXGainer),
+    BParFXPhaser(FXPhaser),
+    BParFXReverb(StructuredObject),
+    BParFXIRC(StructuredObject),
     BParFXSendLevel(BParFXSendLevels),
-    BParFXFilter,
-    BParFXCompressor,
-    BParFXInverter,
-    BParFXDYX,
-    BParFXLimiter,
-    BParFXSurroundPanner,
-    BParFXShaper,
-    BParFXDistortion,
-    BParFXStereoSpread,
-    BParFXLofi,
-    BParFXSkreamer,
-    BParFXRotator,
-    BParFXTwang,
-    BParFXCabinet,
+    BParFXFilter(FXFilter),
+    BParFXCompressor(FXCompressor),
+    BParFXInverter(StructuredObject),
+    BParFXDYX(StructuredObject),
+    BParFXLimiter(FXLimiter),
+    BParFXSurroundPanner(StructuredObject),
+    BParFXDistortion(FXDistortion),
+    BParFXStereoSpread(FXStereoSpread),
+    BParFXLofi(FXLofi),
+    BParFXSkreamer(FXSkreamer),
+    BParFXRotator(BParFXRotator),
+    BParFXTwang(StructuredObject),
+    BParFXCabinet(FXCabinet),
     BParFX(BParFX),
-    BDyxMorphGroup,
-    BDyxMorphMap,
-    BProgram,
+    BDyxMorphGroup(BDyxMorphGroup),
+    BDyxMorphMap(BDyxMorphMap),
     BProgramContainer(ProgramContainer),
-    BSample,
+    BSample(StructuredObject),
     VoiceGroup(VoiceGroup),
-    BZone,
-    BZoneLevelEnv,
-    BZoneArraySer,
-    BGroupCompleteSer,
-    PresetImpl,
+    BZone(Zone),
+    BZoneLevelEnv(StructuredObject),
+    BZoneArraySer(StructuredObject),
+    BGroupCompleteSer(StructuredObject),
+    PresetImpl(StructuredObject),
     VoiceGroups(VoiceGroups),
     GroupList(GroupList),
     ZoneList(ZoneList),
@@ -91,108 +105,122 @@ pub enum KontaktObject {
     LoopArray(LoopArray),
     BParameterArraySerBParFX8(BParamArrayBParFX8),
     BParameterArraySerBParInternalMod16(InternalModArray16),
-    BParameterArraySerBParExternalMod32,
-    BOutputConfiguration,
+    BParameterArraySerBParExternalMod32(ExternalModArray32),
+    BOutputConfiguration(BOutputConfiguration),
     FileNameListPreK51(FileNameListPreK51),
-    FNTablePreK51,
-    BParEnvAhdsr,
-    BParEnvFm7,
-    BParEnvDbd,
-    BParFXTape,
-    BParFXTrans,
-    BParFXSSLGEQ,
+    BParEnvAhdsr(EnvelopeAHDSR),
+    BParEnvFm7(StructuredObject),
+    BParEnvDbd(StructuredObject),
+    BParFXTape(StructuredObject),
+    BParFXTrans(StructuredObject),
+    BParFXSSLGEQ(FXSSLGEQ),
     BInsertBus(InsertBus),
-    BParFXSSLGBusComp,
+    BParFXSSLGBusComp(FXSSLGBusComp),
     SaveSettings(SaveSettings),
-    BParGroupDynamics,
+    BParGroupDynamics(StructuredObject),
     FNTableImpl(FNTableImpl),
-    FileNameList,
-    BParFXFBComp,
-    BParFXJump,
+    BParFXFBComp(StructuredObject),
+    BParFXJump(StructuredObject),
     QuickBrowseData(QuickBrowseData),
-    BSnapshot,
-    BGroupSnapshot,
-    BSnapshotMetaData,
-    BParFXVan51,
-    BParFXACBox,
-    BParFXHotSolo,
-    BParFXBassInvader,
-    BParFXCat,
-    BParFXDStortion,
-    BParFXPlateReverb,
-    BParFXCryWah,
-    BParFXReplikaDelay,
-    BParFXPhasis,
-    BParFXFlair,
-    BParFXChoral,
-    BParFXCoreCell,
-    BParFXHilbertLimiter,
-    BParFXGaloisReverb,
-    BParFXSupercharger,
-    BParFXBassPro,
-    BParFXPsycheDelay,
-    BParFXRingModulator,
+    BSnapshot(BSnapshot),
+    BGroupSnapshot(BGroupSnapshot),
+    BSnapshotMetaData(BSnapshotMetaData),
+    BParFXVan51(StructuredObject),
+    BParFXACBox(FXACBox),
+    BParFXHotSolo(FXHotSolo),
+    BParFXCat(FXCat),
+    BParFXDStortion(FXDStortion),
+    BParFXPlateReverb(FXPlateReverb),
+    BParFXCryWah(FXCryWah),
+    BParFXReplikaDelay(StructuredObject),
+    BParFXPhasis(FXPhasis),
+    BParFXFlair(StructuredObject),
+    BParFXChoral(StructuredObject),
+    BParFXCoreCell(StructuredObject),
+    BParFXHilbertLimiter(StructuredObject),
+    BParFXGaloisReverb(StructuredObject),
+    BParFXSupercharger(StructuredObject),
+    BParFXBassPro(StructuredObject),
+    BParFXPsycheDelay(StructuredObject),
+    BParFXRingModulator(StructuredObject),
+    /// A chunk that can't be read as an object.
     Unsupported(u16),
 
     Program(Program),
+    /// A chunk with an unknown id that reads as a [`StructuredObject`].
     StructuredObject(StructuredObject),
 }
 
 impl TryFrom<&Chunk> for KontaktObject {
     type Error = Error;
 
+    /// Chunks with an unknown id fall back to a [`StructuredObject`], or
+    /// [`KontaktObject::Unsupported`] if they are not structured at all.
+    /// Errors of known ids are returned.
     fn try_from(chunk: &Chunk) -> Result<KontaktObject, Error> {
+        KontaktObject::decode(chunk)
+    }
+}
+
+impl KontaktObject {
+    fn fallback(chunk: &Chunk) -> KontaktObject {
+        match StructuredObject::try_from(chunk) {
+            Ok(so) => KontaktObject::StructuredObject(so),
+            Err(_) => KontaktObject::Unsupported(chunk.id),
+        }
+    }
+
+    fn decode(chunk: &Chunk) -> Result<KontaktObject, Error> {
         Ok(match chunk.id {
-            // 0x54 BParFXBassInvader?,
-            0x00 => KontaktObject::BParModBase,
-            0x01 => KontaktObject::BAutomationObject,
-            0x02 => KontaktObject::OutputPartition,
+            0x00 => KontaktObject::BParModBase(chunk.try_into()?),
+            0x01 => KontaktObject::BAutomationObject(chunk.try_into()?),
+            0x02 => KontaktObject::OutputPartition(chunk.try_into()?),
             0x03 => KontaktObject::Bank(chunk.try_into()?),
-            0x04 => KontaktObject::BGroup,
-            0x05 => KontaktObject::BLoop,
+            0x04 => KontaktObject::BGroup(chunk.try_into()?),
+            0x05 => KontaktObject::BLoop(chunk.try_into()?),
             0x06 => KontaktObject::BParScript(chunk.try_into()?),
-            0x07 => KontaktObject::BParEnv,
-            0x08 => KontaktObject::BParLFO,
-            0x09 => KontaktObject::BParArp,
-            0x0a => KontaktObject::BParEnvF,
-            0x0b => KontaktObject::BParGlide,
-            0x0c => KontaktObject::BParExternalMod,
+            0x07 => KontaktObject::BParEnv(chunk.try_into()?),
+            0x08 => KontaktObject::BParLFO(chunk.try_into()?),
+            0x09 => KontaktObject::BParArp(chunk.try_into()?),
+            0x0a => KontaktObject::BParEnvF(chunk.try_into()?),
+            0x0b => KontaktObject::BParGlide(chunk.try_into()?),
+            0x0c => KontaktObject::BParExternalMod(chunk.try_into()?),
             0x0d => KontaktObject::BParInternalMod(chunk.try_into()?),
-            0x0e => KontaktObject::BParSrcMode,
-            0x0f => KontaktObject::BParStartCriteria,
+            0x0e => KontaktObject::BParSrcMode(chunk.try_into()?),
+            0x0f => KontaktObject::BParStartCriteria(chunk.try_into()?),
             0x10 => KontaktObject::BParFXDelay(chunk.try_into()?),
-            0x11 => KontaktObject::BParFXChorus,
-            0x12 => KontaktObject::BParFXFlanger,
-            0x13 => KontaktObject::BParFXGainer,
-            0x14 => KontaktObject::BParFXPhaser,
-            0x15 => KontaktObject::BParFXReverb,
-            0x16 => KontaktObject::BParFXIRC,
+            0x11 => KontaktObject::BParFXChorus(chunk.try_into()?),
+            0x12 => KontaktObject::BParFXFlanger(chunk.try_into()?),
+            0x13 => KontaktObject::BParFXGainer(chunk.try_into()?),
+            0x14 => KontaktObject::BParFXPhaser(chunk.try_into()?),
+            0x15 => KontaktObject::BParFXReverb(chunk.try_into()?),
+            0x16 => KontaktObject::BParFXIRC(chunk.try_into()?),
             0x17 => KontaktObject::BParFXSendLevel(chunk.try_into()?),
-            0x18 => KontaktObject::BParFXFilter,
-            0x19 => KontaktObject::BParFXCompressor,
-            0x1a => KontaktObject::BParFXInverter,
-            0x1b => KontaktObject::BParFXDYX,
-            0x1c => KontaktObject::BParFXLimiter,
-            0x1d => KontaktObject::BParFXSurroundPanner,
-            0x1e => KontaktObject::BParFXDistortion,
-            0x1f => KontaktObject::BParFXStereoSpread,
-            0x20 => KontaktObject::BParFXLofi,
-            0x21 => KontaktObject::BParFXSkreamer,
-            0x22 => KontaktObject::BParFXRotator,
-            0x23 => KontaktObject::BParFXTwang,
-            0x24 => KontaktObject::BParFXCabinet,
+            0x18 => KontaktObject::BParFXFilter(chunk.try_into()?),
+            0x19 => KontaktObject::BParFXCompressor(chunk.try_into()?),
+            0x1a => KontaktObject::BParFXInverter(chunk.try_into()?),
+            0x1b => KontaktObject::BParFXDYX(chunk.try_into()?),
+            0x1c => KontaktObject::BParFXLimiter(chunk.try_into()?),
+            0x1d => KontaktObject::BParFXSurroundPanner(chunk.try_into()?),
+            0x1e => KontaktObject::BParFXDistortion(chunk.try_into()?),
+            0x1f => KontaktObject::BParFXStereoSpread(chunk.try_into()?),
+            0x20 => KontaktObject::BParFXLofi(chunk.try_into()?),
+            0x21 => KontaktObject::BParFXSkreamer(chunk.try_into()?),
+            0x22 => KontaktObject::BParFXRotator(chunk.try_into()?),
+            0x23 => KontaktObject::BParFXTwang(chunk.try_into()?),
+            0x24 => KontaktObject::BParFXCabinet(chunk.try_into()?),
             0x25 => KontaktObject::BParFX(chunk.try_into()?),
-            0x26 => KontaktObject::BDyxMorphGroup,
-            0x27 => KontaktObject::BDyxMorphMap,
+            0x26 => KontaktObject::BDyxMorphGroup(chunk.try_into()?),
+            0x27 => KontaktObject::BDyxMorphMap(chunk.try_into()?),
             0x28 => KontaktObject::Program(chunk.try_into()?),
             0x29 => KontaktObject::BProgramContainer(chunk.try_into()?),
-            0x2a => KontaktObject::BSample,
+            0x2a => KontaktObject::BSample(chunk.try_into()?),
             0x2b => KontaktObject::VoiceGroup(chunk.try_into()?),
-            0x2c => KontaktObject::BZone,
-            0x2d => KontaktObject::BZoneLevelEnv,
-            0x2e => KontaktObject::BZoneArraySer,
-            0x2f => KontaktObject::BGroupCompleteSer,
-            0x30 => KontaktObject::PresetImpl,
+            0x2c => KontaktObject::BZone(chunk.try_into()?),
+            0x2d => KontaktObject::BZoneLevelEnv(chunk.try_into()?),
+            0x2e => KontaktObject::BZoneArraySer(chunk.try_into()?),
+            0x2f => KontaktObject::BGroupCompleteSer(chunk.try_into()?),
+            0x30 => KontaktObject::PresetImpl(chunk.try_into()?),
             0x32 => KontaktObject::VoiceGroups(chunk.try_into()?),
             0x33 => KontaktObject::GroupList(chunk.try_into()?),
             0x34 => KontaktObject::ZoneList(chunk.try_into()?),
@@ -203,55 +231,157 @@ impl TryFrom<&Chunk> for KontaktObject {
             0x39 => KontaktObject::LoopArray(chunk.try_into()?),
             0x3a => KontaktObject::BParameterArraySerBParFX8(chunk.try_into()?),
             0x3b => KontaktObject::BParameterArraySerBParInternalMod16(chunk.try_into()?),
-            0x3c => KontaktObject::BParameterArraySerBParExternalMod32,
+            0x3c => KontaktObject::BParameterArraySerBParExternalMod32(chunk.try_into()?),
             0x3d => KontaktObject::FileNameListPreK51(chunk.try_into()?),
-            0x3e => KontaktObject::BOutputConfiguration,
-            0x3f => KontaktObject::BParEnvAhdsr,
-            0x40 => KontaktObject::BParEnvFm7,
-            0x41 => KontaktObject::BParEnvDbd,
-            0x42 => KontaktObject::BParFXTape,
-            0x43 => KontaktObject::BParFXTrans,
-            0x44 => KontaktObject::BParFXSSLGEQ,
+            0x3e => KontaktObject::BOutputConfiguration(chunk.try_into()?),
+            0x3f => KontaktObject::BParEnvAhdsr(chunk.try_into()?),
+            0x40 => KontaktObject::BParEnvFm7(chunk.try_into()?),
+            0x41 => KontaktObject::BParEnvDbd(chunk.try_into()?),
+            0x42 => KontaktObject::BParFXTape(chunk.try_into()?),
+            0x43 => KontaktObject::BParFXTrans(chunk.try_into()?),
+            0x44 => KontaktObject::BParFXSSLGEQ(chunk.try_into()?),
             0x45 => KontaktObject::BInsertBus(chunk.try_into()?),
-            0x46 => KontaktObject::BParFXSSLGBusComp,
+            0x46 => KontaktObject::BParFXSSLGBusComp(chunk.try_into()?),
             0x47 => KontaktObject::SaveSettings(chunk.try_into()?),
-            0x4a => KontaktObject::BParGroupDynamics,
+            0x4a => KontaktObject::BParGroupDynamics(chunk.try_into()?),
             0x4b => KontaktObject::FNTableImpl(chunk.try_into()?),
-            0x4c => KontaktObject::BParFXFBComp,
-            0x4d => KontaktObject::BParFXJump,
+            0x4c => KontaktObject::BParFXFBComp(chunk.try_into()?),
+            0x4d => KontaktObject::BParFXJump(chunk.try_into()?),
             0x4e => KontaktObject::QuickBrowseData(chunk.try_into()?),
-            0x4f => KontaktObject::BSnapshot,
-            0x50 => KontaktObject::BGroupSnapshot,
-            0x51 => KontaktObject::BSnapshotMetaData,
-            0x52 => KontaktObject::BParFXVan51,
-            0x53 => KontaktObject::BParFXACBox,
-            0x54 => KontaktObject::BParFXHotSolo,
-            0x55 => KontaktObject::BParFXCat,
-            0x56 => KontaktObject::BParFXDStortion,
-            0x57 => KontaktObject::BParFXPlateReverb,
-            0x58 => KontaktObject::BParFXCryWah,
-            0x59 => KontaktObject::BParFXGaloisReverb,
-            0x5a => KontaktObject::BParFXReplikaDelay,
-            0x5b => KontaktObject::BParFXPhasis,
-            0x5c => KontaktObject::BParFXFlair,
-            0x5d => KontaktObject::BParFXChoral,
-            0x5e => KontaktObject::BParFXCoreCell,
-            0x5f => KontaktObject::BParFXHilbertLimiter,
-            0x60 => KontaktObject::BParFXSupercharger,
-            0x61 => KontaktObject::BParFXBassPro,
-            0x63 => KontaktObject::BParFXPsycheDelay,
-            0x64 => KontaktObject::BParFXRingModulator,
-            _ => KontaktObject::Unsupported(chunk.id),
+            0x4f => KontaktObject::BSnapshot(chunk.try_into()?),
+            0x50 => KontaktObject::BGroupSnapshot(chunk.try_into()?),
+            0x51 => KontaktObject::BSnapshotMetaData(chunk.try_into()?),
+            0x52 => KontaktObject::BParFXVan51(chunk.try_into()?),
+            0x53 => KontaktObject::BParFXACBox(chunk.try_into()?),
+            0x54 => KontaktObject::BParFXHotSolo(chunk.try_into()?),
+            0x55 => KontaktObject::BParFXCat(chunk.try_into()?),
+            0x56 => KontaktObject::BParFXDStortion(chunk.try_into()?),
+            0x57 => KontaktObject::BParFXPlateReverb(chunk.try_into()?),
+            0x58 => KontaktObject::BParFXCryWah(chunk.try_into()?),
+            0x59 => KontaktObject::BParFXGaloisReverb(chunk.try_into()?),
+            0x5a => KontaktObject::BParFXReplikaDelay(chunk.try_into()?),
+            0x5b => KontaktObject::BParFXPhasis(chunk.try_into()?),
+            0x5c => KontaktObject::BParFXFlair(chunk.try_into()?),
+            0x5d => KontaktObject::BParFXChoral(chunk.try_into()?),
+            0x5e => KontaktObject::BParFXCoreCell(chunk.try_into()?),
+            0x5f => KontaktObject::BParFXHilbertLimiter(chunk.try_into()?),
+            0x60 => KontaktObject::BParFXSupercharger(chunk.try_into()?),
+            0x61 => KontaktObject::BParFXBassPro(chunk.try_into()?),
+            0x63 => KontaktObject::BParFXPsycheDelay(chunk.try_into()?),
+            0x64 => KontaktObject::BParFXRingModulator(chunk.try_into()?),
+            _ => KontaktObject::fallback(chunk),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::fs::File;
+    use std::{fs::File, io::Cursor, path::Path};
 
     use super::*;
 
+    /// Decodes the chunk and its children, collecting the ids without a
+    /// decoder and the ids whose decoder failed.
+    fn collect_undecoded(chunk: &Chunk, undecoded: &mut Vec<u16>, failed: &mut Vec<u16>) {
+        match KontaktObject::decode(chunk) {
+            Ok(KontaktObject::Unsupported(_)) | Ok(KontaktObject::StructuredObject(_)) => {
+                undecoded.push(chunk.id)
+            }
+            Err(_) => failed.push(chunk.id),
+            Ok(_) => {}
+        }
+
+        if chunk.data.first() == Some(&1) {
+            if let Ok(so) = StructuredObject::try_from(chunk) {
+                for child in &so.children {
+                    collect_undecoded(child, undecoded, failed);
+                }
+            }
+        }
+    }
+
+    fn walk_fixtures(
+        dir: &Path,
+        undecoded: &mut Vec<u16>,
+        failed: &mut Vec<u16>,
+    ) -> Result<(), Error> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                walk_fixtures(&path, undecoded, failed)?;
+            } else if path.extension().is_some_and(|ext| ext == "kon") {
+                // Only fixtures in "0xNN-Name" folders start with a chunk header.
+                let Some(expected) = path
+                    .ancestors()
+                    .filter_map(|p| p.file_name()?.to_str()?.strip_prefix("0x"))
+                    .find_map(|name| u16::from_str_radix(name.get(..2)?, 16).ok())
+                else {
+                    continue;
+                };
+                let data = std::fs::read(&path)?;
+                if data.get(..2) == Some(&expected.to_le_bytes()[..]) {
+                    let chunk = Chunk::read(Cursor::new(data))?;
+                    collect_undecoded(&chunk, undecoded, failed);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_coverage() -> Result<(), Error> {
+        let mut undecoded = Vec::new();
+        let mut failed = Vec::new();
+        walk_fixtures(
+            Path::new("tests/data/Objects/Kontakt"),
+            &mut undecoded,
+            &mut failed,
+        )?;
+
+        let format_ids = |ids: &mut Vec<u16>| {
+            ids.sort();
+            ids.dedup();
+            ids.iter()
+                .map(|id| format!("0x{id:02x}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        // Found in banks, layout unknown
+        let allowed = "0x48, 0x49";
+        let undecoded = format_ids(&mut undecoded);
+        assert_eq!(undecoded, allowed, "Chunk ids without a decoder");
+
+        let failed = format_ids(&mut failed);
+        assert!(
+            failed.is_empty(),
+            "Chunk ids that failed to decode: {failed}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_fallback() -> Result<(), Error> {
+        let chunk = Chunk {
+            id: 0x62,
+            data: vec![0],
+        };
+        assert!(matches!(
+            chunk.into_object()?,
+            KontaktObject::Unsupported(0x62)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_error() {
+        // SaveSettings is never structured
+        let chunk = Chunk {
+            id: 0x47,
+            data: vec![1],
+        };
+        assert!(chunk.into_object().is_err());
+    }
+
     #[test]
     fn test_structured_object() -> Result<(), Error> {
         let file =
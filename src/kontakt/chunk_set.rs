@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 
-use crate::{read_bytes::ReadBytesExt, Error};
+use crate::{read_bytes::ReadBytesExt, write_bytes::WriteBytesExt, Error};
 
 use super::{
     chunk::Chunk,
-    objects::{FNTableImpl, FileNameListPreK51, Program},
+    objects::{BFileName, FNTableImpl, FileNameListPreK51, Program},
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KontaktChunks(pub Vec<Chunk>);
 
 impl KontaktChunks {
@@ -21,6 +22,13 @@ impl KontaktChunks {
         Ok(Self(objects))
     }
 
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        for chunk in &self.0 {
+            chunk.write(&mut writer)?;
+        }
+        Ok(())
+    }
+
     pub fn first(&self) -> Option<&Chunk> {
         self.0.first()
     }
@@ -41,17 +49,45 @@ impl KontaktChunks {
         self.find_first(0x28).map(Program::try_from)
     }
 
+    /// Replaces the first program.
+    pub fn set_program(&mut self, program: &Program) -> Result<(), Error> {
+        let chunk = self
+            .0
+            .iter_mut()
+            .find(|c| c.id == 0x28)
+            .ok_or(Error::Static("Could not find Program"))?;
+        chunk.data.clear();
+        program.0.write(&mut chunk.data)
+    }
+
     pub fn filename_tables(&self) -> Result<Option<FNTableImpl>, Error> {
         if let Some(chunk) = self.find_first(0x4b) {
             return Ok(Some(FNTableImpl::try_from(chunk)?));
         }
 
-        // TODO: convert FileNameListPreK51
+        if let Some(chunk) = self.find_first(0x3d) {
+            return Ok(Some(FileNameListPreK51::try_from(chunk)?.try_into()?));
+        }
 
         Ok(None)
     }
 
-    pub fn filename_table(&self) -> Option<Result<HashMap<u32, String>, Error>> {
+    /// Replaces the filetable, keeping the table kind of the preset
+    /// ([`FNTableImpl`] or the pre 5.1 [`FileNameListPreK51`]).
+    pub fn set_filename_tables(&mut self, filetable: &FNTableImpl) -> Result<(), Error> {
+        let chunk = self
+            .0
+            .iter_mut()
+            .find(|c| c.id == 0x4b || c.id == 0x3d)
+            .ok_or(Error::Static("Could not find filetable"))?;
+        *chunk = match chunk.id {
+            0x4b => filetable.to_chunk()?,
+            _ => FileNameListPreK51::try_from(filetable.clone())?.to_chunk()?,
+        };
+        Ok(())
+    }
+
+    pub fn filename_table(&self) -> Option<Result<HashMap<u32, BFileName>, Error>> {
         if let Some(chunk) = self.find_first(0x4b) {
             return Some(FNTableImpl::try_from(chunk).map(|f| f.sample_filetable));
         }
@@ -63,3 +99,46 @@ impl KontaktChunks {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::Cursor};
+
+    use super::*;
+    use crate::NIFile;
+
+    fn set_sample(path: &str) -> Result<(), Error> {
+        let file = NIFile::read(File::open(path)?)?;
+        let preset = file.inner_preset()?;
+        let mut chunks = KontaktChunks::read(Cursor::new(&preset))?;
+        let id = chunks.last().unwrap().id;
+
+        let mut filetable = chunks.filename_tables()?.unwrap();
+        chunks.set_filename_tables(&filetable)?;
+        let mut data = Vec::new();
+        chunks.write(&mut data)?;
+        assert_eq!(data, preset);
+
+        filetable
+            .sample_filetable
+            .insert(0, BFileName::from("Samples/relinked.wav"));
+        chunks.set_filename_tables(&filetable)?;
+
+        let mut data = Vec::new();
+        chunks.write(&mut data)?;
+        let chunks = KontaktChunks::read(Cursor::new(data))?;
+        assert_eq!(chunks.last().unwrap().id, id);
+        assert_eq!(
+            chunks.filename_tables()?.unwrap().sample_filetable[&0].to_string(),
+            "Samples/relinked.wav"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_filename_tables() -> Result<(), Error> {
+        set_sample("tests/data/Containers/NKS/KontaktV42/4.2.4.5316-000.nki")?;
+        set_sample("tests/data/Containers/NIS/files/kontakt/5.0.2/musical_saw.nki")?;
+        set_sample("tests/data/Containers/NIS/files/kontakt/5.4.3.307/000.nki")
+    }
+}
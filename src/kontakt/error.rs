@@ -5,4 +5,7 @@ pub enum KontaktError {
 
     #[error("Missing Expected Chunk: 0x{0}")]
     MissingChunk(u16),
+
+    #[error("Unexpected structured data in Chunk 0x{0:X}")]
+    UnexpectedStructured(u16),
 }
@@ -0,0 +1,373 @@
+use std::{
+    io::{Cursor, Read, Seek},
+    path::Path,
+};
+
+use ncw::NcwReader;
+
+use crate::{read_bytes::ReadBytesExt, write_bytes::WriteBytesExt, Error, NIFileError};
+
+use super::MappedLoop;
+
+const WAVE_FORMAT_PCM: u16 = 0x0001;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 0x0003;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Mapping of a sample, stored in the `smpl` and `inst` chunks of wav files.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SampleMapping {
+    pub root_key: u8,
+    /// Cents
+    pub tune: f32,
+    /// Decibels
+    pub gain: f32,
+    pub low_key: u8,
+    pub high_key: u8,
+    pub low_velocity: u8,
+    pub high_velocity: u8,
+    pub loops: Vec<MappedLoop>,
+}
+
+/// Decoded PCM audio of a sample file (wav, ncw).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SampleData {
+    pub channels: u16,
+    pub bits_per_sample: u16,
+    pub sample_rate: u32,
+    /// Interleaved, signed values in the range of `bits_per_sample`.
+    pub samples: Vec<i32>,
+}
+
+impl SampleData {
+    /// Reads a wav or ncw file, detected by its header.
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        let data = std::fs::read(path)?;
+        match data.get(0..4) {
+            Some(b"RIFF") => Self::read_wav(Cursor::new(data)),
+            _ => Self::read_ncw(Cursor::new(data)),
+        }
+    }
+
+    /// Reads integer and float PCM wav files. Float samples are converted to
+    /// 32 bit integers.
+    pub fn read_wav<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        if reader.read_bytes(4)? != b"RIFF" {
+            return Err(Error::Static("Invalid wav: RIFF header not found"));
+        }
+        // the size field is unreliable, chunks are read to the end of the file
+        reader.read_u32_le()?;
+        if reader.read_bytes(4)? != b"WAVE" {
+            return Err(Error::Static("Invalid wav: WAVE header not found"));
+        }
+
+        let mut format = None;
+        let mut data = None;
+        while let Ok(id) = reader.read_bytes(4) {
+            let size = reader.read_u32_le()? as usize;
+            let chunk = reader.read_bytes(size)?;
+            if size % 2 == 1 {
+                let _ = reader.read_u8();
+            }
+            match &id[..] {
+                b"fmt " => format = Some(chunk),
+                b"data" => data = Some(chunk),
+                _ => {}
+            }
+        }
+
+        let format = format.ok_or(Error::Static("Invalid wav: fmt chunk not found"))?;
+        let data = data.ok_or(Error::Static("Invalid wav: data chunk not found"))?;
+
+        let mut reader = Cursor::new(format);
+        let mut format_tag = reader.read_u16_le()?;
+        let channels = reader.read_u16_le()?;
+        let sample_rate = reader.read_u32_le()?;
+        let _byte_rate = reader.read_u32_le()?;
+        let _block_align = reader.read_u16_le()?;
+        let bits_per_sample = reader.read_u16_le()?;
+        if format_tag == WAVE_FORMAT_EXTENSIBLE {
+            // cb_size, valid bits, channel mask, then the sub format guid
+            reader.read_bytes(8)?;
+            format_tag = reader.read_u16_le()?;
+        }
+
+        let samples = match (format_tag, bits_per_sample) {
+            (WAVE_FORMAT_PCM, 8) => data.iter().map(|b| *b as i32 - 128).collect(),
+            (WAVE_FORMAT_PCM, 16) => data
+                .chunks_exact(2)
+                .map(|b| i16::from_le_bytes([b[0], b[1]]) as i32)
+                .collect(),
+            (WAVE_FORMAT_PCM, 24) => data
+                .chunks_exact(3)
+                .map(|b| i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8)
+                .collect(),
+            (WAVE_FORMAT_PCM, 32) => data
+                .chunks_exact(4)
+                .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect(),
+            (WAVE_FORMAT_IEEE_FLOAT, 32) => {
+                return Ok(Self {
+                    channels,
+                    bits_per_sample: 32,
+                    sample_rate,
+                    samples: data
+                        .chunks_exact(4)
+                        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                        .map(|s| (s.clamp(-1.0, 1.0) as f64 * i32::MAX as f64) as i32)
+                        .collect(),
+                })
+            }
+            (format_tag, bits) => {
+                return Err(NIFileError::Generic(format!(
+                    "Unsupported wav format: 0x{format_tag:X}, {bits} bits"
+                )))
+            }
+        };
+
+        Ok(Self {
+            channels,
+            bits_per_sample,
+            sample_rate,
+            samples,
+        })
+    }
+
+    /// Decodes a Native Instruments Compressed Wave (ncw).
+    pub fn read_ncw<R: Read + Seek>(reader: R) -> Result<Self, Error> {
+        let mut ncw = NcwReader::read(reader)
+            .map_err(|e| NIFileError::Generic(format!("Could not read ncw: {e:?}")))?;
+        let header = &ncw.header;
+        let (channels, bits_per_sample, sample_rate) =
+            (header.channels, header.bits_per_sample, header.sample_rate);
+
+        let shift = 32 - bits_per_sample.clamp(8, 32) as u32;
+        let samples = ncw
+            .decode_samples()
+            .map_err(|e| NIFileError::Generic(format!("Could not decode ncw: {e:?}")))?
+            .into_iter()
+            // truncated blocks are not sign extended
+            .map(|s| s.wrapping_shl(shift) >> shift)
+            .collect();
+
+        Ok(Self {
+            channels,
+            bits_per_sample,
+            sample_rate,
+            samples,
+        })
+    }
+
+    /// Writes an integer PCM wav file. The mapping is written to `smpl` and
+    /// `inst` chunks.
+    pub fn write_wav<W: WriteBytesExt>(
+        &self,
+        mut writer: W,
+        mapping: Option<&SampleMapping>,
+    ) -> Result<(), Error> {
+        let bytes_per_sample = (self.bits_per_sample as usize).div_ceil(8);
+
+        let mut format = Vec::new();
+        format.write_u16_le(WAVE_FORMAT_PCM)?;
+        format.write_u16_le(self.channels)?;
+        format.write_u32_le(self.sample_rate)?;
+        format
+            .write_u32_le(self.sample_rate * (self.channels as usize * bytes_per_sample) as u32)?;
+        format.write_u16_le((self.channels as usize * bytes_per_sample) as u16)?;
+        format.write_u16_le(self.bits_per_sample)?;
+
+        let mut data = Vec::with_capacity(self.samples.len() * bytes_per_sample);
+        for sample in &self.samples {
+            match bytes_per_sample {
+                1 => data.write_u8((sample + 128) as u8)?,
+                bytes => data.write_bytes(&sample.to_le_bytes()[..bytes.min(4)])?,
+            }
+        }
+
+        let mut wave = b"WAVE".to_vec();
+        write_riff_chunk(&mut wave, b"fmt ", &format)?;
+        write_riff_chunk(&mut wave, b"data", &data)?;
+        if let Some(mapping) = mapping {
+            write_riff_chunk(&mut wave, b"smpl", &self.smpl_chunk(mapping)?)?;
+            write_riff_chunk(&mut wave, b"inst", &inst_chunk(mapping))?;
+        }
+        write_riff_chunk(&mut writer, b"RIFF", &wave)
+    }
+
+    fn smpl_chunk(&self, mapping: &SampleMapping) -> Result<Vec<u8>, Error> {
+        let (unity_note, pitch_fraction) = smpl_pitch(mapping);
+
+        let mut smpl = Vec::new();
+        smpl.write_u32_le(0)?; // manufacturer
+        smpl.write_u32_le(0)?; // product
+        smpl.write_u32_le(1_000_000_000 / self.sample_rate.max(1))?; // sample period in ns
+        smpl.write_u32_le(unity_note)?;
+        smpl.write_u32_le(pitch_fraction)?;
+        smpl.write_u32_le(0)?; // smpte format
+        smpl.write_u32_le(0)?; // smpte offset
+        smpl.write_u32_le(mapping.loops.len() as u32)?;
+        smpl.write_u32_le(0)?; // sampler data
+        for (id, sample_loop) in mapping.loops.iter().enumerate() {
+            smpl.write_u32_le(id as u32)?;
+            // 0: forward, 1: alternating
+            smpl.write_u32_le(sample_loop.alternating as u32)?;
+            smpl.write_u32_le(sample_loop.start.max(0) as u32)?;
+            // inclusive
+            smpl.write_u32_le((sample_loop.end - 1).max(0) as u32)?;
+            smpl.write_u32_le(0)?; // fraction
+            smpl.write_u32_le(0)?; // play count, 0 is infinite
+        }
+        Ok(smpl)
+    }
+
+    pub fn num_frames(&self) -> usize {
+        match self.channels {
+            0 => 0,
+            channels => self.samples.len() / channels as usize,
+        }
+    }
+
+    /// A single channel, scaled to 16 bits.
+    pub fn channel_i16(&self, channel: usize) -> Vec<i16> {
+        let channels = self.channels.max(1) as usize;
+        self.samples
+            .iter()
+            .skip(channel)
+            .step_by(channels)
+            .map(|s| match self.bits_per_sample {
+                bits if bits > 16 => (s >> (bits - 16)) as i16,
+                bits => (s << (16 - bits)) as i16,
+            })
+            .collect()
+    }
+}
+
+fn inst_chunk(mapping: &SampleMapping) -> Vec<u8> {
+    let (unity_note, fine_tune) = unity_note(mapping);
+    vec![
+        unity_note,
+        fine_tune as u8,
+        mapping.gain.round().clamp(-64.0, 64.0) as i8 as u8,
+        mapping.low_key,
+        mapping.high_key,
+        mapping.low_velocity,
+        mapping.high_velocity,
+    ]
+}
+
+/// The note a sample plays at without transposition, and the remaining
+/// tune in cents (-50 to 50). A sample tuned up is played higher, so its
+/// unity note is lower than the root key.
+fn unity_note(mapping: &SampleMapping) -> (u8, i8) {
+    let semitones = (mapping.tune / 100.0).round();
+    let note = (mapping.root_key as f32 - semitones).clamp(0.0, 127.0) as u8;
+    let cents = (mapping.tune - semitones * 100.0)
+        .round()
+        .clamp(-50.0, 50.0) as i8;
+    (note, cents)
+}
+
+/// The pitch of the sample as a MIDI note and the fraction of a semitone
+/// above it (0 to 2^32), as stored in `smpl` chunks.
+fn smpl_pitch(mapping: &SampleMapping) -> (u32, u32) {
+    let cents = (mapping.root_key as f32 * 100.0 - mapping.tune).round() as i32;
+    match cents.div_euclid(100) {
+        note if note < 0 => (0, 0),
+        note if note > 127 => (127, 0),
+        note => (
+            note as u32,
+            (((cents.rem_euclid(100) as u64) << 32) / 100) as u32,
+        ),
+    }
+}
+
+/// Writes a RIFF chunk, padded to an even size.
+pub(crate) fn write_riff_chunk<W: WriteBytesExt>(
+    mut writer: W,
+    id: &[u8; 4],
+    data: &[u8],
+) -> Result<(), Error> {
+    writer.write_bytes(id)?;
+    writer.write_u32_le(data.len() as u32)?;
+    writer.write_bytes(data)?;
+    if data.len() % 2 == 1 {
+        writer.write_u8(0)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::*;
+
+    #[test]
+    fn test_read_ncw() -> Result<(), Error> {
+        let ncw = SampleData::read_ncw(File::open(
+            "tests/data/Containers/NCW/testfile-onezero-16-bit-stereo-multiblock.ncw",
+        )?)?;
+        let wav = SampleData::read_wav(File::open(
+            "tests/data/Containers/NCW/testfile-onezero-16-bit-stereo-multiblock.wav",
+        )?)?;
+
+        assert_eq!(wav.channels, 2);
+        assert_eq!(wav.sample_rate, 48000);
+        assert_eq!(wav.samples[0], -1);
+        assert_eq!(ncw, wav);
+        assert_eq!(ncw.channel_i16(1)[..2], [0, 0]);
+
+        let mut bytes = Vec::new();
+        ncw.write_wav(&mut bytes, None)?;
+        assert_eq!(SampleData::read_wav(Cursor::new(bytes))?, wav);
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_wav_mapping() -> Result<(), Error> {
+        let sample = SampleData {
+            channels: 1,
+            bits_per_sample: 24,
+            sample_rate: 44100,
+            samples: vec![-8388608, 0, 8388607],
+        };
+        let mapping = SampleMapping {
+            root_key: 60,
+            tune: 230.0,
+            gain: -3.2,
+            low_key: 48,
+            high_key: 72,
+            low_velocity: 1,
+            high_velocity: 100,
+            loops: vec![MappedLoop {
+                start: 1,
+                end: 3,
+                alternating: true,
+                crossfade: 0,
+            }],
+        };
+
+        let mut bytes = Vec::new();
+        sample.write_wav(&mut bytes, Some(&mapping))?;
+        assert_eq!(SampleData::read_wav(Cursor::new(&bytes))?, sample);
+
+        let smpl = bytes.windows(4).position(|w| w == b"smpl").unwrap() + 8;
+        let field = |i: usize| {
+            u32::from_le_bytes(bytes[smpl + i * 4..smpl + i * 4 + 4].try_into().unwrap())
+        };
+        assert_eq!(field(2), 22675); // sample period
+                                     // 57.7, the inst chunk plays it 30 cents up from 58
+        assert_eq!(field(3), 57); // unity note
+        assert_eq!(field(4), 3006477107); // pitch fraction
+        assert_eq!(field(7), 1); // loops
+        assert_eq!([field(10), field(11), field(12)], [1, 1, 2]);
+
+        let inst = bytes.windows(4).position(|w| w == b"inst").unwrap() + 8;
+        assert_eq!(
+            bytes[inst..inst + 7],
+            [58, 30, (-3i8) as u8, 48, 72, 1, 100]
+        );
+        Ok(())
+    }
+}
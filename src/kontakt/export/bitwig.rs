@@ -0,0 +1,198 @@
+use std::{collections::HashMap, fmt::Write, path::Path};
+
+use crate::{kontakt::KontaktInstrument, Error, NIFileError};
+
+use super::{audio::SampleData, escape_xml, number, zip::ZipWriter};
+
+impl KontaktInstrument {
+    /// Converts the instrument to a Bitwig multisample (a zip archive of a
+    /// `multisample.xml` and the samples).
+    ///
+    /// Samples are loaded relative to `instrument_dir`, ncw samples are
+    /// decoded to wav. Gain and tune combine the instrument, group and zone
+    /// settings, see [`KontaktInstrument::zone_map`].
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use ni_file::{kontakt::KontaktInstrument, NIFile};
+    ///
+    /// let file = NIFile::read(std::fs::File::open("Instruments/instrument.nki")?)?;
+    /// let instrument = KontaktInstrument::try_from(&file)?;
+    /// let multisample = instrument.to_multisample(Path::new("Instruments"))?;
+    /// std::fs::write("instrument.multisample", multisample)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_multisample(&self, instrument_dir: &Path) -> Result<Vec<u8>, Error> {
+        let mut zip = ZipWriter::default();
+        // sample path to the file name in the archive
+        let mut files: HashMap<String, String> = HashMap::new();
+
+        for path in self.zones.iter().filter_map(|zone| zone.sample.as_ref()) {
+            let key = path.to_string();
+            if files.contains_key(&key) {
+                continue;
+            }
+
+            let file = path.to_path(instrument_dir);
+            let read_error = |e: Error| {
+                NIFileError::Generic(format!("Could not read sample {}: {e}", file.display()))
+            };
+            let mut name = path.file_name().unwrap_or("sample.wav").to_string();
+            let contents = match name.to_lowercase().ends_with(".ncw") {
+                true => {
+                    name.replace_range(name.len() - 4.., ".wav");
+                    let mut wav = Vec::new();
+                    SampleData::open(&file)
+                        .map_err(read_error)?
+                        .write_wav(&mut wav, None)?;
+                    wav
+                }
+                false => std::fs::read(&file).map_err(|e| read_error(e.into()))?,
+            };
+
+            let name = unique_name(&name, &files);
+            zip.add(&name, &contents)?;
+            files.insert(key, name);
+        }
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        writeln!(xml, "<multisample name=\"{}\">", escape_xml(&self.name)).unwrap();
+        xml.push_str("    <generator>ni-file</generator>\n");
+        xml.push_str("    <category/>\n");
+        writeln!(
+            xml,
+            "    <creator>{}</creator>",
+            escape_xml(&self.metadata.author)
+        )
+        .unwrap();
+        xml.push_str("    <description/>\n");
+        xml.push_str("    <keywords/>\n");
+        for group in &self.groups {
+            writeln!(xml, "    <group name=\"{}\"/>", escape_xml(&group.name)).unwrap();
+        }
+
+        for (zone, mapped) in self.zones.iter().zip(self.zone_map()) {
+            let Some(file) = zone.sample.as_ref().and_then(|p| files.get(&p.to_string())) else {
+                continue;
+            };
+
+            write!(
+                xml,
+                "    <sample file=\"{}\" gain=\"{}\"",
+                escape_xml(file),
+                number(mapped.gain)
+            )
+            .unwrap();
+            if zone.group < self.groups.len() {
+                write!(xml, " group=\"{}\"", zone.group).unwrap();
+            }
+            writeln!(
+                xml,
+                " sample-start=\"{}\" sample-stop=\"{}\" tune=\"{}\" zone-logic=\"{}\">",
+                mapped.sample_start,
+                mapped.sample_end,
+                number(mapped.tune / 100.0),
+                match mapped.round_robin {
+                    true => "round-robin",
+                    false => "always-play",
+                }
+            )
+            .unwrap();
+            writeln!(
+                xml,
+                "        <key root=\"{}\" low=\"{}\" high=\"{}\" track=\"1.0\"/>",
+                mapped.root_key, mapped.low_key, mapped.high_key
+            )
+            .unwrap();
+            writeln!(
+                xml,
+                "        <velocity low=\"{}\" high=\"{}\"/>",
+                mapped.low_velocity, mapped.high_velocity
+            )
+            .unwrap();
+            if let Some(sample_loop) = &mapped.sample_loop {
+                writeln!(
+                    xml,
+                    "        <loop mode=\"{}\" start=\"{}\" stop=\"{}\"/>",
+                    match sample_loop.alternating {
+                        true => "ping-pong",
+                        false => "loop",
+                    },
+                    sample_loop.start,
+                    sample_loop.end
+                )
+                .unwrap();
+            }
+            xml.push_str("    </sample>\n");
+        }
+        xml.push_str("</multisample>\n");
+
+        zip.add("multisample.xml", xml.as_bytes())?;
+        zip.finish()
+    }
+}
+
+/// Numbers samples with the same name in different directories, avoiding
+/// the names of samples already in the archive and of the preset itself.
+fn unique_name(name: &str, files: &HashMap<String, String>) -> String {
+    let taken = |candidate: &str| {
+        candidate.eq_ignore_ascii_case("multisample.xml")
+            || files.values().any(|n| n.eq_ignore_ascii_case(candidate))
+    };
+    let mut unique = name.to_string();
+    let mut count = 1;
+    while taken(&unique) {
+        unique = format!("{count}-{name}");
+        count += 1;
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, fs::File};
+
+    use crate::NIFile;
+
+    use super::*;
+
+    #[test]
+    fn test_multisample_export() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Containers/NIS/files/kontakt/7.1.3.0/002-single-sample-2.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        let dir = std::env::temp_dir().join("ni-file-test-multisample");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("002-single-sample-2 Samples"))?;
+        fs::copy(
+            "tests/data/Containers/NCW/testfile-onezero-16-bit-stereo-multiblock.wav",
+            dir.join("002-single-sample-2 Samples/beep.wav"),
+        )?;
+
+        let zip = instrument.to_multisample(&dir)?;
+        fs::remove_dir_all(&dir)?;
+
+        // local header of the first entry
+        assert_eq!(&zip[0..4], &[0x50, 0x4B, 0x03, 0x04]);
+        assert_eq!(&zip[30..38], b"beep.wav");
+
+        let xml = String::from_utf8_lossy(&zip);
+        assert!(xml.contains("<group name=\"Group 1\"/>"));
+        assert!(xml.contains("<sample file=\"beep.wav\" gain=\"1.32\" group=\"0\""));
+        assert!(xml.contains("<key root=\"50\" low=\"35\" high=\"58\" track=\"1.0\"/>"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unique_name() {
+        let mut files = HashMap::new();
+        files.insert("A/C3.wav".to_string(), "C3.wav".to_string());
+        files.insert("1-C3.wav".to_string(), "1-C3.wav".to_string());
+
+        assert_eq!(unique_name("D3.wav", &files), "D3.wav");
+        assert_eq!(unique_name("c3.WAV", &files), "2-c3.WAV");
+        assert_eq!(unique_name("multisample.xml", &files), "1-multisample.xml");
+    }
+}
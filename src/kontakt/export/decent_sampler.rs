@@ -0,0 +1,251 @@
+use std::fmt::Write;
+
+use crate::kontakt::{InstrumentGroup, InstrumentZone, KontaktInstrument};
+
+use super::{cycles, escape_xml, gain_to_db, number, ratio_to_cents, sample_path, Cycle};
+
+/// Size of the DecentSampler UI, and of Kontakt wallpapers.
+const UI_WIDTH: u32 = 812;
+const UI_HEIGHT: u32 = 375;
+
+impl KontaktInstrument {
+    /// Converts the instrument to a DecentSampler preset (dspreset).
+    ///
+    /// `wallpaper` is the path of the background image relative to the
+    /// preset. The instrument wallpaper is named by
+    /// [`InstrumentMetadata::wallpaper`](crate::kontakt::instrument::InstrumentMetadata::wallpaper).
+    /// Amp envelopes are taken from the AHDSR envelope modulating the group
+    /// volume. DecentSampler has no hold stage, so hold times are dropped.
+    ///
+    /// ```no_run
+    /// use ni_file::{kontakt::KontaktInstrument, NIFile};
+    ///
+    /// let file = NIFile::read(std::fs::File::open("instrument.nki")?)?;
+    /// let instrument = KontaktInstrument::try_from(&file)?;
+    /// std::fs::write("instrument.dspreset", instrument.to_dspreset(None))?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_dspreset(&self, wallpaper: Option<&str>) -> String {
+        let mut xml = String::new();
+
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<DecentSampler minVersion=\"1.0.0\">\n");
+
+        write!(xml, "  <ui width=\"{UI_WIDTH}\" height=\"{UI_HEIGHT}\"").unwrap();
+        if let Some(wallpaper) = wallpaper {
+            write!(xml, " bgImage=\"{}\"", escape_xml(wallpaper)).unwrap();
+        }
+        xml.push_str(">\n    <tab name=\"main\">\n");
+        writeln!(
+            xml,
+            "      <label x=\"10\" y=\"10\" width=\"{}\" height=\"30\" text=\"{}\" textSize=\"24\" textColor=\"FFFFFFFF\" />",
+            UI_WIDTH - 20,
+            escape_xml(&self.name)
+        )
+        .unwrap();
+        xml.push_str("    </tab>\n  </ui>\n");
+
+        xml.push_str("  <groups");
+        write_attributes(
+            &mut xml,
+            &[
+                ("volume", db(self.metadata.volume)),
+                ("globalTuning", semitones(self.metadata.tune)),
+            ],
+        );
+        xml.push_str(">\n");
+
+        let cycles = cycles(&self.groups);
+        for (index, group) in self.groups.iter().enumerate() {
+            xml.push_str("    <group");
+            write_group(&mut xml, group, self.metadata.pan, cycles[index]);
+            xml.push_str(">\n");
+
+            for zone in self.zones.iter().filter(|zone| zone.group == index) {
+                write_zone(&mut xml, zone);
+            }
+            xml.push_str("    </group>\n");
+        }
+
+        let orphans: Vec<_> = self
+            .zones
+            .iter()
+            .filter(|zone| zone.group >= self.groups.len())
+            .collect();
+        if !orphans.is_empty() {
+            xml.push_str("    <group>\n");
+            for zone in orphans {
+                write_zone(&mut xml, zone);
+            }
+            xml.push_str("    </group>\n");
+        }
+
+        xml.push_str("  </groups>\n</DecentSampler>\n");
+        xml
+    }
+}
+
+fn write_group(
+    xml: &mut String,
+    group: &InstrumentGroup,
+    instrument_pan: f32,
+    cycle: Option<Cycle>,
+) {
+    write!(xml, " name=\"{}\"", escape_xml(&group.name)).unwrap();
+    write_attributes(
+        xml,
+        &[
+            ("volume", db(group.volume)),
+            // DecentSampler has no instrument pan
+            ("pan", pan((group.pan + instrument_pan).clamp(-1.0, 1.0))),
+            ("groupTuning", semitones(group.tune)),
+        ],
+    );
+
+    if group.release_trigger {
+        xml.push_str(" trigger=\"release\"");
+    }
+
+    if let Some(cycle) = cycle {
+        write!(
+            xml,
+            " seqMode=\"{}\" seqLength=\"{}\" seqPosition=\"{}\"",
+            if cycle.random {
+                "random"
+            } else {
+                "round_robin"
+            },
+            cycle.length,
+            cycle.position + 1
+        )
+        .unwrap();
+    }
+
+    if let Some(envelope) = &group.amp_envelope {
+        write!(
+            xml,
+            " attack=\"{}\" decay=\"{}\" sustain=\"{}\" release=\"{}\"",
+            seconds(envelope.attack),
+            seconds(envelope.decay),
+            number(envelope.sustain),
+            seconds(envelope.release)
+        )
+        .unwrap();
+    }
+}
+
+fn write_zone(xml: &mut String, zone: &InstrumentZone) {
+    xml.push_str("      <sample");
+    if let Some(sample) = &zone.sample {
+        write!(xml, " path=\"{}\"", escape_xml(&sample_path(sample))).unwrap();
+    }
+    write!(
+        xml,
+        " rootNote=\"{}\" loNote=\"{}\" hiNote=\"{}\" loVel=\"{}\" hiVel=\"{}\"",
+        zone.root_key, zone.low_key, zone.high_key, zone.low_velocity, zone.high_velocity
+    )
+    .unwrap();
+    write_attributes(
+        xml,
+        &[
+            ("volume", db(zone.volume)),
+            ("pan", pan(zone.pan)),
+            ("tuning", semitones(zone.tune)),
+        ],
+    );
+
+    if zone.sample_start > 0 {
+        write!(xml, " start=\"{}\"", zone.sample_start).unwrap();
+    }
+    if zone.sample_end > 0 {
+        write!(xml, " end=\"{}\"", zone.sample_end).unwrap();
+    }
+
+    if let Some(sample_loop) = zone.loops.iter().find(|l| l.mode != 0) {
+        write!(
+            xml,
+            " loopEnabled=\"true\" loopStart=\"{}\" loopEnd=\"{}\"",
+            sample_loop.loop_start,
+            sample_loop.loop_start + sample_loop.loop_length - 1
+        )
+        .unwrap();
+        if sample_loop.x_fade_length > 0 {
+            write!(xml, " loopCrossfade=\"{}\"", sample_loop.x_fade_length).unwrap();
+        }
+    }
+
+    xml.push_str(" />\n");
+}
+
+fn write_attributes(xml: &mut String, attributes: &[(&str, Option<String>)]) {
+    for (name, value) in attributes {
+        if let Some(value) = value {
+            write!(xml, " {name}=\"{value}\"").unwrap();
+        }
+    }
+}
+
+/// Decibels, `None` for unity gain.
+fn db(gain: f32) -> Option<String> {
+    let db = gain_to_db(gain);
+    (db.abs() >= 0.005).then(|| format!("{}dB", number(db)))
+}
+
+/// -100 to 100, `None` if centered.
+fn pan(pan: f32) -> Option<String> {
+    (pan != 0.0).then(|| number(pan * 100.0))
+}
+
+/// Semitones, `None` if not detuned.
+fn semitones(ratio: f32) -> Option<String> {
+    let semitones = ratio_to_cents(ratio) / 100.0;
+    (semitones.abs() >= 0.005).then(|| number(semitones))
+}
+
+/// Milliseconds to seconds, rounded to whole milliseconds.
+fn seconds(ms: f32) -> String {
+    (ms.round() / 1000.0).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::{Error, NIFile};
+
+    use super::*;
+
+    #[test]
+    fn test_dspreset_export() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Containers/NIS/files/kontakt/7.1.3.0/002-single-sample-2.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+        let xml = instrument.to_dspreset(Some("Resources/wallpaper.png"));
+
+        assert!(
+            xml.contains("<ui width=\"812\" height=\"375\" bgImage=\"Resources/wallpaper.png\">")
+        );
+        assert!(xml.contains(
+            "<group name=\"Group 1\" volume=\"6.12dB\" pan=\"22\" groupTuning=\"5.11\" attack=\"0.012\" decay=\"0.123\" sustain=\"1\" release=\"0.023\">"
+        ));
+        assert!(xml.contains(
+            "<sample path=\"002-single-sample-2 Samples/beep.wav\" rootNote=\"50\" loNote=\"35\" hiNote=\"58\""
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dspreset_export_round_robin() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NIS/files/kontakt/5.8.1.43/5.8.1.43-ncw.nki")?;
+        let xml = KontaktInstrument::try_from(&NIFile::read(file)?)?.to_dspreset(None);
+
+        assert!(xml.contains(
+            "<group name=\"VEL: 0-79 RR 3\" seqMode=\"round_robin\" seqLength=\"8\" seqPosition=\"3\""
+        ));
+        assert_eq!(
+            xml.matches("<group ").count(),
+            xml.matches("</group>").count()
+        );
+        Ok(())
+    }
+}
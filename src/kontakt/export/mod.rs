@@ -0,0 +1,117 @@
+//! Converts Kontakt instruments to open sampler formats.
+
+mod audio;
+mod bitwig;
+mod decent_sampler;
+mod sf2;
+mod sfz;
+mod zip;
+mod zone_map;
+
+pub use audio::{SampleData, SampleMapping};
+pub use zone_map::{MappedLoop, MappedZone};
+
+use super::{objects::BFileName, InstrumentGroup};
+
+/// Start criteria modes, see [`StartCriteriaParams::mode`](super::objects::StartCriteriaParams).
+pub(crate) const START_ON_KEY: i32 = 1;
+pub(crate) const START_ON_CONTROLLER: i32 = 2;
+pub(crate) const CYCLE_ROUND_ROBIN: i32 = 3;
+pub(crate) const CYCLE_RANDOM: i32 = 4;
+
+/// Linear gain to decibels.
+pub(crate) fn gain_to_db(gain: f32) -> f32 {
+    match gain {
+        gain if gain <= 0.0 => -144.0,
+        gain => 20.0 * gain.log10(),
+    }
+}
+
+/// Pitch ratio to cents.
+pub(crate) fn ratio_to_cents(ratio: f32) -> f32 {
+    match ratio {
+        ratio if ratio <= 0.0 => 0.0,
+        ratio => 1200.0 * ratio.log2(),
+    }
+}
+
+/// The sample path for a file next to the instrument. Drive letters are kept
+/// for absolute Windows paths.
+pub(crate) fn sample_path(path: &BFileName) -> String {
+    match path.segments.first() {
+        Some(super::objects::BFileNameSegment::Drive(drive)) if !drive.is_empty() => {
+            path.to_windows()
+        }
+        _ => path.to_posix(),
+    }
+}
+
+/// Rounds to 2 decimals.
+pub(crate) fn number(value: f32) -> String {
+    let value = (value * 100.0).round() / 100.0;
+    // avoid "-0"
+    if value == 0.0 {
+        String::from("0")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Escapes text for use in XML attributes.
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Position and length of a group in a round robin or random cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Cycle {
+    pub random: bool,
+    /// 0 based
+    pub position: usize,
+    pub length: usize,
+}
+
+/// The cycle of every group, `None` for groups that always play.
+pub(crate) fn cycles(groups: &[InstrumentGroup]) -> Vec<Option<Cycle>> {
+    let length = |mode: i32| {
+        groups
+            .iter()
+            .flat_map(|g| &g.start_criteria)
+            .filter(|c| c.mode == mode)
+            .map(|c| c.cycle_class.max(0) as usize + 1)
+            .max()
+            .unwrap_or(0)
+    };
+    let round_robin = length(CYCLE_ROUND_ROBIN);
+    let random = length(CYCLE_RANDOM);
+
+    groups
+        .iter()
+        .map(|group| {
+            group.start_criteria.iter().find_map(|c| match c.mode {
+                CYCLE_ROUND_ROBIN => Some(Cycle {
+                    random: false,
+                    position: c.cycle_class.max(0) as usize,
+                    length: round_robin,
+                }),
+                CYCLE_RANDOM => Some(Cycle {
+                    random: true,
+                    position: c.cycle_class.max(0) as usize,
+                    length: random,
+                }),
+                _ => None,
+            })
+        })
+        .collect()
+}
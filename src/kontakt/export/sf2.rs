@@ -0,0 +1,460 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::{
+    kontakt::{InstrumentZone, KontaktInstrument},
+    write_bytes::WriteBytesExt,
+    Error, NIFileError,
+};
+
+use super::{
+    audio::{write_riff_chunk, SampleData},
+    gain_to_db, ratio_to_cents,
+};
+
+// generator operators
+const START_ADDRS_OFFSET: u16 = 0;
+const END_ADDRS_OFFSET: u16 = 1;
+const STARTLOOP_ADDRS_OFFSET: u16 = 2;
+const ENDLOOP_ADDRS_OFFSET: u16 = 3;
+const START_ADDRS_COARSE_OFFSET: u16 = 4;
+const END_ADDRS_COARSE_OFFSET: u16 = 12;
+const PAN: u16 = 17;
+const INSTRUMENT: u16 = 41;
+const KEY_RANGE: u16 = 43;
+const VEL_RANGE: u16 = 44;
+const STARTLOOP_ADDRS_COARSE_OFFSET: u16 = 45;
+const INITIAL_ATTENUATION: u16 = 48;
+const ENDLOOP_ADDRS_COARSE_OFFSET: u16 = 50;
+const COARSE_TUNE: u16 = 51;
+const FINE_TUNE: u16 = 52;
+const SAMPLE_ID: u16 = 53;
+const SAMPLE_MODES: u16 = 54;
+const OVERRIDING_ROOT_KEY: u16 = 58;
+
+// sample types
+const MONO_SAMPLE: u16 = 1;
+const RIGHT_SAMPLE: u16 = 2;
+const LEFT_SAMPLE: u16 = 4;
+
+/// Zero samples written after every sample.
+const SAMPLE_PADDING: usize = 46;
+
+impl KontaktInstrument {
+    /// Converts the instrument to a SoundFont 2, with the samples embedded.
+    ///
+    /// Samples are loaded relative to `instrument_dir` and stored as 16 bit.
+    /// Groups become instruments, zones become instrument zones, stereo
+    /// samples are split into linked left and right samples. SoundFont
+    /// attenuation cannot boost, so positive gains are clamped to 0 dB.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use ni_file::{kontakt::KontaktInstrument, NIFile};
+    ///
+    /// let file = NIFile::read(std::fs::File::open("Instruments/instrument.nki")?)?;
+    /// let instrument = KontaktInstrument::try_from(&file)?;
+    /// std::fs::write("instrument.sf2", instrument.to_sf2(Path::new("Instruments"))?)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_sf2(&self, instrument_dir: &Path) -> Result<Vec<u8>, Error> {
+        let mut sample_data: Vec<i16> = Vec::new();
+        let mut headers: Vec<SampleHeader> = Vec::new();
+        // sample path to the headers of its channels and the number of frames
+        let mut loaded: HashMap<String, (Vec<u16>, i32)> = HashMap::new();
+
+        for zone in &self.zones {
+            let Some(path) = &zone.sample else {
+                continue;
+            };
+            let key = path.to_string();
+            if loaded.contains_key(&key) {
+                continue;
+            }
+
+            let file = path.to_path(instrument_dir);
+            let sample = SampleData::open(&file).map_err(|e| {
+                NIFileError::Generic(format!("Could not read sample {}: {e}", file.display()))
+            })?;
+            let name = path.file_name().unwrap_or_default();
+            let name = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+
+            let channels = sample.channels.clamp(1, 2) as usize;
+            let first = sf2_index(headers.len(), "samples")?;
+            let last = sf2_index(headers.len() + channels - 1, "samples")?;
+            for channel in 0..channels {
+                let start = sample_offset(sample_data.len())?;
+                sample_data.extend(sample.channel_i16(channel));
+                let end = sample_offset(sample_data.len())?;
+                sample_data.extend([0; SAMPLE_PADDING]);
+
+                let (sample_type, link, suffix) = match (channels, channel) {
+                    (1, _) => (MONO_SAMPLE, 0, ""),
+                    (_, 0) => (LEFT_SAMPLE, last, "L"),
+                    _ => (RIGHT_SAMPLE, first, "R"),
+                };
+                headers.push(SampleHeader {
+                    name: format!("{name}{suffix}"),
+                    start,
+                    end,
+                    sample_rate: sample.sample_rate,
+                    original_pitch: zone.root_key.clamp(0, 127) as u8,
+                    link,
+                    sample_type,
+                });
+            }
+            loaded.insert(key, ((first..=last).collect(), sample.num_frames() as i32));
+        }
+
+        // groups become instruments, zones without a group get their own
+        let mut instruments: Vec<(&str, Vec<&InstrumentZone>)> = self
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(index, group)| {
+                let zones = self.zones.iter().filter(|z| z.group == index).collect();
+                (group.name.as_str(), zones)
+            })
+            .collect();
+        let orphans: Vec<_> = self
+            .zones
+            .iter()
+            .filter(|z| z.group >= self.groups.len())
+            .collect();
+        if !orphans.is_empty() {
+            instruments.push((self.name.as_str(), orphans));
+        }
+
+        let mut inst = Vec::new();
+        let mut ibag = Vec::new();
+        let mut igen = Vec::new();
+        let mut generators = 0;
+        for (index, (name, zones)) in instruments.iter().enumerate() {
+            write_name(&mut inst, name)?;
+            inst.write_u16_le(sf2_index(ibag.len() / 4, "zones")?)?;
+
+            for zone in zones {
+                let Some((samples, frames)) = zone
+                    .sample
+                    .as_ref()
+                    .and_then(|path| loaded.get(&path.to_string()))
+                else {
+                    continue;
+                };
+                for (channel, sample_id) in samples.iter().enumerate() {
+                    ibag.write_u16_le(sf2_index(generators, "generators")?)?;
+                    ibag.write_u16_le(0)?;
+
+                    let gens = self.zone_generators(
+                        zone,
+                        index,
+                        *frames,
+                        (samples.len() > 1).then_some(channel),
+                        *sample_id,
+                    );
+                    for (operator, amount) in &gens {
+                        igen.write_u16_le(*operator)?;
+                        igen.write_bytes(amount)?;
+                    }
+                    generators += gens.len();
+                }
+            }
+        }
+        write_name(&mut inst, "EOI")?;
+        inst.write_u16_le(sf2_index(ibag.len() / 4, "zones")?)?;
+        ibag.write_u16_le(sf2_index(generators, "generators")?)?;
+        ibag.write_u16_le(0)?;
+        igen.write_bytes(&[0; 4])?;
+
+        // a single preset playing all instruments
+        let mut phdr = Vec::new();
+        let mut pbag = Vec::new();
+        let mut pgen = Vec::new();
+        write_name(&mut phdr, &self.name)?;
+        phdr.write_bytes(&[0; 6])?; // preset, bank, bag index
+        phdr.write_bytes(&[0; 12])?; // library, genre, morphology
+        let instrument_count = sf2_index(instruments.len(), "instruments")?;
+        for index in 0..instrument_count {
+            pbag.write_u16_le(index)?;
+            pbag.write_u16_le(0)?;
+            pgen.write_u16_le(INSTRUMENT)?;
+            pgen.write_u16_le(index)?;
+        }
+        write_name(&mut phdr, "EOP")?;
+        phdr.write_bytes(&[0; 4])?;
+        phdr.write_u16_le(instrument_count)?;
+        phdr.write_bytes(&[0; 12])?;
+        pbag.write_u16_le(instrument_count)?;
+        pbag.write_u16_le(0)?;
+        pgen.write_bytes(&[0; 4])?;
+
+        let mut shdr = Vec::new();
+        for header in &headers {
+            header.write(&mut shdr)?;
+        }
+        SampleHeader {
+            name: String::from("EOS"),
+            start: 0,
+            end: 0,
+            sample_rate: 0,
+            original_pitch: 0,
+            link: 0,
+            sample_type: 0,
+        }
+        .write(&mut shdr)?;
+
+        let mut info = Vec::new();
+        write_riff_chunk(&mut info, b"ifil", &[2, 0, 1, 0])?;
+        write_riff_chunk(&mut info, b"isng", &info_string("EMU8000"))?;
+        write_riff_chunk(&mut info, b"INAM", &info_string(&self.name))?;
+        if !self.metadata.author.is_empty() {
+            write_riff_chunk(&mut info, b"IENG", &info_string(&self.metadata.author))?;
+        }
+        write_riff_chunk(&mut info, b"ISFT", &info_string("ni-file"))?;
+
+        let mut smpl = Vec::with_capacity(sample_data.len() * 2);
+        for sample in sample_data {
+            smpl.write_i16_le(sample)?;
+        }
+        let mut sdta = Vec::new();
+        write_riff_chunk(&mut sdta, b"smpl", &smpl)?;
+
+        let mut pdta = Vec::new();
+        write_riff_chunk(&mut pdta, b"phdr", &phdr)?;
+        write_riff_chunk(&mut pdta, b"pbag", &pbag)?;
+        write_riff_chunk(&mut pdta, b"pmod", &[0; 10])?;
+        write_riff_chunk(&mut pdta, b"pgen", &pgen)?;
+        write_riff_chunk(&mut pdta, b"inst", &inst)?;
+        write_riff_chunk(&mut pdta, b"ibag", &ibag)?;
+        write_riff_chunk(&mut pdta, b"imod", &[0; 10])?;
+        write_riff_chunk(&mut pdta, b"igen", &igen)?;
+        write_riff_chunk(&mut pdta, b"shdr", &shdr)?;
+
+        let mut sfbk = b"sfbk".to_vec();
+        write_riff_chunk(&mut sfbk, b"LIST", &[&b"INFO"[..], &info].concat())?;
+        write_riff_chunk(&mut sfbk, b"LIST", &[&b"sdta"[..], &sdta].concat())?;
+        write_riff_chunk(&mut sfbk, b"LIST", &[&b"pdta"[..], &pdta].concat())?;
+
+        let mut sf2 = Vec::new();
+        write_riff_chunk(&mut sf2, b"RIFF", &sfbk)?;
+        Ok(sf2)
+    }
+
+    /// Generators of an instrument zone. `channel` is set for stereo samples.
+    fn zone_generators(
+        &self,
+        zone: &InstrumentZone,
+        group: usize,
+        frames: i32,
+        channel: Option<usize>,
+        sample_id: u16,
+    ) -> Vec<(u16, [u8; 2])> {
+        let group = self.groups.get(group);
+        let range = |low: i16, high: i16| [low.clamp(0, 127) as u8, high.clamp(0, 127) as u8];
+
+        let mut gens = vec![
+            (KEY_RANGE, range(zone.low_key, zone.high_key)),
+            (VEL_RANGE, range(zone.low_velocity, zone.high_velocity)),
+        ];
+        let mut push = |operator: u16, amount: i32| {
+            if amount != 0 {
+                gens.push((operator, (amount as i16).to_le_bytes()));
+            }
+        };
+
+        // centibels
+        let gain = self.metadata.volume * group.map_or(1.0, |g| g.volume) * zone.volume;
+        push(
+            INITIAL_ATTENUATION,
+            (-gain_to_db(gain) * 10.0).round().clamp(0.0, 1440.0) as i32,
+        );
+
+        // 0.1%, stereo samples are panned hard left and right
+        let pan = match channel {
+            Some(0) => -1.0,
+            Some(_) => 1.0,
+            None => self.metadata.pan + group.map_or(0.0, |g| g.pan) + zone.pan,
+        };
+        push(PAN, (pan.clamp(-1.0, 1.0) * 500.0).round() as i32);
+
+        let cents = ratio_to_cents(self.metadata.tune)
+            + group.map_or(0.0, |g| ratio_to_cents(g.tune))
+            + ratio_to_cents(zone.tune);
+        let cents = cents.round() as i32;
+        push(COARSE_TUNE, cents / 100);
+        push(FINE_TUNE, cents % 100);
+
+        push(OVERRIDING_ROOT_KEY, zone.root_key.clamp(0, 127) as i32);
+
+        let mut offset = |fine: u16, coarse: u16, value: i32| {
+            push(coarse, value / 32768);
+            push(fine, value % 32768);
+        };
+        offset(
+            START_ADDRS_OFFSET,
+            START_ADDRS_COARSE_OFFSET,
+            zone.sample_start,
+        );
+        if zone.sample_end > 0 && zone.sample_end < frames {
+            offset(
+                END_ADDRS_OFFSET,
+                END_ADDRS_COARSE_OFFSET,
+                zone.sample_end - frames,
+            );
+        }
+
+        // sample loops span the whole sample, zones move them with offsets
+        if let Some(sample_loop) = zone.loops.iter().find(|l| l.mode != 0) {
+            let loop_end = sample_loop.loop_start + sample_loop.loop_length;
+            offset(
+                STARTLOOP_ADDRS_OFFSET,
+                STARTLOOP_ADDRS_COARSE_OFFSET,
+                sample_loop.loop_start,
+            );
+            offset(
+                ENDLOOP_ADDRS_OFFSET,
+                ENDLOOP_ADDRS_COARSE_OFFSET,
+                loop_end.min(frames) - frames,
+            );
+            // 1: continuous, 3: until release
+            push(SAMPLE_MODES, if sample_loop.mode == 1 { 1 } else { 3 });
+        }
+
+        gens.push((SAMPLE_ID, sample_id.to_le_bytes()));
+        gens
+    }
+}
+
+struct SampleHeader {
+    name: String,
+    start: u32,
+    end: u32,
+    sample_rate: u32,
+    original_pitch: u8,
+    link: u16,
+    sample_type: u16,
+}
+
+impl SampleHeader {
+    fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        write_name(&mut writer, &self.name)?;
+        writer.write_u32_le(self.start)?;
+        writer.write_u32_le(self.end)?;
+        // loop start and end
+        writer.write_u32_le(self.start)?;
+        writer.write_u32_le(self.end)?;
+        writer.write_u32_le(self.sample_rate)?;
+        writer.write_u8(self.original_pitch)?;
+        writer.write_u8(0)?; // pitch correction
+        writer.write_u16_le(self.link)?;
+        writer.write_u16_le(self.sample_type)?;
+        Ok(())
+    }
+}
+
+/// Indices into the sample headers, zones and generators are 16 bit.
+fn sf2_index(index: usize, what: &str) -> Result<u16, Error> {
+    u16::try_from(index)
+        .map_err(|_| NIFileError::Generic(format!("Too many {what} for a SoundFont: {index}")))
+}
+
+/// Sample positions are 32 bit.
+fn sample_offset(offset: usize) -> Result<u32, Error> {
+    u32::try_from(offset).map_err(|_| {
+        NIFileError::Generic(format!("Too much sample data for a SoundFont: {offset}"))
+    })
+}
+
+/// 20 byte ascii name, zero padded.
+fn write_name<W: WriteBytesExt>(mut writer: W, name: &str) -> Result<(), Error> {
+    let mut bytes = [0u8; 20];
+    for (byte, c) in bytes.iter_mut().zip(name.chars().take(19)) {
+        *byte = if c.is_ascii() { c as u8 } else { b'_' };
+    }
+    writer.write_bytes(&bytes)?;
+    Ok(())
+}
+
+/// Zero terminated, padded to an even length.
+fn info_string(value: &str) -> Vec<u8> {
+    let mut bytes: Vec<u8> = value.bytes().take(255).collect();
+    bytes.push(0);
+    if bytes.len() % 2 == 1 {
+        bytes.push(0);
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+
+    use crate::{read_bytes::ReadBytesExt, NIFile};
+
+    use super::*;
+
+    /// Returns the data of the first chunk with the id.
+    fn find_chunk<'a>(sf2: &'a [u8], id: &[u8; 4]) -> &'a [u8] {
+        let pos = sf2.windows(4).position(|w| w == id).unwrap();
+        let size = u32::from_le_bytes(sf2[pos + 4..pos + 8].try_into().unwrap()) as usize;
+        &sf2[pos + 8..pos + 8 + size]
+    }
+
+    #[test]
+    fn test_sf2_export() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Containers/NIS/files/kontakt/7.1.3.0/002-single-sample-2.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        let dir = std::env::temp_dir().join("ni-file-test-sf2");
+        let _ = fs::remove_dir_all(&dir);
+        assert!(instrument.to_sf2(&dir).is_err());
+
+        // stereo, 16 bit
+        let mut fmt = Vec::new();
+        for value in [1u16, 2] {
+            fmt.write_u16_le(value)?;
+        }
+        fmt.write_u32_le(44100)?;
+        fmt.write_u32_le(44100 * 4)?;
+        fmt.write_u16_le(4)?;
+        fmt.write_u16_le(16)?;
+        let data: Vec<u8> = (0..1000i16)
+            .flat_map(|s| [s, -s])
+            .flat_map(i16::to_le_bytes)
+            .collect();
+        let mut wave = b"WAVE".to_vec();
+        write_riff_chunk(&mut wave, b"fmt ", &fmt)?;
+        write_riff_chunk(&mut wave, b"data", &data)?;
+        let mut wav = Vec::new();
+        write_riff_chunk(&mut wav, b"RIFF", &wave)?;
+
+        fs::create_dir_all(dir.join("002-single-sample-2 Samples"))?;
+        fs::write(dir.join("002-single-sample-2 Samples/beep.wav"), wav)?;
+
+        let sf2 = instrument.to_sf2(&dir)?;
+        assert_eq!(&sf2[0..4], b"RIFF");
+        assert_eq!(&sf2[8..12], b"sfbk");
+        assert_eq!(find_chunk(&sf2, b"smpl").len(), (1000 + 46) * 2 * 2);
+
+        // left, right and the terminal sample
+        let shdr = find_chunk(&sf2, b"shdr");
+        assert_eq!(shdr.len(), 46 * 3);
+        assert_eq!(&shdr[0..6], b"beepL\0");
+        assert_eq!(&shdr[44..46], &LEFT_SAMPLE.to_le_bytes());
+
+        let mut igen = std::io::Cursor::new(find_chunk(&sf2, b"igen"));
+        assert_eq!(igen.read_u16_le()?, KEY_RANGE);
+        assert_eq!(igen.read_bytes(2)?, [35, 58]);
+        assert_eq!(igen.read_u16_le()?, VEL_RANGE);
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_sf2_index_limits() {
+        assert_eq!(sf2_index(65535, "zones").unwrap(), 65535);
+        assert!(sf2_index(65536, "zones").is_err());
+        assert!(sample_offset(u32::MAX as usize + 1).is_err());
+    }
+}
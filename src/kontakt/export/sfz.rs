@@ -0,0 +1,228 @@
+use std::fmt::Write;
+
+use crate::kontakt::{InstrumentGroup, InstrumentZone, KontaktInstrument};
+
+use super::{
+    cycles, number, sample_path, zone_map::MappedZone, Cycle, START_ON_CONTROLLER, START_ON_KEY,
+};
+
+impl KontaktInstrument {
+    /// Converts the instrument to an SFZ document.
+    ///
+    /// Sample paths are relative to the instrument, so the document should be
+    /// saved next to it. NCW samples need to be decoded to WAV to be played by
+    /// other samplers.
+    ///
+    /// Region opcodes replace the group and global ones in SFZ, so volume,
+    /// pan and tune of the instrument, group and zone are combined and only
+    /// written on regions, see [`KontaktInstrument::zone_map`].
+    ///
+    /// ```no_run
+    /// use ni_file::{kontakt::KontaktInstrument, NIFile};
+    ///
+    /// let file = NIFile::read(std::fs::File::open("instrument.nki")?)?;
+    /// let instrument = KontaktInstrument::try_from(&file)?;
+    /// std::fs::write("instrument.sfz", instrument.to_sfz())?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_sfz(&self) -> String {
+        let mut sfz = String::new();
+
+        writeln!(sfz, "// {}", self.name).unwrap();
+        if !self.metadata.author.is_empty() {
+            writeln!(sfz, "// author: {}", self.metadata.author).unwrap();
+        }
+
+        let mapped = self.zone_map();
+        let zones: Vec<_> = self.zones.iter().zip(&mapped).collect();
+
+        let cycles = cycles(&self.groups);
+        for (index, group) in self.groups.iter().enumerate() {
+            sfz.push_str("\n<group>\n");
+            write_group(&mut sfz, group, cycles[index]);
+
+            for (zone, mapped) in zones.iter().filter(|(zone, _)| zone.group == index) {
+                sfz.push_str("<region>");
+                write_zone(&mut sfz, zone, mapped);
+            }
+        }
+
+        let orphans: Vec<_> = zones
+            .iter()
+            .filter(|(zone, _)| zone.group >= self.groups.len())
+            .collect();
+        if !orphans.is_empty() {
+            sfz.push_str("\n<group>\n");
+            for (zone, mapped) in orphans {
+                sfz.push_str("<region>");
+                write_zone(&mut sfz, zone, mapped);
+            }
+        }
+
+        sfz
+    }
+}
+
+fn write_group(sfz: &mut String, group: &InstrumentGroup, cycle: Option<Cycle>) {
+    writeln!(sfz, "group_label={}", group.name).unwrap();
+
+    if group.release_trigger {
+        sfz.push_str("trigger=release\n");
+    }
+
+    match cycle {
+        Some(Cycle {
+            random: false,
+            position,
+            length,
+        }) => writeln!(sfz, "seq_length={length} seq_position={}", position + 1).unwrap(),
+        Some(Cycle {
+            random: true,
+            position,
+            length,
+        }) => writeln!(
+            sfz,
+            "lorand={} hirand={}",
+            number(position as f32 / length as f32),
+            number((position + 1) as f32 / length as f32)
+        )
+        .unwrap(),
+        None => {}
+    }
+
+    for criteria in &group.start_criteria {
+        match criteria.mode {
+            START_ON_KEY => writeln!(
+                sfz,
+                "sw_lolast={} sw_hilast={}",
+                criteria.key_min, criteria.key_max
+            )
+            .unwrap(),
+            START_ON_CONTROLLER => writeln!(
+                sfz,
+                "locc{cc}={} hicc{cc}={}",
+                criteria.cc_min,
+                criteria.cc_max,
+                cc = criteria.controller
+            )
+            .unwrap(),
+            _ => {}
+        }
+    }
+}
+
+fn write_zone(sfz: &mut String, zone: &InstrumentZone, mapped: &MappedZone) {
+    if let Some(sample) = &zone.sample {
+        write!(sfz, " sample={}", sample_path(sample)).unwrap();
+    }
+    write!(
+        sfz,
+        " lokey={} hikey={} pitch_keycenter={} lovel={} hivel={}",
+        zone.low_key, zone.high_key, zone.root_key, zone.low_velocity, zone.high_velocity
+    )
+    .unwrap();
+
+    // tune is limited to -100 to 100 cents, whole semitones go to transpose
+    let transpose = (mapped.tune / 100.0).round();
+    for (opcode, value) in [
+        (
+            "volume",
+            (mapped.gain.abs() >= 0.005).then(|| number(mapped.gain)),
+        ),
+        (
+            "pan",
+            (mapped.pan != 0.0).then(|| number(mapped.pan * 100.0)),
+        ),
+        ("transpose", (transpose != 0.0).then(|| number(transpose))),
+        ("tune", cents(mapped.tune - transpose * 100.0)),
+    ] {
+        if let Some(value) = value {
+            write!(sfz, " {opcode}={value}").unwrap();
+        }
+    }
+
+    if zone.sample_start > 0 {
+        write!(sfz, " offset={}", zone.sample_start).unwrap();
+    }
+    if zone.sample_end > 0 {
+        write!(sfz, " end={}", zone.sample_end).unwrap();
+    }
+
+    // Until End keeps looping after note off like loop_continuous, Until
+    // Release (assumed to be mode 2, see Loop::mode) like loop_sustain
+    let sample_loop = zone.loops.iter().find_map(|l| match l.mode {
+        1 => Some((l, "loop_continuous")),
+        2 => Some((l, "loop_sustain")),
+        _ => None,
+    });
+    if let Some((sample_loop, loop_mode)) = sample_loop {
+        write!(
+            sfz,
+            " loop_mode={loop_mode} loop_start={} loop_end={}",
+            sample_loop.loop_start,
+            sample_loop.loop_start + sample_loop.loop_length - 1
+        )
+        .unwrap();
+        if sample_loop.alternating_loop {
+            sfz.push_str(" loop_type=alternate");
+        }
+        if sample_loop.x_fade_length > 0 {
+            write!(
+                sfz,
+                " loop_crossfade={}",
+                number(sample_loop.x_fade_length as f32 / zone.sample_rate.max(1) as f32)
+            )
+            .unwrap();
+        }
+    }
+
+    sfz.push('\n');
+}
+
+/// Cents, `None` if not detuned.
+fn cents(cents: f32) -> Option<String> {
+    (cents.abs() >= 0.005).then(|| number(cents))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::{Error, NIFile};
+
+    use super::*;
+
+    #[test]
+    fn test_sfz_export() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Containers/NIS/files/kontakt/7.1.3.0/002-single-sample-2.nki")?;
+        let sfz = KontaktInstrument::try_from(&NIFile::read(file)?)?.to_sfz();
+
+        assert!(sfz.contains("<group>\ngroup_label=Group 1\n<region>"));
+        assert!(sfz.contains(
+            "<region> sample=002-single-sample-2 Samples/beep.wav lokey=35 hikey=58 pitch_keycenter=50"
+        ));
+        assert!(sfz.contains(" volume=1.32 pan=23.1 transpose=7 tune=31\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sfz_export_round_robin() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NIS/files/kontakt/5.8.1.43/5.8.1.43-ncw.nki")?;
+        let sfz = KontaktInstrument::try_from(&NIFile::read(file)?)?.to_sfz();
+
+        assert!(sfz.contains("group_label=VEL: 0-79 RR 3\nseq_length=8 seq_position=3\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sfz_export_release_trigger() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NKS/KontaktV42/KontaktV42-000.nki")?;
+        let sfz = KontaktInstrument::try_from(&NIFile::read(file)?)?.to_sfz();
+
+        assert!(sfz.contains("group_label=note off\n"));
+        assert!(sfz.contains("trigger=release\n"));
+        assert_eq!(sfz.matches("<region>").count(), 414);
+        Ok(())
+    }
+}
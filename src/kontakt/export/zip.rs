@@ -0,0 +1,104 @@
+use flate2::Crc;
+
+use crate::{write_bytes::WriteBytesExt, Error, NIFileError};
+
+/// Writes zip archives with uncompressed (stored) entries. Without zip64,
+/// archives are limited to 4 GiB and 65535 entries.
+#[derive(Default)]
+pub(crate) struct ZipWriter {
+    data: Vec<u8>,
+    directory: Vec<u8>,
+    entries: u16,
+}
+
+impl ZipWriter {
+    pub fn add(&mut self, name: &str, contents: &[u8]) -> Result<(), Error> {
+        let mut crc = Crc::new();
+        crc.update(contents);
+        let offset = zip_size(self.data.len())?;
+        let size = zip_size(contents.len())?;
+        let name_len = u16::try_from(name.len())
+            .map_err(|_| NIFileError::Generic(format!("Zip entry name too long: {name}")))?;
+        self.entries = self
+            .entries
+            .checked_add(1)
+            .ok_or(NIFileError::Static("Too many zip entries"))?;
+
+        // local file header
+        self.data.write_u32_le(0x04034B50)?;
+        write_entry_header(&mut self.data, name_len, crc.sum(), size)?;
+        self.data.write_bytes(name.as_bytes())?;
+        self.data.write_bytes(contents)?;
+
+        // central directory header
+        self.directory.write_u32_le(0x02014B50)?;
+        self.directory.write_u16_le(20)?; // version made by
+        write_entry_header(&mut self.directory, name_len, crc.sum(), size)?;
+        self.directory.write_u16_le(0)?; // comment length
+        self.directory.write_u16_le(0)?; // disk number
+        self.directory.write_u16_le(0)?; // internal attributes
+        self.directory.write_u32_le(0)?; // external attributes
+        self.directory.write_u32_le(offset)?;
+        self.directory.write_bytes(name.as_bytes())?;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<Vec<u8>, Error> {
+        let offset = zip_size(self.data.len())?;
+        let directory_size = zip_size(self.directory.len())?;
+        zip_size(self.data.len() + self.directory.len())?;
+        self.data.write_bytes(&self.directory)?;
+
+        // end of central directory
+        self.data.write_u32_le(0x06054B50)?;
+        self.data.write_u16_le(0)?; // disk number
+        self.data.write_u16_le(0)?; // disk with the directory
+        self.data.write_u16_le(self.entries)?;
+        self.data.write_u16_le(self.entries)?;
+        self.data.write_u32_le(directory_size)?;
+        self.data.write_u32_le(offset)?;
+        self.data.write_u16_le(0)?; // comment length
+        Ok(self.data)
+    }
+}
+
+/// Fields shared by local and central directory headers.
+fn write_entry_header<W: WriteBytesExt>(
+    mut writer: W,
+    name_len: u16,
+    crc: u32,
+    size: u32,
+) -> Result<(), Error> {
+    writer.write_u16_le(20)?; // version needed
+    writer.write_u16_le(1 << 11)?; // utf-8 names
+    writer.write_u16_le(0)?; // stored
+    writer.write_u16_le(0)?; // time
+    writer.write_u16_le(0x21)?; // date, 1980-01-01
+    writer.write_u32_le(crc)?;
+    writer.write_u32_le(size)?; // compressed
+    writer.write_u32_le(size)?; // uncompressed
+    writer.write_u16_le(name_len)?;
+    writer.write_u16_le(0)?; // extra field length
+    Ok(())
+}
+
+/// Sizes and offsets are 32 bit.
+fn zip_size(size: usize) -> Result<u32, Error> {
+    u32::try_from(size).map_err(|_| NIFileError::Generic(format!("Zip archive too large: {size}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_entry_limit() -> Result<(), Error> {
+        let mut zip = ZipWriter::default();
+        for _ in 0..u16::MAX {
+            zip.add("a", &[])?;
+        }
+        assert!(zip.add("a", &[]).is_err());
+        assert!(zip.finish().is_ok());
+        Ok(())
+    }
+}
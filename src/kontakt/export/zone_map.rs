@@ -0,0 +1,296 @@
+use std::fmt::Write;
+
+use crate::kontakt::KontaktInstrument;
+
+use super::{audio::SampleMapping, cycles, gain_to_db, number, ratio_to_cents, sample_path};
+
+/// A zone with the instrument, group and zone settings combined, for
+/// samplers without instrument or group settings.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MappedZone {
+    /// Empty if the zone has no group.
+    pub group: String,
+    /// Relative to the instrument, `None` if the file is missing from the
+    /// filetable.
+    pub sample: Option<String>,
+    pub low_key: i16,
+    pub high_key: i16,
+    pub root_key: i16,
+    pub low_velocity: i16,
+    pub high_velocity: i16,
+    /// Decibels.
+    pub gain: f32,
+    /// Cents.
+    pub tune: f32,
+    /// -1.0 to 1.0
+    pub pan: f32,
+    pub sample_start: i32,
+    /// Exclusive, the number of frames if the full sample is played.
+    pub sample_end: i32,
+    pub sample_loop: Option<MappedLoop>,
+    /// The group is part of a round robin or random cycle.
+    pub round_robin: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MappedLoop {
+    pub start: i32,
+    /// Exclusive
+    pub end: i32,
+    pub alternating: bool,
+    /// Frames
+    pub crossfade: i32,
+}
+
+const CSV_HEADER: &str = "group,sample,low_key,high_key,root_key,low_velocity,high_velocity,gain,tune,pan,sample_start,sample_end,loop_start,loop_end,loop_alternating,loop_crossfade,round_robin";
+
+impl KontaktInstrument {
+    /// The zones of the instrument, in the order of [`KontaktInstrument::zones`].
+    pub fn zone_map(&self) -> Vec<MappedZone> {
+        let cycles = cycles(&self.groups);
+
+        self.zones
+            .iter()
+            .map(|zone| {
+                let group = self.groups.get(zone.group);
+                MappedZone {
+                    group: group.map(|g| g.name.clone()).unwrap_or_default(),
+                    sample: zone.sample.as_ref().map(sample_path),
+                    low_key: zone.low_key,
+                    high_key: zone.high_key,
+                    root_key: zone.root_key,
+                    low_velocity: zone.low_velocity,
+                    high_velocity: zone.high_velocity,
+                    gain: gain_to_db(
+                        self.metadata.volume * group.map_or(1.0, |g| g.volume) * zone.volume,
+                    ),
+                    tune: ratio_to_cents(self.metadata.tune)
+                        + group.map_or(0.0, |g| ratio_to_cents(g.tune))
+                        + ratio_to_cents(zone.tune),
+                    pan: (self.metadata.pan + group.map_or(0.0, |g| g.pan) + zone.pan)
+                        .clamp(-1.0, 1.0),
+                    sample_start: zone.sample_start,
+                    sample_end: match zone.sample_end {
+                        0 => zone.num_frames,
+                        end => end,
+                    },
+                    sample_loop: zone.loops.iter().find(|l| l.mode != 0).map(|l| MappedLoop {
+                        start: l.loop_start,
+                        end: l.loop_start + l.loop_length,
+                        alternating: l.alternating_loop,
+                        crossfade: l.x_fade_length,
+                    }),
+                    round_robin: cycles.get(zone.group).is_some_and(Option::is_some),
+                }
+            })
+            .collect()
+    }
+
+    /// The mapping of a sample for embedding in a wav file, `None` if no zone
+    /// references it. `path` is the sample path relative to the container,
+    /// e.g. the file name of a monolith item, and matches the end of the
+    /// filetable path. Extensions are ignored, so decoded ncw samples match
+    /// their zones. Root key, tune, gain and loop are taken from the first
+    /// zone, key and velocity ranges cover all zones.
+    pub fn sample_mapping(&self, path: &str) -> Option<SampleMapping> {
+        let normalize = |path: &str| {
+            let path = path.replace('\\', "/").to_lowercase();
+            match path.rsplit_once('.') {
+                Some((stem, ext)) if !ext.contains('/') => stem.to_string(),
+                _ => path,
+            }
+        };
+        let target = normalize(path);
+        let target = target.trim_start_matches("./");
+
+        let zones: Vec<MappedZone> = self
+            .zone_map()
+            .into_iter()
+            .filter(|zone| {
+                zone.sample.as_deref().map(normalize).is_some_and(|sample| {
+                    sample == target || sample.ends_with(&format!("/{target}"))
+                })
+            })
+            .collect();
+        let first = zones.first()?;
+
+        let midi = |value: i16| value.clamp(0, 127) as u8;
+        Some(SampleMapping {
+            root_key: midi(first.root_key),
+            tune: first.tune,
+            gain: first.gain,
+            low_key: zones.iter().map(|z| midi(z.low_key)).min()?,
+            high_key: zones.iter().map(|z| midi(z.high_key)).max()?,
+            low_velocity: zones.iter().map(|z| midi(z.low_velocity)).min()?,
+            high_velocity: zones.iter().map(|z| midi(z.high_velocity)).max()?,
+            loops: first.sample_loop.iter().cloned().collect(),
+        })
+    }
+
+    /// The zone map as CSV, one zone per row.
+    pub fn to_zone_map_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+
+        for zone in self.zone_map() {
+            let (loop_start, loop_end, alternating, crossfade) = match &zone.sample_loop {
+                Some(l) => (
+                    l.start.to_string(),
+                    l.end.to_string(),
+                    l.alternating.to_string(),
+                    l.crossfade.to_string(),
+                ),
+                None => Default::default(),
+            };
+            let row = [
+                csv_field(&zone.group),
+                csv_field(zone.sample.as_deref().unwrap_or_default()),
+                zone.low_key.to_string(),
+                zone.high_key.to_string(),
+                zone.root_key.to_string(),
+                zone.low_velocity.to_string(),
+                zone.high_velocity.to_string(),
+                number(zone.gain),
+                number(zone.tune),
+                number(zone.pan),
+                zone.sample_start.to_string(),
+                zone.sample_end.to_string(),
+                loop_start,
+                loop_end,
+                alternating,
+                crossfade,
+                zone.round_robin.to_string(),
+            ];
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// The zone map as a JSON document.
+    pub fn to_zone_map_json(&self) -> String {
+        let mut json = String::from("{\n");
+        writeln!(json, "  \"name\": {},", json_string(&self.name)).unwrap();
+        json.push_str("  \"zones\": [");
+
+        for (i, zone) in self.zone_map().iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("\n    {");
+            write!(
+                json,
+                "\"group\": {}, \"sample\": {}, ",
+                json_string(&zone.group),
+                zone.sample
+                    .as_deref()
+                    .map_or(String::from("null"), json_string)
+            )
+            .unwrap();
+            write!(
+                json,
+                "\"low_key\": {}, \"high_key\": {}, \"root_key\": {}, \"low_velocity\": {}, \"high_velocity\": {}, ",
+                zone.low_key, zone.high_key, zone.root_key, zone.low_velocity, zone.high_velocity
+            )
+            .unwrap();
+            write!(
+                json,
+                "\"gain\": {}, \"tune\": {}, \"pan\": {}, \"sample_start\": {}, \"sample_end\": {}, ",
+                number(zone.gain),
+                number(zone.tune),
+                number(zone.pan),
+                zone.sample_start,
+                zone.sample_end
+            )
+            .unwrap();
+            match &zone.sample_loop {
+                Some(l) => write!(
+                    json,
+                    "\"loop\": {{\"start\": {}, \"end\": {}, \"alternating\": {}, \"crossfade\": {}}}, ",
+                    l.start, l.end, l.alternating, l.crossfade
+                )
+                .unwrap(),
+                None => json.push_str("\"loop\": null, "),
+            }
+            write!(json, "\"round_robin\": {}}}", zone.round_robin).unwrap();
+        }
+
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::{Error, NIFile};
+
+    use super::*;
+
+    #[test]
+    fn test_zone_map() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Containers/NIS/files/kontakt/7.1.3.0/002-single-sample-2.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        let zones = instrument.zone_map();
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].group, "Group 1");
+        assert_eq!(number(zones[0].gain), "1.32");
+        assert_eq!(number(zones[0].tune), "731");
+
+        let csv = instrument.to_zone_map_csv();
+        assert!(csv.starts_with(CSV_HEADER));
+        assert!(csv.contains("\nGroup 1,002-single-sample-2 Samples/beep.wav,35,58,50,"));
+
+        let json = instrument.to_zone_map_json();
+        assert!(json.contains(
+            "{\"group\": \"Group 1\", \"sample\": \"002-single-sample-2 Samples/beep.wav\", \"low_key\": 35,"
+        ));
+
+        let mapping = instrument.sample_mapping("Beep.ncw").unwrap();
+        assert_eq!(
+            (mapping.root_key, mapping.low_key, mapping.high_key),
+            (50, 35, 58)
+        );
+        assert!(instrument
+            .sample_mapping("002-single-sample-2 Samples\\beep.ncw")
+            .is_some());
+        assert!(instrument.sample_mapping("Other/beep.ncw").is_none());
+        assert!(instrument.sample_mapping("other.wav").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_zone_map_escaping() {
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+        assert_eq!(json_string("a\"\\\n"), "\"a\\\"\\\\\\n\"");
+    }
+}
@@ -1,9 +1,518 @@
-use super::{objects::BPatchHeader, schemas::KontaktPreset};
+use std::collections::HashMap;
 
-#[deprecated]
+use crate::{Error, NIFile};
+
+use super::{
+    objects::{
+        BFileName, BParScriptParams, Effect, EnvelopeAHDSRParams, GlideParams, Group, LFOParams,
+        Loop, ModSource, Program, SourceMode, StartCriteriaParams, Zone,
+    },
+    schemas::KontaktPreset,
+};
+
+/// A Kontakt instrument, independent of the preset version it was read from.
+///
+/// Kontakt 1 and 2 presets are XML documents and only provide part of the
+/// instrument, see [`XMLDocument::instrument`](super::schemas::XMLDocument::instrument).
+///
+/// ```no_run
+/// use ni_file::{kontakt::KontaktInstrument, NIFile};
+///
+/// let file = NIFile::read(std::fs::File::open("instrument.nki")?)?;
+/// let instrument = KontaktInstrument::try_from(&file)?;
+///
+/// for zone in &instrument.zones {
+///     println!("{:?}", zone.sample);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KontaktInstrument {
-    pub header: BPatchHeader,
-    pub preset: KontaktPreset,
-    // pub sound_info: SoundInfoItem,
+    pub name: String,
+    pub metadata: InstrumentMetadata,
+    pub groups: Vec<InstrumentGroup>,
+    pub zones: Vec<InstrumentZone>,
+    pub scripts: Vec<BParScriptParams>,
+    pub insert_effects: Vec<Effect>,
+    pub send_effects: Vec<Effect>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InstrumentMetadata {
+    pub author: String,
+    pub url: String,
+    pub credits: String,
+    pub categories: [i16; 3],
+    pub library_id: i32,
+    pub volume: f32,
+    pub pan: f32,
+    pub tune: f32,
+    /// The wallpaper picture, relative to the instrument. Stored on disk or
+    /// in the resource container, see [`NKRContainer::wallpaper`](crate::nkr::NKRContainer::wallpaper).
+    pub wallpaper: Option<BFileName>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InstrumentGroup {
+    pub name: String,
+    pub volume: f32,
+    pub pan: f32,
+    pub tune: f32,
+    pub muted: bool,
+    /// Triggered on note off.
+    pub release_trigger: bool,
+    /// Conditions for the group to play, e.g. round robin.
+    pub start_criteria: Vec<StartCriteriaParams>,
+    pub source_mode: SourceMode,
+    /// Insert effects.
+    pub effects: Vec<Effect>,
+    pub modulators: Vec<Modulator>,
+    /// The AHDSR envelope modulating the volume, if there is one.
+    pub amp_envelope: Option<EnvelopeAHDSRParams>,
+}
+
+/// A single modulation target of a group.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Modulator {
+    /// e.g. "ENV_AHDSR", "LFO_SINE", "VEL_VOLUME"
+    pub source: String,
+    pub source_params: ModulatorSource,
+    /// e.g. "volume", "pitch"
+    pub target: String,
+    pub intensity: f32,
+    pub bypass: bool,
+}
+
+/// The parameters of a [`Modulator`] source.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModulatorSource {
+    EnvelopeAHDSR(EnvelopeAHDSRParams),
+    LFO(LFOParams),
+    Glide(GlideParams),
+    /// A MIDI source, see [`ExternalModParams::source`](super::objects::ExternalModParams).
+    External {
+        source: i32,
+        cc_number: Option<u8>,
+    },
+    /// An envelope other than AHDSR, or a source with unknown parameters.
+    Unknown,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InstrumentZone {
+    /// Index into [`KontaktInstrument::groups`].
+    pub group: usize,
+    /// The sample path, `None` if the file is missing from the filetable.
+    pub sample: Option<BFileName>,
+    pub filename_id: i32,
+    pub root_key: i16,
+    pub low_key: i16,
+    pub high_key: i16,
+    pub low_velocity: i16,
+    pub high_velocity: i16,
+    pub volume: f32,
+    pub pan: f32,
+    pub tune: f32,
+    pub sample_start: i32,
+    pub sample_end: i32,
+    pub sample_rate: i32,
+    pub num_channels: u8,
+    pub num_frames: i32,
+    pub loops: Vec<Loop>,
+}
+
+impl KontaktInstrument {
+    /// Builds the instrument from a program and the sample table of its
+    /// filetable.
+    pub fn from_program(
+        program: &Program,
+        sample_filetable: &HashMap<u32, BFileName>,
+    ) -> Result<Self, Error> {
+        let params = program.params()?;
+
+        let groups = match program.group_list() {
+            Some(list) => list?
+                .groups
+                .iter()
+                .map(InstrumentGroup::try_from)
+                .collect::<Result<Vec<_>, Error>>()?,
+            None => Vec::new(),
+        };
+
+        let mut zones = Vec::new();
+        if let Some(list) = program.zone_list() {
+            let list = list?;
+            for (zone, group) in list.zones().iter().zip(list.group_indices()) {
+                zones.push(InstrumentZone::read(
+                    zone,
+                    *group as usize,
+                    sample_filetable,
+                )?);
+            }
+        }
+
+        let scripts = program
+            .scripts()?
+            .iter()
+            .map(|script| script.params())
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut fx_chains = program.fx_chains()?.into_iter();
+        let insert_effects = match fx_chains.next() {
+            Some(chain) => chain.fx_items()?,
+            None => Vec::new(),
+        };
+        let send_effects = match fx_chains.next() {
+            Some(chain) => chain.fx_items()?,
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            name: params.name,
+            metadata: InstrumentMetadata {
+                author: params.instrument_author,
+                url: params.instrument_url,
+                credits: params.instrument_credits,
+                categories: [
+                    params.instrument_cat1,
+                    params.instrument_cat2,
+                    params.instrument_cat3,
+                ],
+                library_id: params.library_id,
+                volume: params.volume,
+                pan: params.pan,
+                tune: params.tune,
+                wallpaper: None,
+            },
+            groups,
+            zones,
+            scripts,
+            insert_effects,
+            send_effects,
+        })
+    }
+}
+
+impl KontaktInstrument {
+    /// Like [`KontaktInstrument::from_program`], with the wallpaper taken
+    /// from the other filetable.
+    fn from_preset(
+        program: &Program,
+        sample_filetable: &HashMap<u32, BFileName>,
+        other_filetable: &HashMap<u32, BFileName>,
+    ) -> Result<Self, Error> {
+        let mut instrument = Self::from_program(program, sample_filetable)?;
+        instrument.metadata.wallpaper = program
+            .params()?
+            .wallpaper_filename
+            .and_then(|id| u32::try_from(id).ok())
+            .and_then(|id| other_filetable.get(&id))
+            .cloned();
+        Ok(instrument)
+    }
+}
+
+impl InstrumentZone {
+    fn read(
+        zone: &Zone,
+        group: usize,
+        sample_filetable: &HashMap<u32, BFileName>,
+    ) -> Result<Self, Error> {
+        let params = zone.params()?;
+
+        Ok(Self {
+            group,
+            sample: u32::try_from(params.filename_id)
+                .ok()
+                .and_then(|id| sample_filetable.get(&id))
+                .cloned(),
+            filename_id: params.filename_id,
+            root_key: params.root_key,
+            low_key: params.low_key,
+            high_key: params.high_key,
+            low_velocity: params.low_velocity,
+            high_velocity: params.high_velocity,
+            volume: params.zone_volume,
+            pan: params.zone_pan,
+            tune: params.zone_tune,
+            sample_start: params.sample_start,
+            sample_end: params.sample_end,
+            sample_rate: params.sample_rate,
+            num_channels: params.num_channels,
+            num_frames: params.num_frames,
+            loops: match zone.loops() {
+                Some(loops) => loops?.items,
+                None => Vec::new(),
+            },
+        })
+    }
+}
+
+impl std::convert::TryFrom<&Group> for InstrumentGroup {
+    type Error = Error;
+
+    fn try_from(group: &Group) -> Result<Self, Self::Error> {
+        let params = group.params()?;
+
+        let mut modulators = Vec::new();
+        let mut amp_envelope = None;
+        if let Some(mods) = group.internal_mods()? {
+            for m in mods.mods()? {
+                let params = m.params()?;
+                let source_params = match m.source()? {
+                    Some(ModSource::Envelope(envelope)) => match envelope.ahdsr()? {
+                        Some(env) => ModulatorSource::EnvelopeAHDSR(env.params()?),
+                        None => ModulatorSource::Unknown,
+                    },
+                    Some(ModSource::LFO(lfo)) => ModulatorSource::LFO(lfo.params()?),
+                    Some(ModSource::Glide(glide)) => ModulatorSource::Glide(glide.params()?),
+                    Some(ModSource::Unsupported(_)) | None => ModulatorSource::Unknown,
+                };
+                if amp_envelope.is_none()
+                    && !params.bypass
+                    && params.base.targets.iter().any(|t| t.target == "volume")
+                {
+                    if let ModulatorSource::EnvelopeAHDSR(env) = &source_params {
+                        amp_envelope = Some(env.clone());
+                    }
+                }
+                for target in params.base.targets {
+                    modulators.push(Modulator {
+                        source: params.source_name.clone(),
+                        source_params: source_params.clone(),
+                        target: target.target,
+                        intensity: target.intensity,
+                        bypass: params.bypass,
+                    });
+                }
+            }
+        }
+        if let Some(mods) = group.external_mods()? {
+            for m in mods.mods()? {
+                let params = m.params()?;
+                for target in params.base.targets {
+                    modulators.push(Modulator {
+                        source: params.name.clone(),
+                        source_params: ModulatorSource::External {
+                            source: params.source,
+                            cc_number: params.cc_number,
+                        },
+                        target: target.target,
+                        intensity: target.intensity,
+                        bypass: params.bypass,
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            name: params.name,
+            volume: params.volume,
+            pan: params.pan,
+            tune: params.tune,
+            muted: params.muted,
+            release_trigger: params.release_trigger,
+            start_criteria: params.start_criteria.items,
+            source_mode: group.src_mode()?.params.mode,
+            effects: group.fx()?.fx_items()?,
+            modulators,
+            amp_envelope,
+        })
+    }
+}
+
+impl std::convert::TryFrom<&KontaktPreset> for KontaktInstrument {
+    type Error = Error;
+
+    fn try_from(preset: &KontaktPreset) -> Result<Self, Self::Error> {
+        match preset {
+            KontaktPreset::KontaktV42(p) => Self::from_preset(
+                &p.program,
+                &p.filetable.sample_filetable,
+                &p.filetable.other_filetable,
+            ),
+            KontaktPreset::Kon5(p) => Self::from_preset(
+                &p.program,
+                &p.filetable.sample_filetable,
+                &p.filetable.other_filetable,
+            ),
+            KontaktPreset::Kon5PreK51(p) => Self::from_preset(
+                &p.program,
+                &p.filetable.sample_filetable,
+                &p.filetable.other_filetable,
+            ),
+            KontaktPreset::Kon6(p) => Self::from_preset(
+                &p.program,
+                &p.filetable.sample_filetable,
+                &p.filetable.other_filetable,
+            ),
+            KontaktPreset::Kon7(p) => Self::from_preset(
+                &p.program,
+                &p.filetable.sample_filetable,
+                &p.filetable.other_filetable,
+            ),
+            KontaktPreset::KontaktV1(p) => p.preset.instrument(),
+            KontaktPreset::KontaktV2(p) => p.preset.instrument(),
+            KontaktPreset::NKM(_) => Err(Error::Static("Multis contain more than one instrument")),
+            KontaktPreset::Unsupported(chunks) => {
+                let program = chunks
+                    .program()
+                    .ok_or(Error::Static("Could not find Program"))??;
+                match chunks.filename_tables()? {
+                    Some(filetable) => Self::from_preset(
+                        &program,
+                        &filetable.sample_filetable,
+                        &filetable.other_filetable,
+                    ),
+                    None => Self::from_program(&program, &HashMap::new()),
+                }
+            }
+        }
+    }
+}
+
+impl std::convert::TryFrom<&NIFile> for KontaktInstrument {
+    type Error = Error;
+
+    fn try_from(file: &NIFile) -> Result<Self, Self::Error> {
+        Self::try_from(&KontaktPreset::try_from(file)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::*;
+
+    #[test]
+    fn test_instrument_kontakt_v42() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NKS/KontaktV42/KontaktV42-000.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        assert_eq!(instrument.groups.len(), 6);
+        assert_eq!(instrument.groups[0].source_mode, SourceMode::DFD);
+        assert_eq!(instrument.groups[1].source_mode, SourceMode::Sampler);
+        assert_eq!(instrument.zones.len(), 414);
+        assert_eq!(instrument.zones[413].group, 5);
+        assert!(instrument.zones.iter().all(|zone| zone.sample.is_some()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_instrument_kontakt_v1() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NKS/KontaktV1/000-kontaktv1-nki.nki")?;
+        let preset = KontaktPreset::try_from(&NIFile::read(file)?)?;
+        assert!(matches!(preset, KontaktPreset::KontaktV1(_)));
+
+        let instrument = KontaktInstrument::try_from(&preset)?;
+        assert_eq!(instrument.name, "crunchy Kit");
+        assert_eq!(instrument.groups.len(), 1);
+        assert_eq!(instrument.groups[0].pan, 0.0);
+        assert_eq!(instrument.groups[0].modulators.len(), 3);
+        assert!(instrument.groups[0].amp_envelope.is_some());
+        assert!(matches!(
+            instrument.groups[0].modulators[2].source_params,
+            ModulatorSource::External { source: 1, .. }
+        ));
+        assert_eq!(instrument.zones.len(), 60);
+        assert_eq!(instrument.zones[0].root_key, 36);
+        assert_eq!(
+            instrument.zones[0].sample.as_ref().unwrap().to_string(),
+            "crunchy Kit Samples/big_kit_bd1.wav"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_instrument_kontakt_v2() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NKS/KontaktV2/kokiriko_all_fx.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        assert_eq!(instrument.name, "kokiriko_all_fx");
+        assert_eq!(instrument.metadata.author, "ssb");
+        assert_eq!(
+            instrument.metadata.wallpaper.unwrap().to_string(),
+            "Samples/Wallpaper/kor_wp_01.tga"
+        );
+        assert_eq!(instrument.groups[0].name, "kereizuki a r1");
+        assert_eq!(instrument.groups[0].tune, 1.0);
+        assert_eq!(instrument.zones[0].low_key, 70);
+        assert_eq!(
+            instrument.zones[0].sample.as_ref().unwrap().to_string(),
+            "Samples/koriko_kireizuki_01.wav"
+        );
+        let env = instrument.groups[0].amp_envelope.as_ref().unwrap();
+        assert_eq!(env.decay.round(), 12643.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instrument_kon5_pre_k51_filetable() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NIS/files/kontakt/5.0.2/musical_saw.nki")?;
+        let preset = KontaktPreset::try_from(&NIFile::read(file)?)?;
+        assert!(matches!(preset, KontaktPreset::Kon5PreK51(_)));
+
+        let instrument = KontaktInstrument::try_from(&preset)?;
+
+        assert_eq!(instrument.name, "Saw");
+        assert_eq!(instrument.zones.len(), 15);
+        assert_eq!(
+            instrument.zones[0].sample.as_ref().unwrap().to_string(),
+            "saw.wav"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_instrument_monolith() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/FileContainer/files/000-default.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        assert_eq!(instrument.name, "000-default");
+        assert_eq!(instrument.groups.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instrument_kon5_internal_mod_array_v11() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NIS/files/kontakt/5.4.3.307/000.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        assert_eq!(instrument.zones.len(), 61);
+        let sources: Vec<&str> = instrument.groups[0]
+            .modulators
+            .iter()
+            .map(|m| m.source.as_str())
+            .collect();
+        assert!(sources.contains(&"GLIDE"));
+        assert!(instrument.groups[0]
+            .modulators
+            .iter()
+            .any(|m| matches!(m.source_params, ModulatorSource::Glide(_))));
+        assert!(instrument.groups[0].amp_envelope.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_instrument_kon7() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Containers/NIS/files/kontakt/7.1.3.0/001-single-sample.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        assert_eq!(instrument.zones.len(), 1);
+        assert_eq!(instrument.zones[0].group, 0);
+        assert_eq!(
+            instrument.zones[0].sample.as_ref().unwrap().to_string(),
+            "001-single-sample Samples/beep.wav"
+        );
+        assert_eq!(instrument.scripts.len(), 5);
+        Ok(())
+    }
 }
@@ -3,15 +3,24 @@
 mod chunk;
 mod chunk_set;
 mod error;
+pub mod export;
 mod instrument;
 pub mod objects;
+mod params;
 mod patch;
+mod relink;
+mod samples;
 pub mod schemas;
+mod scripts;
 mod structured_object;
 
 pub use chunk::*;
 pub use chunk_set::*;
 pub use error::*;
 pub use instrument::*;
+pub use params::*;
 pub use patch::KontaktPatch;
+pub use relink::*;
+pub use samples::*;
+pub use scripts::*;
 pub use structured_object::*;
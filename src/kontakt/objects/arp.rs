@@ -0,0 +1,36 @@
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    Error,
+};
+
+const CHUNK_ID: u16 = 0x09;
+
+/// # Arpeggiator
+///
+/// No known file contains an arpeggiator, so only the
+/// [`StructuredObject`] is available and its parameters are not decoded.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x09
+/// - Versions:       ?
+/// - Kontakt 7:      BParArp
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arpeggiator(pub StructuredObject);
+
+impl std::convert::TryFrom<&Chunk> for Arpeggiator {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
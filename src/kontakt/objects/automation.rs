@@ -0,0 +1,55 @@
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    Error,
+};
+
+const CHUNK_ID: u16 = 0x01;
+
+/// # BAutomationObject
+///
+/// A host automation or MIDI controller assignment. No known binary file
+/// contains one, so only the [`StructuredObject`] is available; the fields
+/// are decoded from Kontakt 2 XML presets, see
+/// [`XMLDocument::automation`](crate::kontakt::schemas::XMLDocument::automation).
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x01
+/// - Versions:       ?
+/// - Kontakt 7:      BAutomationObject
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BAutomationObject(pub StructuredObject);
+
+/// Field names follow the `<AutObj>` element of Kontakt 2 XML presets.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AutomationParams {
+    /// Found "midi_ctrl"
+    pub address_mode: String,
+    pub soft_take_over: bool,
+    pub program_send_fx_is_in_insert_chain: bool,
+    /// Automation id, the MIDI CC number for "midi_ctrl".
+    pub address: i32,
+    /// Target parameter, e.g. "pts_script_knob_0_1".
+    pub par_tag: String,
+    pub obj_idx: i32,
+    pub range_from: f32,
+    pub range_to: f32,
+}
+
+impl std::convert::TryFrom<&Chunk> for BAutomationObject {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
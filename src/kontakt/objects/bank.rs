@@ -31,9 +31,11 @@ pub const KONTAKT_BANK_ID: u16 = 0x03;
 ///   BMultiChannelProcessBuffer?
 ///
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bank(pub StructuredObject);
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BankPublicParams {
     pub master_volume: f32,
     pub master_tune: f32,
@@ -62,6 +64,13 @@ impl Bank {
     pub fn slot_list(&self) -> Result<super::SlotList, Error> {
         (&self.0.children[7]).try_into()
     }
+
+    pub fn output_configuration(&self) -> Result<Option<super::BOutputConfiguration>, Error> {
+        self.0
+            .find_first(0x3E)
+            .map(super::BOutputConfiguration::try_from)
+            .transpose()
+    }
 }
 
 impl std::convert::TryFrom<&Chunk> for Bank {
@@ -3,7 +3,8 @@ use std::io::Cursor;
 use crate::{
     kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
     read_bytes::ReadBytesExt,
-    Error,
+    write_bytes::WriteBytesExt,
+    Error, NIFileError,
 };
 
 const CHUNK_ID: u16 = 0x06;
@@ -18,33 +19,117 @@ const CHUNK_ID: u16 = 0x06;
 /// Kontakt 7:      BParScript
 /// KontaktIO:
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BParScript(pub StructuredObject);
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BParScriptParams {
     pub text: Option<String>,
     pub source_editor_open: bool,
     pub touched_but_not_applied: bool,
     pub bypass: bool,
-    pub password_hash: String,
+    /// Hash of the password, empty if the script is not protected. 16
+    /// bytes in Kontakt 4.2.
+    pub password_hash: Vec<u8>,
     pub description: Option<String>,
     pub textfile_name: Option<String>,
 }
 
-impl BParScript {
-    pub fn params(&self) -> Result<BParScriptParams, Error> {
-        let mut reader = Cursor::new(&self.0.public_data);
-
-        Ok(BParScriptParams {
+impl BParScriptParams {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        Ok(Self {
             text: reader.read_optional_sized_utf8()?,
             source_editor_open: reader.read_bool()?,
             touched_but_not_applied: reader.read_bool()?,
             bypass: reader.read_bool()?,
-            password_hash: reader.read_sized_utf8()?,
+            password_hash: {
+                let length = reader.read_u32_le()?;
+                reader.read_bytes(length as usize)?
+            },
             description: reader.read_optional_sized_utf8()?,
             textfile_name: reader.read_optional_sized_utf8()?,
         })
     }
+
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_optional_sized_utf8(self.text.as_deref())?;
+        writer.write_bool(self.source_editor_open)?;
+        writer.write_bool(self.touched_but_not_applied)?;
+        writer.write_bool(self.bypass)?;
+        writer.write_u32_le(self.password_hash.len() as u32)?;
+        writer.write_bytes(&self.password_hash)?;
+        writer.write_optional_sized_utf8(self.description.as_deref())?;
+        writer.write_optional_sized_utf8(self.textfile_name.as_deref())?;
+        Ok(())
+    }
+}
+
+impl BParScript {
+    /// A new v60 script slot without persistent variables.
+    pub fn new(params: &BParScriptParams) -> Result<Self, Error> {
+        let mut public_data = Vec::new();
+        params.write(&mut public_data)?;
+        // number of persistent variables
+        public_data.write_u32_le(0)?;
+
+        Ok(Self(StructuredObject {
+            is_structured: false,
+            version: 0x60,
+            public_data,
+            private_data: Vec::new(),
+            children: Vec::new(),
+        }))
+    }
+
+    /// Reads an unstructured script slot followed by other data, as stored
+    /// in the private data of Kontakt 4.2 programs.
+    pub fn read_embedded<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        let is_structured = reader.read_bool()?;
+        let version = reader.read_u16_le()?;
+        if is_structured || version != 0x60 {
+            return Err(NIFileError::Generic(format!(
+                "Unsupported embedded BParScript version: 0x{:X}",
+                version
+            )));
+        }
+
+        let start = reader.stream_position()?;
+        BParScriptParams::read(&mut reader)?;
+        // persistent variables, e.g. "$volume 12"
+        let num_variables = reader.read_u32_le()?;
+        for _ in 0..num_variables {
+            let length = reader.read_u32_le()?;
+            reader.read_bytes(length as usize)?;
+        }
+        let end = reader.stream_position()?;
+
+        reader.seek(std::io::SeekFrom::Start(start))?;
+        Ok(Self(StructuredObject {
+            is_structured: false,
+            version,
+            public_data: reader.read_bytes((end - start) as usize)?,
+            private_data: Vec::new(),
+            children: Vec::new(),
+        }))
+    }
+
+    pub fn params(&self) -> Result<BParScriptParams, Error> {
+        BParScriptParams::read(Cursor::new(&self.0.public_data))
+    }
+
+    /// Replaces the params, keeping any data stored after them.
+    pub fn set_params(&mut self, params: &BParScriptParams) -> Result<(), Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+        BParScriptParams::read(&mut reader)?;
+        let remainder = self.0.public_data[reader.position() as usize..].to_vec();
+
+        let mut public_data = Vec::new();
+        params.write(&mut public_data)?;
+        public_data.extend(remainder);
+        self.0.public_data = public_data;
+        Ok(())
+    }
 }
 
 impl std::convert::TryFrom<&Chunk> for BParScript {
@@ -0,0 +1,119 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+use super::ExternalMod;
+
+const CHUNK_ID: u16 = 0x3C;
+
+/// BParameterArraySerBParExternalMod32
+///
+/// An array of 32 ExternalMod objects.
+///
+/// Type:           Chunk<StructuredObject>
+/// SerType:        0x3C
+/// Versions:       0x10, 0x12
+/// Kontakt 7:      BParameterArraySerBParExternalMod32
+/// KontaktIO:      BParamArray<32>
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExternalModArray32(pub StructuredObject);
+
+impl ExternalModArray32 {
+    pub fn children(&self) -> Result<Vec<Chunk>, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+        let mut items = Vec::new();
+
+        match self.0.version {
+            0x10..=0x12 => {
+                for _ in 0..32 {
+                    if reader.read_bool()? {
+                        items.push(Chunk::read(&mut reader)?);
+                    }
+                }
+            }
+            _ => {
+                return Err(NIFileError::Generic(format!(
+                    "Unsupported ExternalModArray32 version: 0x{:X}",
+                    self.0.version
+                )))
+            }
+        }
+
+        Ok(items)
+    }
+
+    pub fn mods(&self) -> Result<Vec<ExternalMod>, Error> {
+        self.children()?.iter().map(ExternalMod::try_from).collect()
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for ExternalModArray32 {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_ext_mod_array_v10() -> Result<(), Error> {
+        let file = File::open(
+            "tests/data/Objects/Kontakt/0x3C-ExternalModArray32/ExternalModArray32-000.kon",
+        )?;
+        let mod_arr = ExternalModArray32::try_from(&Chunk::read(file)?)?;
+        assert_eq!(mod_arr.0.version, 0x10);
+
+        let mods = mod_arr.mods()?;
+        assert_eq!(mods.len(), 2);
+
+        let params = mods[0].params()?;
+        assert_eq!(params.base.targets[0].target, "intensity");
+        assert_eq!(params.name, "CC_FREQUENCY");
+        assert_eq!(params.source, 4);
+        assert_eq!(params.cc_number, Some(1));
+
+        let params = mods[1].params()?;
+        assert_eq!(params.base.targets[0].target, "pitch");
+        assert_eq!(params.source, 1);
+        assert_eq!(params.initial_value, -1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ext_mod_array_v12() -> Result<(), Error> {
+        let file = File::open(
+            "tests/data/Objects/Kontakt/0x3C-ExternalModArray32/ExternalModArray32-001.kon",
+        )?;
+        let mod_arr = ExternalModArray32::try_from(&Chunk::read(file)?)?;
+        assert_eq!(mod_arr.0.version, 0x12);
+
+        let mods = mod_arr.mods()?;
+        assert_eq!(mods.len(), 2);
+
+        let params = mods[0].params()?;
+        assert_eq!(params.base.targets[0].target, "volume");
+        assert_eq!(params.name, "VEL_VOLUME");
+        assert_eq!(params.source, 6);
+        Ok(())
+    }
+}
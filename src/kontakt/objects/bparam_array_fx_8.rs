@@ -6,7 +6,7 @@ use crate::{
     Error,
 };
 
-use super::BParFX;
+use super::{BParFX, Effect};
 
 /// Type:           StructuredObject
 /// SerType:        0x3A
@@ -15,6 +15,7 @@ use super::BParFX;
 /// KontaktIO:      BParamArray<8>
 #[doc = include_str!("../../../doc/presets/Kontakt/BParamArray.md")]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BParamArrayBParFX8 {
     pub version: u16,
     pub items: Vec<Option<Chunk>>,
@@ -28,32 +29,34 @@ impl BParamArrayBParFX8 {
 
         assert!(!is_structured_data); // always false?
 
-        match version {
-            0x11 => {
-                panic!("Unsupported BParamArrayBParFX8: v11");
-            }
-            _ => {
-                for _ in 0..num_items {
-                    let has_item = reader.read_bool()?;
-                    if has_item {
-                        items.push(Some(Chunk::read(&mut reader)?));
-                    } else {
-                        items.push(None);
-                    }
-                }
+        for _ in 0..num_items {
+            let has_item = reader.read_bool()?;
+            if has_item {
+                items.push(Some(Chunk::read(&mut reader)?));
+            } else {
+                items.push(None);
             }
         }
 
         Ok(Self { version, items })
     }
 
-    pub fn fx_items(&self) -> Result<Vec<BParFX>, Error> {
-        Ok(self
-            .items
+    pub fn fx_slots(&self) -> Result<Vec<BParFX>, Error> {
+        self.items
             .iter()
             .filter_map(|c| c.as_ref())
-            .filter_map(|c| BParFX::try_from(c).ok())
-            .collect())
+            .map(BParFX::try_from)
+            .collect()
+    }
+
+    pub fn fx_items(&self) -> Result<Vec<Effect>, Error> {
+        let mut effects = Vec::new();
+        for slot in self.fx_slots()? {
+            if let Some(effect) = slot.fx()? {
+                effects.push(effect);
+            }
+        }
+        Ok(effects)
     }
 
     pub fn len(&self) -> usize {
@@ -106,4 +109,17 @@ mod tests {
         assert_eq!(arr.items.len(), 8);
         Ok(())
     }
+
+    #[test]
+    fn test_bparam_array_v11() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/BParameterArray/BParameterArray-002.kon")?;
+        let arr = BParamArrayBParFX8::read(file, 8)?;
+
+        assert_eq!(arr.version, 0x11);
+        assert_eq!(arr.items.len(), 8);
+        assert_eq!(arr.len(), 1);
+        assert_eq!(arr.fx_slots()?.len(), 1);
+        Ok(())
+    }
 }
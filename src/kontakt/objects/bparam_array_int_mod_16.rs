@@ -3,9 +3,13 @@ use std::io::Cursor;
 use crate::{
     kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
     read_bytes::ReadBytesExt,
-    Error,
+    Error, NIFileError,
 };
 
+use super::InternalMod;
+#[cfg(test)]
+use super::ModSource;
+
 const CHUNK_ID: u16 = 0x3B;
 
 /// BParameterArraySerBParInternalMod16
@@ -18,6 +22,7 @@ const CHUNK_ID: u16 = 0x3B;
 /// Kontakt 7:      BParameterArraySerBParInternalMod16
 /// KontaktIO:      BParamArray<16>
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InternalModArray16(pub StructuredObject);
 
 impl InternalModArray16 {
@@ -26,24 +31,27 @@ impl InternalModArray16 {
         let mut items = Vec::new();
 
         match self.0.version {
-            0x11 => unimplemented!(),
-            0x10 | 0x12 => {
+            0x10..=0x12 => {
                 for _ in 0..16 {
                     if reader.read_bool()? {
-                        // if V11:
-                        // reader.read_u32_le()?;
                         items.push(Chunk::read(&mut reader)?);
                     }
                 }
             }
-            _ => panic!(
-                "Unsupported InternalModArray16 version: 0x{:X}",
-                self.0.version
-            ),
+            _ => {
+                return Err(NIFileError::Generic(format!(
+                    "Unsupported InternalModArray16 version: 0x{:X}",
+                    self.0.version
+                )))
+            }
         }
 
         Ok(items)
     }
+
+    pub fn mods(&self) -> Result<Vec<InternalMod>, Error> {
+        self.children()?.iter().map(InternalMod::try_from).collect()
+    }
 }
 
 impl std::convert::TryFrom<&Chunk> for InternalModArray16 {
@@ -78,6 +86,31 @@ mod tests {
         assert_eq!(mod_arr.0.version, 0x10);
         assert_eq!(mod_arr.0.children.len(), 0);
         assert_eq!(mod_arr.children()?.len(), 3);
+
+        let mods = mod_arr.mods()?;
+        assert_eq!(mods.len(), 3);
+
+        let params = mods[1].params()?;
+        assert_eq!(params.base.targets[0].target, "filterCutoff");
+        assert_eq!(params.base.targets[0].target_obj_idx, Some(0));
+        assert_eq!(params.source_name, "LFO 1");
+        assert!(params.retrigger);
+        assert_eq!(params.class_id, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bparam_array_v11() -> Result<(), Error> {
+        let file = File::open(
+            "tests/data/Objects/Kontakt/0x3B-InternalModArray16/InternalModArray16-001.kon",
+        )?;
+        let mod_arr = InternalModArray16::try_from(&Chunk::read(file)?)?;
+        assert_eq!(mod_arr.0.version, 0x11);
+
+        let mods = mod_arr.mods()?;
+        assert_eq!(mods.len(), 3);
+        assert_eq!(mods[2].params()?.source_name, "GLIDE");
+        assert!(matches!(mods[2].source()?, Some(ModSource::Glide(_))));
         Ok(())
     }
 }
@@ -1,8 +1,13 @@
+use std::io::Cursor;
+
 use crate::{
     kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    read_bytes::ReadBytesExt,
     Error,
 };
 
+use super::Effect;
+
 const CHUNK_ID: u16 = 0x25;
 
 /// # BParFX
@@ -17,9 +22,37 @@ const CHUNK_ID: u16 = 0x25;
 /// - KontaktIO:      BParamArray<8>
 ///
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BParFX(pub StructuredObject);
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BParFXParams {
+    /// Found 0x03, 0x07, 0x09, 0x0A, 0x0B, 0x12, 0x13, 0x16, 0x18
+    pub u_a: u32,
+    pub routers_open: bool,
+    pub class_id: i32,
+    pub bypass: bool,
+    pub out_level: f32,
+    pub out_level_dry: f32,
+    pub send_fx_out_partition: i32,
+}
+
 impl BParFX {
+    pub fn params(&self) -> Result<BParFXParams, Error> {
+        let mut reader = Cursor::new(&self.0.private_data);
+
+        Ok(BParFXParams {
+            u_a: reader.read_u32_le()?,
+            routers_open: reader.read_bool()?,
+            class_id: reader.read_i32_le()?,
+            bypass: reader.read_bool()?,
+            out_level: reader.read_f32_le()?,
+            out_level_dry: reader.read_f32_le()?,
+            send_fx_out_partition: reader.read_i32_le()?,
+        })
+    }
+
     pub fn version(&self) -> u16 {
         self.0.version
     }
@@ -27,6 +60,10 @@ impl BParFX {
     pub fn effect(&self) -> Option<&Chunk> {
         self.0.children.get(0)
     }
+
+    pub fn fx(&self) -> Result<Option<Effect>, Error> {
+        self.effect().map(Effect::try_from).transpose()
+    }
 }
 
 impl std::convert::TryFrom<&Chunk> for BParFX {
@@ -43,3 +80,25 @@ impl std::convert::TryFrom<&Chunk> for BParFX {
         Ok(Self(chunk.try_into()?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_bparfx_v50() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x25-BParFX/BParFXV50-000.kon")?;
+        let bparfx = BParFX::try_from(&Chunk::read(file)?)?;
+        assert_eq!(bparfx.version(), 0x50);
+
+        let params = bparfx.params()?;
+        assert!(!params.bypass);
+        assert_eq!(params.out_level, 1.0);
+        assert_eq!(params.send_fx_out_partition, -1);
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x53;
+
+/// # FXACBox
+///
+/// AC Box guitar amp.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x53
+/// - Versions:       0x10
+/// - Kontakt 7:      BParFXACBox
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXACBox(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXACBoxParams {
+    /// Found 0.5
+    pub u_a: f32,
+    /// Found 0.5
+    pub u_b: f32,
+    /// Found 0.5
+    pub u_c: f32,
+    /// Found 0.5
+    pub u_d: f32,
+    /// Found 0.5
+    pub u_e: f32,
+    /// Found 0.5
+    pub u_f: f32,
+    /// Found 0.5
+    pub u_g: f32,
+    /// Found true
+    pub u_h: bool,
+}
+
+impl FXACBox {
+    pub fn params(&self) -> Result<FXACBoxParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x10 => Ok(FXACBoxParams {
+                u_a: reader.read_f32_le()?,
+                u_b: reader.read_f32_le()?,
+                u_c: reader.read_f32_le()?,
+                u_d: reader.read_f32_le()?,
+                u_e: reader.read_f32_le()?,
+                u_f: reader.read_f32_le()?,
+                u_g: reader.read_f32_le()?,
+                u_h: reader.read_bool()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXACBox version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXACBox {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_ac_box_v10() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x53-BParFXACBox/BParFXACBox-000.kon")?;
+        let fx = FXACBox::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x10);
+
+        let params = fx.params()?;
+        assert_eq!(params.u_a, 0.5);
+        assert!(params.u_h);
+        Ok(())
+    }
+}
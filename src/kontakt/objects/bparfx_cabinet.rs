@@ -0,0 +1,90 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x24;
+
+/// # FXCabinet
+///
+/// Speaker cabinet effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x24
+/// - Versions:       0x50
+/// - Kontakt 7:      BParFXCabinet
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXCabinet(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXCabinetParams {
+    pub size: f32,
+    pub air: f32,
+    pub treble: f32,
+    pub bass: f32,
+    pub cabinet_type: i32,
+}
+
+impl FXCabinet {
+    pub fn params(&self) -> Result<FXCabinetParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x50 => Ok(FXCabinetParams {
+                size: reader.read_f32_le()?,
+                air: reader.read_f32_le()?,
+                treble: reader.read_f32_le()?,
+                bass: reader.read_f32_le()?,
+                cabinet_type: reader.read_i32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXCabinet version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXCabinet {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_cabinet_v50() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x24-BParFXCabinet/BParFXCabinet-000.kon")?;
+        let fx = FXCabinet::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x50);
+
+        let params = fx.params()?;
+        assert_eq!(params.size, 1.0);
+        assert_eq!(params.cabinet_type, 4);
+        Ok(())
+    }
+}
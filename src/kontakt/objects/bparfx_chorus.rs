@@ -0,0 +1,91 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x11;
+
+/// # FXChorus
+///
+/// Chorus effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x11
+/// - Versions:       0x50
+/// - Kontakt 7:      BParFXChorus
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXChorus(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXChorusParams {
+    pub depth: f32,
+    /// Rate in Hz.
+    pub speed: f32,
+    pub phase: f32,
+    /// Found -1.0
+    pub u_a: f32,
+}
+
+impl FXChorus {
+    pub fn params(&self) -> Result<FXChorusParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x50 => Ok(FXChorusParams {
+                depth: reader.read_f32_le()?,
+                speed: reader.read_f32_le()?,
+                phase: reader.read_f32_le()?,
+                u_a: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXChorus version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXChorus {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_chorus_v50() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x11-BParFXChorus/BParFXChorus-000.kon")?;
+        let fx = FXChorus::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x50);
+
+        let params = fx.params()?;
+        assert!((params.depth - 0.79).abs() < 0.01);
+        assert!((params.speed - 0.3).abs() < 0.01);
+        assert_eq!(params.phase, 0.85);
+        assert_eq!(params.u_a, -1.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x19;
+
+/// # FXCompressor
+///
+/// Compressor effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x19
+/// - Versions:       0x70
+/// - Kontakt 7:      BParFXCompressor
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXCompressor(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXCompressorParams {
+    /// Found 1
+    pub u_a: i32,
+    /// Threshold in dB.
+    pub threshold: f32,
+    /// Found 0.18
+    pub u_b: f32,
+    /// Attack time in ms.
+    pub attack: f32,
+    /// Release time in ms.
+    pub release: f32,
+    pub u_c: bool,
+}
+
+impl FXCompressor {
+    pub fn params(&self) -> Result<FXCompressorParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x70 => Ok(FXCompressorParams {
+                u_a: reader.read_i32_le()?,
+                threshold: reader.read_f32_le()?,
+                u_b: reader.read_f32_le()?,
+                attack: reader.read_f32_le()?,
+                release: reader.read_f32_le()?,
+                u_c: reader.read_bool()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXCompressor version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXCompressor {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_compressor_v70() -> Result<(), Error> {
+        let file = File::open(
+            "tests/data/Objects/Kontakt/0x19-BParFXCompressor/BParFXCompressor-000.kon",
+        )?;
+        let fx = FXCompressor::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x70);
+
+        let params = fx.params()?;
+        assert_eq!(params.u_a, 1);
+        assert!((params.threshold + 7.22).abs() < 0.01);
+        assert!((params.attack - 25.28).abs() < 0.01);
+        assert!((params.release - 94.34).abs() < 0.01);
+        assert!(params.u_c);
+        Ok(())
+    }
+}
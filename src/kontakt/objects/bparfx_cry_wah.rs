@@ -0,0 +1,85 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x58;
+
+/// # FXCryWah
+///
+/// Cry Wah pedal.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x58
+/// - Versions:       0x10
+/// - Kontakt 7:      BParFXCryWah
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXCryWah(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXCryWahParams {
+    /// Normalized pedal position.
+    pub pedal: f32,
+    /// Found true
+    pub u_a: bool,
+}
+
+impl FXCryWah {
+    pub fn params(&self) -> Result<FXCryWahParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x10 => Ok(FXCryWahParams {
+                pedal: reader.read_f32_le()?,
+                u_a: reader.read_bool()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXCryWah version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXCryWah {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_cry_wah_v10() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x58-BParFXCryWah/BParFXCryWah-000.kon")?;
+        let fx = FXCryWah::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x10);
+
+        let params = fx.params()?;
+        assert_eq!(params.pedal, 0.5);
+        assert!(params.u_a);
+        Ok(())
+    }
+}
@@ -19,34 +19,49 @@ const CHUNK_ID: u16 = 0x10;
 /// - KontaktIO:      ?
 ///
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FXDelay(pub StructuredObject);
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FXDelayParams {
-    a: f32,
-    b: f32,
-    c: f32,
-    d: f32,
-    e: f32,
-    f: f32,
-    g: f32,
-    h: bool,
+    /// Delay time in ms.
+    pub time: f32,
+    pub damping: f32,
+    pub pan: f32,
+    pub feedback: f32,
+    /// Found -1.0, 0.5
+    pub u_a: f32,
+    /// v51
+    pub u_b: Option<f32>,
+    /// v51
+    pub u_c: Option<f32>,
+    /// v51
+    pub u_d: Option<bool>,
 }
 
 impl FXDelay {
     pub fn params(&self) -> Result<FXDelayParams, Error> {
         let mut reader = Cursor::new(&self.0.public_data);
 
-        Ok(FXDelayParams {
-            a: reader.read_f32_le()?,
-            b: reader.read_f32_le()?,
-            c: reader.read_f32_le()?,
-            d: reader.read_f32_le()?,
-            e: reader.read_f32_le()?,
-            f: reader.read_f32_le()?,
-            g: reader.read_f32_le()?,
-            h: reader.read_bool()?,
-        })
+        let mut params = FXDelayParams {
+            time: reader.read_f32_le()?,
+            damping: reader.read_f32_le()?,
+            pan: reader.read_f32_le()?,
+            feedback: reader.read_f32_le()?,
+            u_a: reader.read_f32_le()?,
+            u_b: None,
+            u_c: None,
+            u_d: None,
+        };
+
+        if self.0.version >= 0x51 {
+            params.u_b = Some(reader.read_f32_le()?);
+            params.u_c = Some(reader.read_f32_le()?);
+            params.u_d = Some(reader.read_bool()?);
+        }
+
+        Ok(params)
     }
 }
 
@@ -67,27 +82,22 @@ impl std::convert::TryFrom<&Chunk> for FXDelay {
 
 #[cfg(test)]
 mod tests {
-    // use super::*;
-    // use crate::Error;
-    // use std::fs::File;
-    //
-    // #[test]
-    // fn test_bank() -> Result<(), Error> {
-    //     let chunk = Chunk::read(File::open(
-    //         "tests/data/Objects/Kontakt/0x29-ProgramContainer/ProgramContainerV51-000.kon",
-    //     )?)?;
-    //     let pc = InsertBus::try_from(&chunk)?;
-    //
-    //     assert_eq!(pc.0.version, 0x51);
-    //     assert_eq!(pc.0.children.len(), 3);
-    //     assert_eq!(pc.0.children[0].id, 0x2B); // VoiceGroup
-    //     assert_eq!(pc.0.children[1].id, 0x47); // SaveSettings
-    //     assert_eq!(pc.0.children[2].id, 0x36); // ProgramList
-    //
-    //     pc.params()?;
-    //     pc.voice_group()?;
-    //     pc.program_list()?;
-    //     // println!("{:X}", pc.0.children[2].id);
-    //     Ok(())
-    // }
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_delay_v50() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x10-BParFXDelay/BParFXDelay-000.kon")?;
+        let fx = FXDelay::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x50);
+
+        let params = fx.params()?;
+        assert!((params.time - 61.01).abs() < 0.01);
+        assert_eq!(params.pan, 1.0);
+        assert!(params.u_b.is_none());
+        Ok(())
+    }
 }
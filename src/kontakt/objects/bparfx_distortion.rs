@@ -0,0 +1,88 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x1E;
+
+/// # FXDistortion
+///
+/// Distortion effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x1E
+/// - Versions:       0x60
+/// - Kontakt 7:      BParFXDistortion
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXDistortion(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXDistortionParams {
+    /// 0 = tube, 1 = transistor?
+    pub mode: i32,
+    pub drive: f32,
+    pub damping: f32,
+}
+
+impl FXDistortion {
+    pub fn params(&self) -> Result<FXDistortionParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x60 => Ok(FXDistortionParams {
+                mode: reader.read_i32_le()?,
+                drive: reader.read_f32_le()?,
+                damping: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXDistortion version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXDistortion {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_distortion_v60() -> Result<(), Error> {
+        let file = File::open(
+            "tests/data/Objects/Kontakt/0x1E-BParFXDistortion/BParFXDistortion-000.kon",
+        )?;
+        let fx = FXDistortion::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x60);
+
+        let params = fx.params()?;
+        assert_eq!(params.mode, 1);
+        assert!((params.drive - 0.31).abs() < 0.01);
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x56;
+
+/// # FXDStortion
+///
+/// DStortion distortion pedal.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x56
+/// - Versions:       0x10
+/// - Kontakt 7:      BParFXDStortion
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXDStortion(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXDStortionParams {
+    /// Found 0.5
+    pub u_a: f32,
+    /// Found 0.5
+    pub u_b: f32,
+    /// Found 0.5
+    pub u_c: f32,
+    /// Found 0.5
+    pub u_d: f32,
+    /// Found 0.5
+    pub u_e: f32,
+    /// Found 0.5
+    pub u_f: f32,
+    /// Found true
+    pub u_g: bool,
+}
+
+impl FXDStortion {
+    pub fn params(&self) -> Result<FXDStortionParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x10 => Ok(FXDStortionParams {
+                u_a: reader.read_f32_le()?,
+                u_b: reader.read_f32_le()?,
+                u_c: reader.read_f32_le()?,
+                u_d: reader.read_f32_le()?,
+                u_e: reader.read_f32_le()?,
+                u_f: reader.read_f32_le()?,
+                u_g: reader.read_bool()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXDStortion version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXDStortion {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_dstortion_v10() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x56-BParFXDStortion/BParFXDStortion-000.kon")?;
+        let fx = FXDStortion::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x10);
+
+        let params = fx.params()?;
+        assert_eq!(params.u_f, 0.5);
+        assert!(params.u_g);
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x18;
+
+/// # FXFilter
+///
+/// Filter and EQ effect. The parameters that follow the filter type depend on
+/// the type.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x18
+/// - Versions:       0x80, 0x90, 0x92
+/// - Kontakt 7:      BParFXFilter
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXFilter(pub StructuredObject);
+
+/// The filter model.
+///
+/// Only types found in test files are supported. Names follow the `type`
+/// attribute of Kontakt 2 XML presets, which only ever contain "eq3band";
+/// the other ids have no known name yet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FilterType {
+    /// "eq3band"
+    Eq3Band,
+    Unknown(i32),
+}
+
+impl From<i32> for FilterType {
+    fn from(value: i32) -> Self {
+        match value {
+            24 => FilterType::Eq3Band,
+            v => FilterType::Unknown(v),
+        }
+    }
+}
+
+impl FilterType {
+    /// Number of bands for EQ types.
+    pub fn num_bands(&self) -> usize {
+        match self {
+            FilterType::Eq3Band => 3,
+            FilterType::Unknown(_) => 0,
+        }
+    }
+
+    /// Number of type-specific parameters, or `None` if no sample file
+    /// contains the type.
+    pub fn num_params(&self) -> Option<usize> {
+        match self {
+            FilterType::Eq3Band => Some(0),
+            FilterType::Unknown(2 | 3 | 9 | 50 | 52 | 54 | 100 | 101 | 102) => Some(2),
+            FilterType::Unknown(36 | 90) => Some(3),
+            FilterType::Unknown(_) => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EqBand {
+    /// Frequency in Hz.
+    pub freq: f32,
+    /// Bandwidth in octaves.
+    pub bandwidth: f32,
+    /// Gain in dB.
+    pub gain: f32,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXFilterParams {
+    pub filter_type: FilterType,
+    /// Only stored for type 36. Found false
+    pub u_b: Option<bool>,
+    /// v92, same as the filter type
+    pub u_a: Option<i32>,
+    /// EQ types only.
+    pub bands: Vec<EqBand>,
+    /// Type-specific parameters in stored order, normalized 0.0 - 1.0.
+    pub params: Vec<f32>,
+}
+
+impl FXFilterParams {
+    /// Normalized cutoff of the two-parameter filter types.
+    pub fn cutoff(&self) -> Option<f32> {
+        match self.params.len() {
+            2 => Some(self.params[0]),
+            _ => None,
+        }
+    }
+
+    /// Normalized resonance of the two-parameter filter types.
+    pub fn resonance(&self) -> Option<f32> {
+        match self.params.len() {
+            2 => Some(self.params[1]),
+            _ => None,
+        }
+    }
+}
+
+impl FXFilter {
+    pub fn params(&self) -> Result<FXFilterParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        if !matches!(self.0.version, 0x80 | 0x90 | 0x92) {
+            return Err(NIFileError::Generic(format!(
+                "Unsupported FXFilter version: 0x{:X}",
+                self.0.version
+            )));
+        }
+
+        let filter_type = FilterType::from(reader.read_i32_le()?);
+        let num_params = filter_type.num_params().ok_or_else(|| {
+            NIFileError::Generic(format!("Unsupported filter type: {:?}", filter_type))
+        })?;
+
+        let u_b = match filter_type {
+            FilterType::Unknown(36) => Some(reader.read_bool()?),
+            _ => None,
+        };
+        let u_a = match self.0.version {
+            0x92 => Some(reader.read_i32_le()?),
+            _ => None,
+        };
+
+        let mut bands = Vec::new();
+        for _ in 0..filter_type.num_bands() {
+            bands.push(EqBand {
+                freq: reader.read_f32_le()?,
+                bandwidth: reader.read_f32_le()?,
+                gain: reader.read_f32_le()?,
+            });
+        }
+
+        let mut params = Vec::new();
+        for _ in 0..num_params {
+            params.push(reader.read_f32_le()?);
+        }
+
+        if reader.position() != self.0.public_data.len() as u64 {
+            return Err(Error::Static("Excess data found in FXFilter"));
+        }
+
+        Ok(FXFilterParams {
+            filter_type,
+            u_b,
+            u_a,
+            bands,
+            params,
+        })
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXFilter {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_filter_eq3_v80() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x18-BParFXFilter/BParFXFilter-000.kon")?;
+        let fx = FXFilter::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x80);
+
+        let params = fx.params()?;
+        assert_eq!(params.filter_type, FilterType::Eq3Band);
+        assert_eq!(params.bands.len(), 3);
+        assert!((params.bands[0].freq - 402.2).abs() < 0.1);
+        assert!(params.params.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_eq3_v92() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x18-BParFXFilter/BParFXFilter-001.kon")?;
+        let fx = FXFilter::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x92);
+
+        let params = fx.params()?;
+        assert_eq!(params.filter_type, FilterType::Eq3Band);
+        assert_eq!(params.u_a, Some(24));
+        assert_eq!(params.bands[1].freq, 1450.0);
+        assert_eq!(params.bands[2].freq, 7500.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_v90() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x18-BParFXFilter/BParFXFilter-002.kon")?;
+        let fx = FXFilter::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x90);
+
+        let params = fx.params()?;
+        assert_eq!(params.filter_type, FilterType::Unknown(100));
+        assert_eq!(params.params.len(), 2);
+        assert_eq!(params.params[1], 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_type_36_v92() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x18-BParFXFilter/BParFXFilter-003.kon")?;
+        let fx = FXFilter::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x92);
+
+        let params = fx.params()?;
+        assert_eq!(params.filter_type, FilterType::Unknown(36));
+        assert_eq!(params.u_b, Some(false));
+        assert_eq!(params.u_a, Some(36));
+        assert!((params.params[1] - 0.4).abs() < 0.001);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_type_52_v92() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x18-BParFXFilter/BParFXFilter-004.kon")?;
+        let fx = FXFilter::try_from(&Chunk::read(file)?)?;
+
+        let params = fx.params()?;
+        assert_eq!(params.filter_type, FilterType::Unknown(52));
+        assert!((params.params[0] - 0.745).abs() < 0.001);
+        assert!((params.params[1] - 0.242).abs() < 0.001);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_type_param_counts() -> Result<(), Error> {
+        let expected = [
+            ("005", 2, 2),
+            ("006", 3, 2),
+            ("007", 9, 2),
+            ("008", 50, 2),
+            ("009", 54, 2),
+            ("010", 90, 3),
+            ("011", 101, 2),
+            ("012", 102, 2),
+        ];
+        for (n, filter_type, num_params) in expected {
+            let file = File::open(format!(
+                "tests/data/Objects/Kontakt/0x18-BParFXFilter/BParFXFilter-{n}.kon"
+            ))?;
+            let params = FXFilter::try_from(&Chunk::read(file)?)?.params()?;
+            assert_eq!(params.filter_type, FilterType::Unknown(filter_type));
+            assert_eq!(params.params.len(), num_params);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_cutoff() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x18-BParFXFilter/BParFXFilter-005.kon")?;
+        let params = FXFilter::try_from(&Chunk::read(file)?)?.params()?;
+        assert!((params.cutoff().unwrap() - 0.845).abs() < 0.001);
+        assert_eq!(params.resonance(), Some(0.0));
+        Ok(())
+    }
+}
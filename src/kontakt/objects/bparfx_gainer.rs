@@ -0,0 +1,81 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x13;
+
+/// # FXGainer
+///
+/// Gainer effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x13
+/// - Versions:       0x50
+/// - Kontakt 7:      BParFXGainer
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXGainer(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXGainerParams {
+    /// Linear gain.
+    pub gain: f32,
+}
+
+impl FXGainer {
+    pub fn params(&self) -> Result<FXGainerParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x50 => Ok(FXGainerParams {
+                gain: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXGainer version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXGainer {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_gainer_v50() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x13-BParFXGainer/BParFXGainer-000.kon")?;
+        let fx = FXGainer::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x50);
+
+        let params = fx.params()?;
+        assert_eq!(params.gain, 1.0);
+        Ok(())
+    }
+}
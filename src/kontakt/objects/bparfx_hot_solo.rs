@@ -0,0 +1,110 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x54;
+
+/// # FXHotSolo
+///
+/// Hot Solo guitar amp.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x54
+/// - Versions:       0x51
+/// - Kontakt 7:      BParFXHotSolo
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXHotSolo(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXHotSoloParams {
+    /// Found 0.5
+    pub u_a: f32,
+    /// Found 0.5
+    pub u_b: f32,
+    /// Found 0.5
+    pub u_c: f32,
+    /// Found 0.5
+    pub u_d: f32,
+    /// Found 0.5
+    pub u_e: f32,
+    /// Found 0.5
+    pub u_f: f32,
+    /// Found 0.5
+    pub u_g: f32,
+    /// Found false
+    pub u_h: bool,
+    /// Found false
+    pub u_i: bool,
+    /// Found false
+    pub u_j: bool,
+}
+
+impl FXHotSolo {
+    pub fn params(&self) -> Result<FXHotSoloParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x51 => Ok(FXHotSoloParams {
+                u_a: reader.read_f32_le()?,
+                u_b: reader.read_f32_le()?,
+                u_c: reader.read_f32_le()?,
+                u_d: reader.read_f32_le()?,
+                u_e: reader.read_f32_le()?,
+                u_f: reader.read_f32_le()?,
+                u_g: reader.read_f32_le()?,
+                u_h: reader.read_bool()?,
+                u_i: reader.read_bool()?,
+                u_j: reader.read_bool()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXHotSolo version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXHotSolo {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_hot_solo_v51() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x54-BParFXHotSolo/BParFXHotSolo-000.kon")?;
+        let fx = FXHotSolo::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x51);
+
+        let params = fx.params()?;
+        assert_eq!(params.u_g, 0.5);
+        assert!(!params.u_j);
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x1C;
+
+/// # FXLimiter
+///
+/// Limiter effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x1C
+/// - Versions:       0x60
+/// - Kontakt 7:      BParFXLimiter
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXLimiter(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXLimiterParams {
+    /// Input gain in dB.
+    pub input_gain: f32,
+    /// Release time in ms.
+    pub release: f32,
+}
+
+impl FXLimiter {
+    pub fn params(&self) -> Result<FXLimiterParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x60 => Ok(FXLimiterParams {
+                input_gain: reader.read_f32_le()?,
+                release: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXLimiter version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXLimiter {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_limiter_v60() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x1C-BParFXLimiter/BParFXLimiter-000.kon")?;
+        let fx = FXLimiter::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x60);
+
+        let params = fx.params()?;
+        assert_eq!(params.input_gain, 0.0);
+        assert_eq!(params.release, 20.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x20;
+
+/// # FXLofi
+///
+/// Lo-fi effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x20
+/// - Versions:       0x70
+/// - Kontakt 7:      BParFXLofi
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXLofi(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXLofiParams {
+    pub bits: f32,
+    pub sample_rate: f32,
+    pub noise: f32,
+    pub u_a: bool,
+    pub noise_color: f32,
+}
+
+impl FXLofi {
+    pub fn params(&self) -> Result<FXLofiParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x70 => Ok(FXLofiParams {
+                bits: reader.read_f32_le()?,
+                sample_rate: reader.read_f32_le()?,
+                noise: reader.read_f32_le()?,
+                u_a: reader.read_bool()?,
+                noise_color: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXLofi version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXLofi {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_lofi_v70() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x20-BParFXLofi/BParFXLofi-000.kon")?;
+        let fx = FXLofi::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x70);
+
+        let params = fx.params()?;
+        assert!((params.bits - 0.333).abs() < 0.001);
+        assert!((params.sample_rate - 0.0142).abs() < 0.001);
+        assert_eq!(params.noise, 0.0);
+        assert!(!params.u_a);
+        assert!((params.noise_color - 0.2).abs() < 0.001);
+        Ok(())
+    }
+}
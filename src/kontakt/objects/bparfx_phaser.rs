@@ -0,0 +1,93 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x14;
+
+/// # FXPhaser
+///
+/// Phaser effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x14
+/// - Versions:       0x50
+/// - Kontakt 7:      BParFXPhaser
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXPhaser(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXPhaserParams {
+    pub depth: f32,
+    /// Rate in Hz.
+    pub speed: f32,
+    pub phase: f32,
+    pub feedback: f32,
+    /// Found -1.0
+    pub u_a: f32,
+}
+
+impl FXPhaser {
+    pub fn params(&self) -> Result<FXPhaserParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x50 => Ok(FXPhaserParams {
+                depth: reader.read_f32_le()?,
+                speed: reader.read_f32_le()?,
+                phase: reader.read_f32_le()?,
+                feedback: reader.read_f32_le()?,
+                u_a: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXPhaser version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXPhaser {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_phaser_v50() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x14-BParFXPhaser/BParFXPhaser-000.kon")?;
+        let fx = FXPhaser::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x50);
+
+        let params = fx.params()?;
+        assert!((params.depth - 0.79).abs() < 0.01);
+        assert_eq!(params.speed, 0.25);
+        assert_eq!(params.feedback, 0.5);
+        assert_eq!(params.u_a, -1.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x5B;
+
+/// # FXPhasis
+///
+/// Phasis phaser.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x5B
+/// - Versions:       0x51
+/// - Kontakt 7:      BParFXPhasis
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXPhasis(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXPhasisParams {
+    /// Found 0.5
+    pub u_a: f32,
+    /// Found 0.5
+    pub u_b: f32,
+    /// Found 0.5
+    pub u_c: f32,
+    /// Found 0.0
+    pub u_d: f32,
+    /// Found 1.0
+    pub u_e: f32,
+    /// Found 0.0
+    pub u_f: f32,
+    /// Found 1.0
+    pub u_g: f32,
+    /// Found 0.5
+    pub u_h: f32,
+    /// Found 0.0
+    pub u_i: f32,
+    /// Found false
+    pub u_j: bool,
+    /// Found false
+    pub u_k: bool,
+    /// Found false
+    pub u_l: bool,
+    /// Found -1.0
+    pub u_m: f32,
+    /// Found 0.0
+    pub u_n: f32,
+    /// Found 1.0
+    pub u_o: f32,
+    /// Found false
+    pub u_p: bool,
+}
+
+impl FXPhasis {
+    pub fn params(&self) -> Result<FXPhasisParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x51 => Ok(FXPhasisParams {
+                u_a: reader.read_f32_le()?,
+                u_b: reader.read_f32_le()?,
+                u_c: reader.read_f32_le()?,
+                u_d: reader.read_f32_le()?,
+                u_e: reader.read_f32_le()?,
+                u_f: reader.read_f32_le()?,
+                u_g: reader.read_f32_le()?,
+                u_h: reader.read_f32_le()?,
+                u_i: reader.read_f32_le()?,
+                u_j: reader.read_bool()?,
+                u_k: reader.read_bool()?,
+                u_l: reader.read_bool()?,
+                u_m: reader.read_f32_le()?,
+                u_n: reader.read_f32_le()?,
+                u_o: reader.read_f32_le()?,
+                u_p: reader.read_bool()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXPhasis version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXPhasis {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_phasis_v51() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x5B-BParFXPhasis/BParFXPhasis-000.kon")?;
+        let fx = FXPhasis::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x51);
+
+        let params = fx.params()?;
+        assert_eq!(params.u_m, -1.0);
+        assert_eq!(params.u_o, 1.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x57;
+
+/// # FXPlateReverb
+///
+/// Plate reverb.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x57
+/// - Versions:       0x10
+/// - Kontakt 7:      BParFXPlateReverb
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXPlateReverb(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXPlateReverbParams {
+    /// Found 0.5
+    pub u_a: f32,
+    /// Found 0.164
+    pub u_b: f32,
+    /// Found 0.5
+    pub u_c: f32,
+    /// Found 0.0
+    pub u_d: f32,
+    /// Found 1.0
+    pub u_e: f32,
+}
+
+impl FXPlateReverb {
+    pub fn params(&self) -> Result<FXPlateReverbParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x10 => Ok(FXPlateReverbParams {
+                u_a: reader.read_f32_le()?,
+                u_b: reader.read_f32_le()?,
+                u_c: reader.read_f32_le()?,
+                u_d: reader.read_f32_le()?,
+                u_e: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXPlateReverb version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXPlateReverb {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_plate_reverb_v10() -> Result<(), Error> {
+        let file = File::open(
+            "tests/data/Objects/Kontakt/0x57-BParFXPlateReverb/BParFXPlateReverb-000.kon",
+        )?;
+        let fx = FXPlateReverb::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x10);
+
+        let params = fx.params()?;
+        assert!((params.u_b - 0.164).abs() < 0.001);
+        assert_eq!(params.u_e, 1.0);
+        Ok(())
+    }
+}
@@ -1,24 +1,57 @@
 use std::io::Cursor;
 
 use crate::{
-    kontakt::{error::KontaktError, Chunk},
+    kontakt::{Chunk, KontaktError, StructuredObject},
     read_bytes::ReadBytesExt,
-    Error,
+    Error, NIFileError,
 };
 
 const CHUNK_ID: u16 = 0x22;
 
-/// Type:           Chunk<Data>
-/// SerType:        0x22
-/// Versions:       ?
-/// Kontakt 7:      BParFXRotator
-/// KontaktIO:
+/// # BParFXRotator
+///
+/// Rotary speaker effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x22
+/// - Versions:       0x50
+/// - Kontakt 7:      BParFXRotator
+/// - KontaktIO:      ?
+///
 #[derive(Debug)]
-pub struct BParFXRotator;
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BParFXRotator(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BParFXRotatorParams {
+    /// 0 = slow, 1 = fast?
+    pub speed: i32,
+    pub balance: f32,
+    pub accel_hi: f32,
+    pub accel_lo: f32,
+    pub distance: f32,
+    pub u_a: f32,
+}
 
 impl BParFXRotator {
-    pub fn read<R: ReadBytesExt>(mut _reader: R) -> Result<Self, Error> {
-        Ok(Self)
+    pub fn params(&self) -> Result<BParFXRotatorParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x50 => Ok(BParFXRotatorParams {
+                speed: reader.read_i32_le()?,
+                balance: reader.read_f32_le()?,
+                accel_hi: reader.read_f32_le()?,
+                accel_lo: reader.read_f32_le()?,
+                distance: reader.read_f32_le()?,
+                u_a: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported BParFXRotator version: 0x{:X}",
+                self.0.version
+            ))),
+        }
     }
 }
 
@@ -33,7 +66,28 @@ impl std::convert::TryFrom<&Chunk> for BParFXRotator {
             }
             .into());
         }
-        let reader = Cursor::new(&chunk.data);
-        Self::read(reader)
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_rotator_v50() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x22-BParFXRotator/BParFXRotator-000.kon")?;
+        let fx = BParFXRotator::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x50);
+
+        let params = fx.params()?;
+        assert_eq!(params.speed, 0);
+        assert_eq!(params.u_a, 1.0);
+        Ok(())
     }
 }
@@ -14,6 +14,7 @@ const CHUNK_ID: u16 = 0x17;
 /// Kontakt 7:      BParFXSendLevels
 /// KontaktIO:
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BParFXSendLevels;
 
 impl BParFXSendLevels {
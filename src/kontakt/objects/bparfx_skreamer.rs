@@ -0,0 +1,95 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x21;
+
+/// # FXSkreamer
+///
+/// Skreamer overdrive effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x21
+/// - Versions:       0x50
+/// - Kontakt 7:      BParFXSkreamer
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXSkreamer(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXSkreamerParams {
+    pub tone: f32,
+    pub drive: f32,
+    /// Found 0.5
+    pub u_a: f32,
+    /// Found 0.0, 0.5
+    pub u_b: f32,
+    /// Found 0.8
+    pub u_c: f32,
+}
+
+impl FXSkreamer {
+    pub fn params(&self) -> Result<FXSkreamerParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x50 => Ok(FXSkreamerParams {
+                tone: reader.read_f32_le()?,
+                drive: reader.read_f32_le()?,
+                u_a: reader.read_f32_le()?,
+                u_b: reader.read_f32_le()?,
+                u_c: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXSkreamer version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXSkreamer {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_skreamer_v50() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x21-BParFXSkreamer/BParFXSkreamer-000.kon")?;
+        let fx = FXSkreamer::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x50);
+
+        let params = fx.params()?;
+        assert!((params.tone - 0.879).abs() < 0.001);
+        assert!((params.drive - 0.138).abs() < 0.001);
+        assert_eq!(params.u_a, 0.5);
+        assert_eq!(params.u_c, 0.8);
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x46;
+
+/// # FXSSLGBusComp
+///
+/// Solid bus compressor.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x46
+/// - Versions:       0x12
+/// - Kontakt 7:      BParFXSSLGBusComp
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXSSLGBusComp(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXSSLGBusCompParams {
+    pub threshold: f32,
+    pub ratio: f32,
+    pub attack: f32,
+    pub release: f32,
+    pub makeup: f32,
+    pub mix: f32,
+    /// Found true
+    pub u_a: bool,
+    /// Found false
+    pub u_b: bool,
+    /// Found 0.63
+    pub u_c: f32,
+}
+
+impl FXSSLGBusComp {
+    pub fn params(&self) -> Result<FXSSLGBusCompParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x12 => Ok(FXSSLGBusCompParams {
+                threshold: reader.read_f32_le()?,
+                ratio: reader.read_f32_le()?,
+                attack: reader.read_f32_le()?,
+                release: reader.read_f32_le()?,
+                makeup: reader.read_f32_le()?,
+                mix: reader.read_f32_le()?,
+                u_a: reader.read_bool()?,
+                u_b: reader.read_bool()?,
+                u_c: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXSSLGBusComp version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXSSLGBusComp {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_ssl_bus_comp_v12() -> Result<(), Error> {
+        let file = File::open(
+            "tests/data/Objects/Kontakt/0x46-BParFXSSLGBusComp/BParFXSSLGBusComp-000.kon",
+        )?;
+        let fx = FXSSLGBusComp::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x12);
+
+        let params = fx.params()?;
+        assert_eq!(params.ratio, 0.2);
+        assert_eq!(params.mix, 1.0);
+        Ok(())
+    }
+}
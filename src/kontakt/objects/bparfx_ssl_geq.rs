@@ -0,0 +1,108 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x44;
+
+/// # FXSSLGEQ
+///
+/// Solid G-EQ four band equalizer.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x44
+/// - Versions:       0x10
+/// - Kontakt 7:      BParFXSSLGEQ
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXSSLGEQ(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXSSLGEQParams {
+    /// Normalized, 0.5 is 0 dB.
+    pub lf_gain: f32,
+    pub lf_freq: f32,
+    pub lf_bell: bool,
+    /// Normalized, 0.5 is 0 dB.
+    pub lmf_gain: f32,
+    pub lmf_freq: f32,
+    pub lmf_q: f32,
+    /// Normalized, 0.5 is 0 dB.
+    pub hmf_gain: f32,
+    pub hmf_freq: f32,
+    pub hmf_q: f32,
+    /// Normalized, 0.5 is 0 dB.
+    pub hf_gain: f32,
+    pub hf_freq: f32,
+    pub hf_bell: bool,
+}
+
+impl FXSSLGEQ {
+    pub fn params(&self) -> Result<FXSSLGEQParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x10 => Ok(FXSSLGEQParams {
+                lf_gain: reader.read_f32_le()?,
+                lf_freq: reader.read_f32_le()?,
+                lf_bell: reader.read_bool()?,
+                lmf_gain: reader.read_f32_le()?,
+                lmf_freq: reader.read_f32_le()?,
+                lmf_q: reader.read_f32_le()?,
+                hmf_gain: reader.read_f32_le()?,
+                hmf_freq: reader.read_f32_le()?,
+                hmf_q: reader.read_f32_le()?,
+                hf_gain: reader.read_f32_le()?,
+                hf_freq: reader.read_f32_le()?,
+                hf_bell: reader.read_bool()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXSSLGEQ version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXSSLGEQ {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_ssl_geq_v10() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x44-BParFXSSLGEQ/BParFXSSLGEQ-000.kon")?;
+        let fx = FXSSLGEQ::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x10);
+
+        let params = fx.params()?;
+        assert!((params.lf_gain - 0.42).abs() < 0.01);
+        assert!(!params.lf_bell);
+        assert!((params.hf_freq - 0.66).abs() < 0.01);
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x1F;
+
+/// # FXStereoSpread
+///
+/// Stereo modeller effect.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x1F
+/// - Versions:       0x70
+/// - Kontakt 7:      BParFXStereoSpread
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXStereoSpread(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FXStereoSpreadParams {
+    pub spread: f32,
+    pub pan: f32,
+    pub u_a: bool,
+}
+
+impl FXStereoSpread {
+    pub fn params(&self) -> Result<FXStereoSpreadParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x70 => Ok(FXStereoSpreadParams {
+                spread: reader.read_f32_le()?,
+                pan: reader.read_f32_le()?,
+                u_a: reader.read_bool()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported FXStereoSpread version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for FXStereoSpread {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_stereo_spread_v70() -> Result<(), Error> {
+        let file = File::open(
+            "tests/data/Objects/Kontakt/0x1F-BParFXStereoSpread/BParFXStereoSpread-000.kon",
+        )?;
+        let fx = FXStereoSpread::try_from(&Chunk::read(file)?)?;
+        assert_eq!(fx.0.version, 0x70);
+
+        let params = fx.params()?;
+        assert!((params.spread + 0.334).abs() < 0.01);
+        assert_eq!(params.pan, 0.0);
+        Ok(())
+    }
+}
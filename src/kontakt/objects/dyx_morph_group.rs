@@ -0,0 +1,101 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    read_bytes::ReadBytesExt,
+    Error,
+};
+
+const CHUNK_ID: u16 = 0x26;
+
+/// # BDyxMorphGroup
+///
+/// An AET morph group of a [`Program`](super::Program). Only the layout in
+/// `doc/presets/Kontakt/BDyxMorphGroup.md` is known, no AET instrument is
+/// available to name the fields.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x26
+/// - Versions:       ?
+/// - Kontakt 7:      BDyxMorphGroup
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BDyxMorphGroup(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DyxMorphGroupParams {
+    pub u_a: u32,
+    pub name: String,
+    pub u_b: f32,
+    pub u_c: f32,
+    pub u_d: f32,
+    pub u_e: bool,
+    pub u_f: u16,
+}
+
+impl BDyxMorphGroup {
+    pub fn params(&self) -> Result<DyxMorphGroupParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        Ok(DyxMorphGroupParams {
+            u_a: reader.read_u32_le()?,
+            name: reader.read_widestring_utf16()?,
+            u_b: reader.read_f32_le()?,
+            u_c: reader.read_f32_le()?,
+            u_d: reader.read_f32_le()?,
+            u_e: reader.read_bool()?,
+            u_f: reader.read_u16_le()?,
+        })
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for BDyxMorphGroup {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{write_bytes::WriteBytesExt, Error};
+
+    use super::*;
+
+    #[test]
+    fn test_dyx_morph_group_params() -> Result<(), Error> {
+        let mut public_data = Vec::new();
+        public_data.write_u32_le(1)?;
+        public_data.write_widestring_utf16("Legato")?;
+        public_data.write_f32_le(0.5)?;
+        public_data.write_f32_le(1.0)?;
+        public_data.write_f32_le(0.0)?;
+        public_data.write_bool(true)?;
+        public_data.write_u16_le(3)?;
+
+        let group = BDyxMorphGroup(StructuredObject {
+            is_structured: true,
+            version: 0x10,
+            public_data,
+            private_data: Vec::new(),
+            children: Vec::new(),
+        });
+        let params = group.params()?;
+        assert_eq!(params.name, "Legato");
+        assert_eq!(params.u_c, 1.0);
+        assert!(params.u_e);
+        assert_eq!(params.u_f, 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    Error,
+};
+
+const CHUNK_ID: u16 = 0x27;
+
+/// # BDyxMorphMap
+///
+/// An AET morph map of a [`Program`](super::Program). No known file contains
+/// one, so the layers and key/velocity mappings are not decoded.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x27
+/// - Versions:       ?
+/// - Kontakt 7:      BDyxMorphMap
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BDyxMorphMap(pub StructuredObject);
+
+impl std::convert::TryFrom<&Chunk> for BDyxMorphMap {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    kontakt::{Chunk, StructuredObject},
+    Error,
+};
+
+use super::{
+    BParFXRotator, BParFXSendLevels, FXACBox, FXCabinet, FXCat, FXChorus, FXCompressor, FXCryWah,
+    FXDStortion, FXDelay, FXDistortion, FXFilter, FXGainer, FXHotSolo, FXLimiter, FXLofi, FXPhaser,
+    FXPhasis, FXPlateReverb, FXSSLGBusComp, FXSkreamer, FXStereoSpread, FXSSLGEQ,
+};
+
+/// An effect object, usually the first child of a [`BParFX`](super::BParFX) slot.
+///
+/// Only effects found in sample files have decoded parameters. The others
+/// (flanger, reverb, twang, tape, trans and most of 0x4C - 0x64) keep their
+/// raw [`StructuredObject`], which carries the object version.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Effect {
+    Delay(FXDelay),
+    Chorus(FXChorus),
+    Flanger(StructuredObject),
+    Gainer(FXGainer),
+    Phaser(FXPhaser),
+    Reverb(StructuredObject),
+    Convolution(StructuredObject),
+    SendLevels(BParFXSendLevels),
+    Filter(FXFilter),
+    Compressor(FXCompressor),
+    Inverter(StructuredObject),
+    Dyx(StructuredObject),
+    Limiter(FXLimiter),
+    SurroundPanner(StructuredObject),
+    Distortion(FXDistortion),
+    StereoSpread(FXStereoSpread),
+    Lofi(FXLofi),
+    Skreamer(FXSkreamer),
+    Rotator(BParFXRotator),
+    Twang(StructuredObject),
+    Cabinet(FXCabinet),
+    Tape(StructuredObject),
+    Trans(StructuredObject),
+    SSLGEQ(FXSSLGEQ),
+    SSLGBusComp(FXSSLGBusComp),
+    FBComp(StructuredObject),
+    Jump(StructuredObject),
+    Van51(StructuredObject),
+    ACBox(FXACBox),
+    HotSolo(FXHotSolo),
+    Cat(FXCat),
+    DStortion(FXDStortion),
+    PlateReverb(FXPlateReverb),
+    CryWah(FXCryWah),
+    GaloisReverb(StructuredObject),
+    ReplikaDelay(StructuredObject),
+    Phasis(FXPhasis),
+    Flair(StructuredObject),
+    Choral(StructuredObject),
+    CoreCell(StructuredObject),
+    HilbertLimiter(StructuredObject),
+    Supercharger(StructuredObject),
+    BassPro(StructuredObject),
+    PsycheDelay(StructuredObject),
+    RingModulator(StructuredObject),
+    Unsupported(u16),
+}
+
+impl Effect {
+    /// The version of the effect object, if known.
+    pub fn version(&self) -> Option<u16> {
+        Some(match self {
+            Effect::Delay(fx) => fx.0.version,
+            Effect::Limiter(fx) => fx.0.version,
+            Effect::Distortion(fx) => fx.0.version,
+            Effect::StereoSpread(fx) => fx.0.version,
+            Effect::Rotator(fx) => fx.0.version,
+            Effect::Cabinet(fx) => fx.0.version,
+            Effect::Filter(fx) => fx.0.version,
+            Effect::Skreamer(fx) => fx.0.version,
+            Effect::Lofi(fx) => fx.0.version,
+            Effect::Compressor(fx) => fx.0.version,
+            Effect::Phaser(fx) => fx.0.version,
+            Effect::Gainer(fx) => fx.0.version,
+            Effect::Chorus(fx) => fx.0.version,
+            Effect::SSLGEQ(fx) => fx.0.version,
+            Effect::SSLGBusComp(fx) => fx.0.version,
+            Effect::ACBox(fx) => fx.0.version,
+            Effect::HotSolo(fx) => fx.0.version,
+            Effect::Cat(fx) => fx.0.version,
+            Effect::DStortion(fx) => fx.0.version,
+            Effect::PlateReverb(fx) => fx.0.version,
+            Effect::CryWah(fx) => fx.0.version,
+            Effect::Phasis(fx) => fx.0.version,
+            Effect::SendLevels(_) | Effect::Unsupported(_) => return None,
+            Effect::Flanger(so)
+            | Effect::Reverb(so)
+            | Effect::Convolution(so)
+            | Effect::Inverter(so)
+            | Effect::Dyx(so)
+            | Effect::SurroundPanner(so)
+            | Effect::Twang(so)
+            | Effect::Tape(so)
+            | Effect::Trans(so)
+            | Effect::FBComp(so)
+            | Effect::Jump(so)
+            | Effect::Van51(so)
+            | Effect::GaloisReverb(so)
+            | Effect::ReplikaDelay(so)
+            | Effect::Flair(so)
+            | Effect::Choral(so)
+            | Effect::CoreCell(so)
+            | Effect::HilbertLimiter(so)
+            | Effect::Supercharger(so)
+            | Effect::BassPro(so)
+            | Effect::PsycheDelay(so)
+            | Effect::RingModulator(so) => so.version,
+        })
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for Effect {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        Ok(match chunk.id {
+            0x10 => Effect::Delay(chunk.try_into()?),
+            0x11 => Effect::Chorus(chunk.try_into()?),
+            0x12 => Effect::Flanger(chunk.try_into()?),
+            0x13 => Effect::Gainer(chunk.try_into()?),
+            0x14 => Effect::Phaser(chunk.try_into()?),
+            0x15 => Effect::Reverb(chunk.try_into()?),
+            0x16 => Effect::Convolution(chunk.try_into()?),
+            0x17 => Effect::SendLevels(chunk.try_into()?),
+            0x18 => Effect::Filter(chunk.try_into()?),
+            0x19 => Effect::Compressor(chunk.try_into()?),
+            0x1a => Effect::Inverter(chunk.try_into()?),
+            0x1b => Effect::Dyx(chunk.try_into()?),
+            0x1c => Effect::Limiter(chunk.try_into()?),
+            0x1d => Effect::SurroundPanner(chunk.try_into()?),
+            0x1e => Effect::Distortion(chunk.try_into()?),
+            0x1f => Effect::StereoSpread(chunk.try_into()?),
+            0x20 => Effect::Lofi(chunk.try_into()?),
+            0x21 => Effect::Skreamer(chunk.try_into()?),
+            0x22 => Effect::Rotator(chunk.try_into()?),
+            0x23 => Effect::Twang(chunk.try_into()?),
+            0x24 => Effect::Cabinet(chunk.try_into()?),
+            0x42 => Effect::Tape(chunk.try_into()?),
+            0x43 => Effect::Trans(chunk.try_into()?),
+            0x44 => Effect::SSLGEQ(chunk.try_into()?),
+            0x46 => Effect::SSLGBusComp(chunk.try_into()?),
+            0x4c => Effect::FBComp(chunk.try_into()?),
+            0x4d => Effect::Jump(chunk.try_into()?),
+            0x52 => Effect::Van51(chunk.try_into()?),
+            0x53 => Effect::ACBox(chunk.try_into()?),
+            0x54 => Effect::HotSolo(chunk.try_into()?),
+            0x55 => Effect::Cat(chunk.try_into()?),
+            0x56 => Effect::DStortion(chunk.try_into()?),
+            0x57 => Effect::PlateReverb(chunk.try_into()?),
+            0x58 => Effect::CryWah(chunk.try_into()?),
+            0x59 => Effect::GaloisReverb(chunk.try_into()?),
+            0x5a => Effect::ReplikaDelay(chunk.try_into()?),
+            0x5b => Effect::Phasis(chunk.try_into()?),
+            0x5c => Effect::Flair(chunk.try_into()?),
+            0x5d => Effect::Choral(chunk.try_into()?),
+            0x5e => Effect::CoreCell(chunk.try_into()?),
+            0x5f => Effect::HilbertLimiter(chunk.try_into()?),
+            0x60 => Effect::Supercharger(chunk.try_into()?),
+            0x61 => Effect::BassPro(chunk.try_into()?),
+            0x63 => Effect::PsycheDelay(chunk.try_into()?),
+            0x64 => Effect::RingModulator(chunk.try_into()?),
+            id => Effect::Unsupported(id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::{kontakt::objects::BParFX, Error};
+
+    use super::*;
+
+    #[test]
+    fn test_effect_from_bparfx() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x25-BParFX/BParFXV50-000.kon")?;
+        let bparfx = BParFX::try_from(&Chunk::read(file)?)?;
+        let effect = bparfx.fx()?;
+        assert!(matches!(effect, Some(Effect::SendLevels(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_effect_version() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x24-BParFXCabinet/BParFXCabinet-000.kon")?;
+        let effect = Effect::try_from(&Chunk::read(file)?)?;
+        assert!(matches!(effect, Effect::Cabinet(_)));
+        assert_eq!(effect.version(), Some(0x50));
+        Ok(())
+    }
+}
@@ -1,12 +1,65 @@
-pub struct Envelope {}
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    Error,
+};
 
-use crate::{read_bytes::ReadBytesExt, Error};
+use super::{envelope_ahdsr, EnvelopeAHDSR};
 
+const CHUNK_ID: u16 = 0x07;
+
+/// # Envelope
+///
+/// Container for an envelope. The envelope shape is the first child,
+/// e.g. [`EnvelopeAHDSR`].
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x07
+/// - Versions:       0x90
+/// - Kontakt 7:      BParEnv
+/// - KontaktIO:      ?
+///
 #[derive(Debug)]
-pub struct EnvelopeAHDSR_V10;
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Envelope(pub StructuredObject);
+
+impl Envelope {
+    pub fn ahdsr(&self) -> Result<Option<EnvelopeAHDSR>, Error> {
+        self.0
+            .find_first(envelope_ahdsr::CHUNK_ID)
+            .map(EnvelopeAHDSR::try_from)
+            .transpose()
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for Envelope {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
 
-impl EnvelopeAHDSR_V10 {
-    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
-        Ok(Self {})
+    #[test]
+    fn test_envelope() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x07-BParEnv/BParEnv-000.kon")?;
+        let env = Envelope::try_from(&Chunk::read(file)?)?;
+        assert_eq!(env.0.version, 0x90);
+        assert!(env.ahdsr()?.is_some());
+        Ok(())
     }
 }
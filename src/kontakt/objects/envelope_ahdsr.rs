@@ -0,0 +1,164 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+pub(super) const CHUNK_ID: u16 = 0x3F;
+
+/// # EnvelopeAHDSR
+///
+/// Attack, hold, decay, sustain, release envelope.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x3F
+/// - Versions:       0x10, 0x11
+/// - Kontakt 7:      BParEnvAHDSR
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvelopeAHDSR(pub StructuredObject);
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvelopeAHDSRParams {
+    pub attack_curve: f32,
+    /// Times in ms.
+    pub attack: f32,
+    pub hold: f32,
+    pub decay: f32,
+    pub release: f32,
+    /// Linear, 0.0 - 1.0
+    pub sustain: f32,
+    pub note_off_less_mode: bool,
+    /// Always 4 entries.
+    pub u_a: Vec<EnvelopeAHDSRUnknown>,
+}
+
+/// Trailing entry of [`EnvelopeAHDSRParams`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvelopeAHDSRUnknown {
+    /// Found -1.0
+    pub u_a: f32,
+    /// Only in v11. Found 0.0
+    pub u_b: Option<f32>,
+    /// Only in v11. Found 1.0
+    pub u_c: Option<f32>,
+    /// Only in v11. Found false
+    pub u_d: Option<bool>,
+}
+
+impl EnvelopeAHDSR {
+    pub fn params(&self) -> Result<EnvelopeAHDSRParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+        let version = self.0.version;
+
+        if !matches!(version, 0x10 | 0x11) {
+            return Err(NIFileError::Generic(format!(
+                "Unsupported EnvelopeAHDSR version: 0x{:X}",
+                version
+            )));
+        }
+
+        let attack_curve = reader.read_f32_le()?;
+        let attack = reader.read_f32_le()?;
+        let hold = reader.read_f32_le()?;
+        let decay = reader.read_f32_le()?;
+        let release = reader.read_f32_le()?;
+        let sustain = reader.read_f32_le()?;
+        let note_off_less_mode = reader.read_bool()?;
+
+        let mut u_a = Vec::new();
+        for _ in 0..4 {
+            u_a.push(match version {
+                0x11 => EnvelopeAHDSRUnknown {
+                    u_a: reader.read_f32_le()?,
+                    u_b: Some(reader.read_f32_le()?),
+                    u_c: Some(reader.read_f32_le()?),
+                    u_d: Some(reader.read_bool()?),
+                },
+                _ => EnvelopeAHDSRUnknown {
+                    u_a: reader.read_f32_le()?,
+                    u_b: None,
+                    u_c: None,
+                    u_d: None,
+                },
+            });
+        }
+
+        Ok(EnvelopeAHDSRParams {
+            attack_curve,
+            attack,
+            hold,
+            decay,
+            release,
+            sustain,
+            note_off_less_mode,
+            u_a,
+        })
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for EnvelopeAHDSR {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_envelope_ahdsr_v10() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x3F-BParEnvAHDSR/BParEnvAHDSR-000.kon")?;
+        let env = EnvelopeAHDSR::try_from(&Chunk::read(file)?)?;
+        assert_eq!(env.0.version, 0x10);
+
+        let params = env.params()?;
+        assert_eq!(params.hold, 50.0);
+        assert_eq!(params.sustain, 1.0);
+        assert_eq!(params.u_a.len(), 4);
+        assert_eq!(params.u_a[3].u_a, -1.0);
+        assert_eq!(params.u_a[3].u_b, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_envelope_ahdsr_v11() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x3F-BParEnvAHDSR/BParEnvAHDSR-001.kon")?;
+        let env = EnvelopeAHDSR::try_from(&Chunk::read(file)?)?;
+        assert_eq!(env.0.version, 0x11);
+
+        let params = env.params()?;
+        assert_eq!(params.attack, 12.3);
+        assert_eq!(params.hold, 11.2);
+        assert_eq!(params.decay, 123.4);
+        assert_eq!(params.release, 22.5);
+        assert!(params.note_off_less_mode);
+
+        let last = &params.u_a[3];
+        assert_eq!(last.u_a, -1.0);
+        assert_eq!(last.u_b, Some(0.0));
+        assert_eq!(last.u_c, Some(1.0));
+        assert_eq!(last.u_d, Some(false));
+        Ok(())
+    }
+}
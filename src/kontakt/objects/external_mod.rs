@@ -0,0 +1,148 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+use super::ModBaseParams;
+
+const CHUNK_ID: u16 = 0x0C;
+
+/// # ExternalMod
+///
+/// A modulation assignment driven by an external (MIDI) source, such as
+/// velocity, pitch bend or a MIDI CC. All parameters are stored in the
+/// private data.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x0C
+/// - Versions:       0x100, 0x102
+/// - Kontakt 7:      BParExternalMod
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExternalMod(pub StructuredObject);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExternalModParams {
+    pub base: ModBaseParams,
+    /// e.g. "VEL_VOLUME", "PB_PITCH", "CC_FREQUENCY", "External Mod."
+    pub name: String,
+    /// Found 1
+    pub u_a: u32,
+    /// Found values: 1 = pitch bend, 3 = aftertouch, 4 = midi cc, 6 = velocity
+    pub source: i32,
+    /// MIDI controller number, only stored for MIDI CC sources.
+    pub cc_number: Option<u8>,
+    /// Found -1 (velocity, pitch bend), 0
+    pub initial_value: i16,
+    pub bypass: bool,
+    pub simulate_k2_env_time_modulation: bool,
+    /// Found 1 to 0x15
+    pub class_id: i32,
+}
+
+/// [`ExternalModParams::source`] of MIDI CC modulators.
+pub const EXTERNAL_MOD_SOURCE_MIDI_CC: i32 = 4;
+
+impl ExternalMod {
+    pub fn params(&self) -> Result<ExternalModParams, Error> {
+        let mut reader = Cursor::new(&self.0.private_data);
+
+        match self.0.version {
+            0x100 | 0x102 => {
+                let base = ModBaseParams::read(&mut reader)?;
+                let name = reader.read_sized_utf8()?;
+                let u_a = reader.read_u32_le()?;
+                let source = reader.read_i32_le()?;
+                let cc_number = match source {
+                    EXTERNAL_MOD_SOURCE_MIDI_CC => Some(reader.read_u8()?),
+                    _ => None,
+                };
+
+                Ok(ExternalModParams {
+                    base,
+                    name,
+                    u_a,
+                    source,
+                    cc_number,
+                    initial_value: reader.read_i16_le()?,
+                    bypass: reader.read_bool()?,
+                    simulate_k2_env_time_modulation: reader.read_bool()?,
+                    class_id: reader.read_i32_le()?,
+                })
+            }
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported ExternalMod version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for ExternalMod {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_external_mod_cc() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x0C-ExternalMod/ExternalMod-000.kon")?;
+        let external_mod = ExternalMod::try_from(&Chunk::read(file)?)?;
+        assert_eq!(external_mod.0.version, 0x100);
+
+        let params = external_mod.params()?;
+        assert_eq!(params.base.targets[0].target, "intensity");
+        assert_eq!(params.base.targets[0].target_obj_idx, Some(1));
+        assert!(!params.base.targets[0].table_open);
+        assert_eq!(params.name, "CC_FREQUENCY");
+        assert_eq!(params.source, 4);
+        assert_eq!(params.cc_number, Some(1));
+        assert_eq!(params.initial_value, 0);
+        assert!(!params.bypass);
+        assert_eq!(params.class_id, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_external_mod_pitch_bend() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x0C-ExternalMod/ExternalMod-001.kon")?;
+        let external_mod = ExternalMod::try_from(&Chunk::read(file)?)?;
+        assert_eq!(external_mod.0.version, 0x102);
+
+        let params = external_mod.params()?;
+        assert_eq!(params.base.targets[0].target, "pitch");
+        assert_eq!(params.base.targets[0].target_obj_idx, None);
+        assert_eq!(params.base.targets[0].smoothing_coef, 250);
+        assert_eq!(params.name, "PB_PITCH");
+        assert_eq!(params.source, 1);
+        assert_eq!(params.cc_number, None);
+        assert_eq!(params.initial_value, -1);
+        assert!(!params.bypass);
+        assert!(params.simulate_k2_env_time_modulation);
+        assert_eq!(params.class_id, 2);
+        Ok(())
+    }
+}
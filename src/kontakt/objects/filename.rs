@@ -1,62 +1,383 @@
-use crate::{read_bytes::ReadBytesExt, Error};
+use std::path::{Component, Path, PathBuf};
 
-pub struct BFileName;
-pub struct BFileNameSegment;
+use crate::{read_bytes::ReadBytesExt, write_bytes::WriteBytesExt, Error, NIFileError};
+
+/// Type:           Raw
+/// Kontakt 7:      BFileName
+/// KontaktIO:      BFileName
+///
+/// Internally, kontakt breaks paths into segments for multiplatform support.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BFileName {
+    pub segments: Vec<BFileNameSegment>,
+    /// Stored with a segment count of -1 instead of 0. Written back as -1
+    /// while there are no segments.
+    pub unset: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BFileNameSegment {
+    /// 0x01: Drive letter or volume name, empty for the root on macOS
+    Drive(String),
+    /// 0x02
+    Directory(String),
+    /// 0x03: ".."
+    Parent,
+    /// 0x04
+    FileName(String),
+    /// 0x05: Found in place of directories and filenames
+    Name(String),
+    /// 0x06: A location managed by Kontakt, e.g. the factory content
+    SpecialLocation,
+    /// 0x08: Library container (nkx, nkr), used like a directory
+    Library(String),
+    /// 0x09: Multi (nkm), used like a directory
+    MultiFile(String),
+}
 
 impl BFileName {
-    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Vec<String>, Error> {
-        let segments = reader.read_i32_le()?;
-        let mut filename = Vec::new();
-        for _ in 0..segments {
-            match BFileNameSegment::read(&mut reader) {
-                Ok(segment) => filename.push(segment),
-                Err(e) => {
-                    dbg!(e, &filename);
+    // K4PatchLib::BFileName::Retrieve
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        // -1 if unset
+        let segment_count = reader.read_i32_le()?;
+        if segment_count < -1 {
+            return Err(NIFileError::Generic(format!(
+                "Invalid BFileName segment count: {segment_count}"
+            )));
+        }
+
+        let mut segments = Vec::new();
+        for _ in 0..segment_count {
+            segments.push(BFileNameSegment::read(&mut reader)?);
+        }
+        Ok(Self {
+            segments,
+            unset: segment_count == -1,
+        })
+    }
+
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        match self.unset && self.segments.is_empty() {
+            true => writer.write_i32_le(-1)?,
+            false => writer.write_i32_le(self.segments.len() as i32)?,
+        }
+        for segment in &self.segments {
+            segment.write(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Starts with a drive or volume.
+    pub fn is_absolute(&self) -> bool {
+        matches!(self.segments.first(), Some(BFileNameSegment::Drive(_)))
+    }
+
+    /// The last segment, if it names a file.
+    pub fn file_name(&self) -> Option<&str> {
+        match self.segments.last()? {
+            BFileNameSegment::FileName(name) | BFileNameSegment::Name(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The library container (nkx, nkr) the file is stored in.
+    pub fn library(&self) -> Option<&str> {
+        self.segments.iter().find_map(|segment| match segment {
+            BFileNameSegment::Library(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Renders the path with "/" separators. Windows drives become a
+    /// top-level directory, e.g. "/C/Users", the macOS root is "/".
+    pub fn to_posix(&self) -> String {
+        self.render("/", |drive| match drive {
+            "" => String::new(),
+            drive => format!("/{drive}"),
+        })
+    }
+
+    /// Renders the path with "\" separators, e.g. "C:\Users".
+    pub fn to_windows(&self) -> String {
+        self.render("\\", |drive| match drive {
+            "" => String::new(),
+            drive => format!("{drive}:"),
+        })
+    }
+
+    fn render(&self, separator: &str, drive: impl Fn(&str) -> String) -> String {
+        let mut parts = Vec::new();
+        for segment in &self.segments {
+            match segment {
+                BFileNameSegment::Drive(name) => parts.push(drive(name)),
+                // resolved by Kontakt, the rest of the path is relative to it
+                BFileNameSegment::SpecialLocation => {}
+                segment => parts.push(segment.name().to_string()),
+            }
+        }
+        match (self.segments.first(), parts.len()) {
+            (Some(BFileNameSegment::Drive(_)), 1) => parts[0].clone() + separator,
+            _ => parts.join(separator),
+        }
+    }
+
+    /// The path on this system, relative paths are joined to `base`.
+    pub fn to_path(&self, base: &Path) -> PathBuf {
+        let mut segments = self.segments.iter().peekable();
+        let mut path = match segments.peek() {
+            Some(BFileNameSegment::Drive(name)) if name.is_empty() => PathBuf::from("/"),
+            Some(BFileNameSegment::Drive(name)) => PathBuf::from(format!("{name}:/")),
+            _ => base.to_path_buf(),
+        };
+        if matches!(segments.peek(), Some(BFileNameSegment::Drive(_))) {
+            segments.next();
+        }
+        for segment in segments {
+            match segment {
+                BFileNameSegment::Parent => {
+                    path.pop();
                 }
-            };
+                BFileNameSegment::SpecialLocation | BFileNameSegment::Drive(_) => {}
+                segment => path.push(segment.name()),
+            }
         }
-        Ok(filename)
+        path
     }
 
-    // K4PatchLib::BFileName::Retrieve
-    pub fn read_filename<R: ReadBytesExt>(mut reader: R) -> Result<BFileName, Error> {
-        let i = reader.read_i32_le()?;
-        if i < 0 {
-            reader.read_widestring_utf16()?;
-        } else if i > 0 {
+    /// A path on this system, relative to `base` if both share a root.
+    pub fn from_path(path: &Path, base: &Path) -> Self {
+        let path: Vec<_> = path.components().collect();
+        let base: Vec<_> = base.components().collect();
+
+        let common = path.iter().zip(&base).take_while(|(a, b)| a == b).count();
+        let only_root = common == 1 && matches!(path.first(), Some(Component::RootDir));
+
+        let mut segments = Vec::new();
+        let rest = if common > 0 && !only_root {
+            segments.extend(base[common..].iter().map(|_| BFileNameSegment::Parent));
+            &path[common..]
+        } else {
+            &path[..]
+        };
+
+        for (i, component) in rest.iter().enumerate() {
+            let name = component.as_os_str().to_string_lossy().into_owned();
+            match component {
+                Component::Prefix(_) => segments.push(BFileNameSegment::Drive(
+                    name.trim_end_matches(':').to_string(),
+                )),
+                Component::RootDir if segments.is_empty() => {
+                    segments.push(BFileNameSegment::Drive(String::new()))
+                }
+                Component::RootDir | Component::CurDir => {}
+                Component::ParentDir => segments.push(BFileNameSegment::Parent),
+                Component::Normal(_) if i == rest.len() - 1 => {
+                    segments.push(BFileNameSegment::FileName(name))
+                }
+                Component::Normal(_) => segments.push(BFileNameSegment::Directory(name)),
+            }
+        }
+        Self {
+            segments,
+            unset: false,
         }
+    }
+}
+
+/// Parses the form written by [`Display`](std::fmt::Display), segments joined
+/// with "/".
+///
+/// Segment types are inferred: containers (nkx, nkr) and multis (nkm) inside
+/// the path are libraries and multi files, a last segment with an extension
+/// is a filename.
+impl From<&str> for BFileName {
+    fn from(path: &str) -> Self {
+        let names: Vec<&str> = match path {
+            "" => Vec::new(),
+            path => path.split(['/', '\\']).collect(),
+        };
 
-        Ok(BFileName)
+        let mut segments = Vec::new();
+        for (i, name) in names.iter().enumerate() {
+            let extension = Path::new(name)
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_lowercase())
+                .filter(|ext| ext.chars().any(|c| c.is_alphabetic()));
+            segments.push(match (*name, extension.as_deref()) {
+                ("..", _) => BFileNameSegment::Parent,
+                ("", _) if i == 0 => BFileNameSegment::SpecialLocation,
+                (drive, _) if i == 0 && drive.ends_with(':') => {
+                    BFileNameSegment::Drive(drive.trim_end_matches(':').to_string())
+                }
+                (name, Some(_)) if i == names.len() - 1 => {
+                    BFileNameSegment::FileName(name.to_string())
+                }
+                (name, Some("nkx" | "nkr")) => BFileNameSegment::Library(name.to_string()),
+                (name, Some("nkm")) => BFileNameSegment::MultiFile(name.to_string()),
+                (name, _) => BFileNameSegment::Directory(name.to_string()),
+            });
+        }
+        Self {
+            segments,
+            unset: false,
+        }
+    }
+}
+
+/// Segments joined with "/". Drives end in ":", a special location is an
+/// empty first segment.
+impl std::fmt::Display for BFileName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = self
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                BFileNameSegment::Drive(name) => format!("{name}:"),
+                segment => segment.name().to_string(),
+            })
+            .collect();
+        f.write_str(&names.join("/"))
     }
 }
 
-/// Internally, kontakt breaks paths into segments for multiplatform support.
 impl BFileNameSegment {
-    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<String, Error> {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
         let segment_type = reader.read_u8()?;
         Ok(match segment_type {
-            1 => {
-                // root file node
-                format!("{}:", reader.read_widestring_utf16()?)
-            }
-            3 => {
-                // parent dir
-                String::from("..")
-            }
-            2 | 4 | 5 => reader.read_widestring_utf16()?,
-            6 => {
-                // set special location
-                String::new()
-            }
-            8 => {
-                // Library (nkx)
-                reader.read_widestring_utf16()?
+            0x01 => Self::Drive(reader.read_widestring_utf16()?),
+            0x02 => Self::Directory(reader.read_widestring_utf16()?),
+            0x03 => Self::Parent,
+            0x04 => Self::FileName(reader.read_widestring_utf16()?),
+            0x05 => Self::Name(reader.read_widestring_utf16()?),
+            0x06 => Self::SpecialLocation,
+            0x08 => Self::Library(reader.read_widestring_utf16()?),
+            0x09 => Self::MultiFile(reader.read_widestring_utf16()?),
+            _ => {
+                return Err(NIFileError::Generic(format!(
+                    "Unknown BFileName segment type: {segment_type}"
+                )))
             }
-            9 => {
-                // multi file (used like a dir)
-                reader.read_widestring_utf16()?
-            }
-            _ => panic!("unknown segment id: {segment_type}"),
         })
     }
+
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_u8(self.segment_type())?;
+        match self {
+            Self::Parent | Self::SpecialLocation => {}
+            segment => writer.write_widestring_utf16(segment.name())?,
+        }
+        Ok(())
+    }
+
+    pub fn segment_type(&self) -> u8 {
+        match self {
+            Self::Drive(_) => 0x01,
+            Self::Directory(_) => 0x02,
+            Self::Parent => 0x03,
+            Self::FileName(_) => 0x04,
+            Self::Name(_) => 0x05,
+            Self::SpecialLocation => 0x06,
+            Self::Library(_) => 0x08,
+            Self::MultiFile(_) => 0x09,
+        }
+    }
+
+    /// The name of the segment, ".." for parents and empty for special
+    /// locations.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Drive(name)
+            | Self::Directory(name)
+            | Self::FileName(name)
+            | Self::Name(name)
+            | Self::Library(name)
+            | Self::MultiFile(name) => name,
+            Self::Parent => "..",
+            Self::SpecialLocation => "",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::Cursor};
+
+    use super::*;
+    use crate::kontakt::Chunk;
+
+    #[test]
+    fn test_bfilename_unset() -> Result<(), Error> {
+        let path = BFileName::read(Cursor::new((-1i32).to_le_bytes()))?;
+        assert!(path.unset);
+        assert!(path.is_empty());
+
+        let mut data = Vec::new();
+        path.write(&mut data)?;
+        assert_eq!(data, (-1i32).to_le_bytes());
+
+        let path = BFileName::read(Cursor::new(0i32.to_le_bytes()))?;
+        assert!(!path.unset);
+        let mut data = Vec::new();
+        path.write(&mut data)?;
+        assert_eq!(data, 0i32.to_le_bytes());
+
+        assert!(BFileName::read(Cursor::new((-2i32).to_le_bytes())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bfilename_render() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x4B-FNTableImpl/FNTableImpl-000.kon")?;
+        let mut reader = Cursor::new(Chunk::read(file)?.data);
+        reader.read_u16_le()?; // version
+        reader.read_u32_le()?; // file count
+
+        let filename = BFileName::read(&mut reader)?;
+        assert_eq!(
+            filename.segments,
+            vec![
+                BFileNameSegment::Drive("C".into()),
+                BFileNameSegment::Directory("Users".into()),
+                BFileNameSegment::Directory("Administrator".into()),
+                BFileNameSegment::Directory("Desktop".into()),
+            ]
+        );
+        assert!(filename.is_absolute());
+        assert_eq!(filename.to_string(), "C:/Users/Administrator/Desktop");
+        assert_eq!(filename.to_windows(), r"C:\Users\Administrator\Desktop");
+        assert_eq!(filename.to_posix(), "/C/Users/Administrator/Desktop");
+
+        let mut bytes = Vec::new();
+        filename.write(&mut bytes)?;
+        assert_eq!(BFileName::read(Cursor::new(bytes))?, filename);
+
+        let filename = BFileName::from("../Samples/K4IR.nkx/hall.wav");
+        assert_eq!(filename.library(), Some("K4IR.nkx"));
+        assert_eq!(filename.file_name(), Some("hall.wav"));
+        assert_eq!(filename.to_windows(), r"..\Samples\K4IR.nkx\hall.wav");
+        Ok(())
+    }
+
+    #[test]
+    fn test_bfilename_from_path() {
+        let filename = BFileName::from_path(
+            Path::new("/lib/Samples/a.wav"),
+            Path::new("/lib/Instruments"),
+        );
+        assert_eq!(filename.to_string(), "../Samples/a.wav");
+        assert_eq!(
+            filename.to_path(Path::new("/lib/Instruments")),
+            PathBuf::from("/lib/Samples/a.wav")
+        );
+
+        let filename = BFileName::from_path(Path::new("/lib/a.wav"), Path::new("/other"));
+        assert_eq!(filename.to_string(), ":/lib/a.wav");
+        assert_eq!(filename.to_posix(), "/lib/a.wav");
+    }
 }
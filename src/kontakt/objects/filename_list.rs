@@ -41,10 +41,11 @@ use time::OffsetDateTime;
 use crate::{
     kontakt::{chunk::Chunk, KontaktError},
     read_bytes::ReadBytesExt,
+    write_bytes::WriteBytesExt,
     Error,
 };
 
-use super::BFileName;
+use super::{filename_table::write_filetable, BFileName};
 
 const CHUNK_ID: u16 = 0x3D;
 
@@ -52,16 +53,19 @@ const CHUNK_ID: u16 = 0x3D;
 /// SerType:        0x3D
 /// Kontakt 7:      FNTablePreK51
 /// KontaktIO:      FileNameListPreK51
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FileNameListPreK51 {
     /// List of resources and paths (nkr, search paths)
-    pub special_filetable: HashMap<u32, String>,
+    pub special_filetable: HashMap<u32, BFileName>,
     /// List of samples (wav, ncw)
-    pub sample_filetable: HashMap<u32, String>,
+    pub sample_filetable: HashMap<u32, BFileName>,
     /// List of sample timestamps
-    pub sample_timestamp_table: HashMap<u32, time::Date>,
+    pub sample_timestamp_table: HashMap<u32, OffsetDateTime>,
     /// List of instruments (nki) and internal files (ir samples)
-    pub other_filetable: HashMap<u32, String>,
+    pub other_filetable: HashMap<u32, BFileName>,
+    /// Found per other file, 1 for the first file and 0 otherwise
+    pub u_a: HashMap<u32, u32>,
 }
 
 impl FileNameListPreK51 {
@@ -70,22 +74,22 @@ impl FileNameListPreK51 {
         let file_count = reader.read_u32_le()?;
         let mut special_filetable = HashMap::new();
         for i in 0..file_count {
-            special_filetable.insert(i, BFileName::read(&mut reader)?.join("/"));
+            special_filetable.insert(i, BFileName::read(&mut reader)?);
         }
 
         // sample filetable
         let file_count = reader.read_u32_le()?;
         let mut sample_filetable = HashMap::new();
         for i in 0..file_count {
-            sample_filetable.insert(i, BFileName::read(&mut reader)?.join("/"));
+            sample_filetable.insert(i, BFileName::read(&mut reader)?);
         }
 
         // sample timestamps
         let mut sample_timestamp_table = HashMap::new();
         for i in 0..file_count {
             let unix_timestamp = reader.read_u64_le()? as i64;
-            let datetime = OffsetDateTime::from_unix_timestamp(unix_timestamp).unwrap();
-            let timestamp: time::Date = datetime.date();
+            let timestamp = OffsetDateTime::from_unix_timestamp(unix_timestamp)
+                .map_err(|_| Error::Static("Invalid sample timestamp"))?;
             sample_timestamp_table.insert(i, timestamp);
         }
 
@@ -93,7 +97,12 @@ impl FileNameListPreK51 {
         let file_count = reader.read_u32_le()?;
         let mut other_filetable = HashMap::new();
         for i in 0..file_count {
-            other_filetable.insert(i, BFileName::read(&mut reader)?.join("/"));
+            other_filetable.insert(i, BFileName::read(&mut reader)?);
+        }
+
+        let mut u_a = HashMap::new();
+        for i in 0..file_count {
+            u_a.insert(i, reader.read_u32_le()?);
         }
 
         Ok(Self {
@@ -101,8 +110,37 @@ impl FileNameListPreK51 {
             sample_filetable,
             sample_timestamp_table,
             other_filetable,
+            u_a,
         })
     }
+
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        write_filetable(&mut writer, &self.special_filetable)?;
+
+        let file_count = write_filetable(&mut writer, &self.sample_filetable)?;
+        for i in 0..file_count {
+            let timestamp = self
+                .sample_timestamp_table
+                .get(&i)
+                .map(|t| t.unix_timestamp())
+                .unwrap_or_default();
+            writer.write_u64_le(timestamp as u64)?;
+        }
+
+        let file_count = write_filetable(&mut writer, &self.other_filetable)?;
+        for i in 0..file_count {
+            writer.write_u32_le(self.u_a.get(&i).copied().unwrap_or_default())?;
+        }
+
+        Ok(())
+    }
+
+    /// The table as a [`Chunk`], for writing it back into a preset.
+    pub fn to_chunk(&self) -> Result<Chunk, Error> {
+        let mut data = Vec::new();
+        self.write(&mut data)?;
+        Ok(Chunk { id: CHUNK_ID, data })
+    }
 }
 
 impl std::convert::TryFrom<&Chunk> for FileNameListPreK51 {
@@ -135,4 +173,17 @@ mod tests {
         FileNameListPreK51::read(&mut Cursor::new(chunk))?;
         Ok(())
     }
+
+    #[test]
+    fn test_fntable_prek51_write() -> Result<(), Error> {
+        for i in 0..4 {
+            let file = File::open(format!(
+                "tests/data/Objects/Kontakt/0x3D-FNTablePreK51/FNTablePreK51-00{i}.kon"
+            ))?;
+            let chunk = Chunk::read(file)?;
+            let table = FileNameListPreK51::try_from(&chunk)?;
+            assert_eq!(table.to_chunk()?.data, chunk.data);
+        }
+        Ok(())
+    }
 }
@@ -3,8 +3,13 @@ use std::collections::HashMap;
 use time::OffsetDateTime;
 
 use crate::{
-    kontakt::{chunk::Chunk, objects::BFileName, KontaktError},
+    kontakt::{
+        chunk::Chunk,
+        objects::{BFileName, FileNameListPreK51},
+        KontaktError,
+    },
     read_bytes::ReadBytesExt,
+    write_bytes::WriteBytesExt,
     Error,
 };
 
@@ -13,16 +18,21 @@ const CHUNK_ID: u16 = 0x4B;
 /// A table representing external files of different kinds, used in Kontakt 5.1+.
 /// Kontakt: FNTableImpl
 /// LibKIO: BFileName
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FNTableImpl {
     /// List of resources and paths (nkr, search paths)
-    pub special_filetable: HashMap<u32, String>,
+    pub special_filetable: HashMap<u32, BFileName>,
     /// List of samples (wav, ncw)
-    pub sample_filetable: HashMap<u32, String>,
+    pub sample_filetable: HashMap<u32, BFileName>,
     /// List of sample timestamps
-    pub sample_timestamp_table: HashMap<u32, time::Date>,
+    pub sample_timestamp_table: HashMap<u32, OffsetDateTime>,
     /// List of instruments (nki) and internal files (ir samples)
-    pub other_filetable: HashMap<u32, String>,
+    pub other_filetable: HashMap<u32, BFileName>,
+    /// Found per sample, always 0
+    pub u_a: HashMap<u32, u32>,
+    /// Found per other file, 1 for the first file and 0 otherwise
+    pub u_b: HashMap<u32, i16>,
 }
 
 impl std::convert::TryFrom<&Chunk> for FNTableImpl {
@@ -50,35 +60,40 @@ impl FNTableImpl {
         let file_count = reader.read_u32_le()?;
         let mut special_filetable = HashMap::new();
         for i in 0..file_count {
-            special_filetable.insert(i, BFileName::read(&mut reader)?.join("/"));
+            special_filetable.insert(i, BFileName::read(&mut reader)?);
         }
 
         // sample filetable
         let file_count = reader.read_u32_le()?;
         let mut sample_filetable = HashMap::new();
         for i in 0..file_count {
-            sample_filetable.insert(i, BFileName::read(&mut reader)?.join("/"));
+            sample_filetable.insert(i, BFileName::read(&mut reader)?);
         }
 
         // sample timestamps
         let mut sample_timestamp_table = HashMap::new();
         for i in 0..file_count {
             let unix_timestamp = reader.read_u64_le()? as i64;
-            let datetime = OffsetDateTime::from_unix_timestamp(unix_timestamp).unwrap();
-            let timestamp: time::Date = datetime.date();
+            let timestamp = OffsetDateTime::from_unix_timestamp(unix_timestamp)
+                .map_err(|_| Error::Static("Invalid sample timestamp"))?;
             sample_timestamp_table.insert(i, timestamp);
         }
 
-        // offsets?
-        for _ in 0..file_count {
-            let _a = reader.read_u32_le()?;
+        let mut u_a = HashMap::new();
+        for i in 0..file_count {
+            u_a.insert(i, reader.read_u32_le()?);
         }
 
         // other filetable
         let file_count = reader.read_u32_le()?;
         let mut other_filetable = HashMap::new();
         for i in 0..file_count {
-            other_filetable.insert(i, BFileName::read(&mut reader)?.join("/"));
+            other_filetable.insert(i, BFileName::read(&mut reader)?);
+        }
+
+        let mut u_b = HashMap::new();
+        for i in 0..file_count {
+            u_b.insert(i, reader.read_i16_le()?);
         }
 
         Ok(Self {
@@ -86,6 +101,96 @@ impl FNTableImpl {
             sample_filetable,
             other_filetable,
             sample_timestamp_table,
+            u_a,
+            u_b,
+        })
+    }
+
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_u16_le(2)?;
+
+        write_filetable(&mut writer, &self.special_filetable)?;
+
+        let file_count = write_filetable(&mut writer, &self.sample_filetable)?;
+        for i in 0..file_count {
+            let timestamp = self
+                .sample_timestamp_table
+                .get(&i)
+                .map(|t| t.unix_timestamp())
+                .unwrap_or_default();
+            writer.write_u64_le(timestamp as u64)?;
+        }
+        for i in 0..file_count {
+            writer.write_u32_le(self.u_a.get(&i).copied().unwrap_or_default())?;
+        }
+
+        let file_count = write_filetable(&mut writer, &self.other_filetable)?;
+        for i in 0..file_count {
+            writer.write_i16_le(self.u_b.get(&i).copied().unwrap_or_default())?;
+        }
+
+        Ok(())
+    }
+
+    /// The table as a [`Chunk`], for writing it back into a preset.
+    pub fn to_chunk(&self) -> Result<Chunk, Error> {
+        let mut data = Vec::new();
+        self.write(&mut data)?;
+        Ok(Chunk { id: CHUNK_ID, data })
+    }
+}
+
+/// Writes the file count and every file of a table, ordered by index.
+pub(crate) fn write_filetable<W: WriteBytesExt>(
+    mut writer: W,
+    table: &HashMap<u32, BFileName>,
+) -> Result<u32, Error> {
+    let file_count = table.len() as u32;
+    writer.write_u32_le(file_count)?;
+    for i in 0..file_count {
+        let path = table
+            .get(&i)
+            .ok_or(Error::Static("Filetable indices are not contiguous"))?;
+        path.write(&mut writer)?;
+    }
+    Ok(file_count)
+}
+
+impl std::convert::TryFrom<FileNameListPreK51> for FNTableImpl {
+    type Error = Error;
+
+    fn try_from(list: FileNameListPreK51) -> Result<Self, Self::Error> {
+        Ok(Self {
+            special_filetable: list.special_filetable,
+            u_a: list.sample_filetable.keys().map(|i| (*i, 0)).collect(),
+            u_b: list
+                .u_a
+                .iter()
+                .map(|(i, v)| Ok((*i, i16::try_from(*v)?)))
+                .collect::<Result<_, std::num::TryFromIntError>>()
+                .map_err(|_| Error::Static("FileNameListPreK51 value does not fit FNTableImpl"))?,
+            sample_filetable: list.sample_filetable,
+            sample_timestamp_table: list.sample_timestamp_table,
+            other_filetable: list.other_filetable,
+        })
+    }
+}
+
+impl std::convert::TryFrom<FNTableImpl> for FileNameListPreK51 {
+    type Error = Error;
+
+    fn try_from(table: FNTableImpl) -> Result<Self, Self::Error> {
+        Ok(Self {
+            special_filetable: table.special_filetable,
+            sample_filetable: table.sample_filetable,
+            sample_timestamp_table: table.sample_timestamp_table,
+            other_filetable: table.other_filetable,
+            u_a: table
+                .u_b
+                .iter()
+                .map(|(i, v)| Ok((*i, u32::try_from(*v)?)))
+                .collect::<Result<_, std::num::TryFromIntError>>()
+                .map_err(|_| Error::Static("FNTableImpl value does not fit FileNameListPreK51"))?,
         })
     }
 }
@@ -140,4 +245,34 @@ mod tests {
         let _table = FNTableImpl::read(file)?;
         Ok(())
     }
+
+    #[test]
+    fn test_fntable_write() -> Result<(), Error> {
+        for i in 0..8 {
+            let file = File::open(format!(
+                "tests/data/Objects/Kontakt/0x4B-FNTableImpl/FNTableImpl-00{i}.kon"
+            ))?;
+            let chunk = Chunk::read(file)?;
+            let table = FNTableImpl::try_from(&chunk)?;
+            assert_eq!(table.to_chunk()?.data, chunk.data);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fntable_pre_k51_conversion() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x3D-FNTablePreK51/FNTablePreK51-003.kon")?;
+        let chunk = Chunk::read(file)?;
+        let list = FileNameListPreK51::try_from(&chunk)?;
+
+        let table = FNTableImpl::try_from(list.clone())?;
+        let list = FileNameListPreK51::try_from(table)?;
+        assert_eq!(list.to_chunk()?.data, chunk.data);
+
+        let mut list = list;
+        list.u_a.insert(0, 70000);
+        assert!(FNTableImpl::try_from(list).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,89 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x0B;
+
+/// # Glide
+///
+/// Portamento between successive notes.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x0B
+/// - Versions:       0x50
+/// - Kontakt 7:      BParGlide
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Glide(pub StructuredObject);
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlideParams {
+    /// Found 1
+    pub mode: i32,
+    /// Glide time in ms.
+    pub time: f32,
+    /// Found -1.0
+    pub u_a: f32,
+}
+
+impl Glide {
+    pub fn params(&self) -> Result<GlideParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x50 => Ok(GlideParams {
+                mode: reader.read_i32_le()?,
+                time: reader.read_f32_le()?,
+                u_a: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported Glide version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for Glide {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_glide_v50() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x0B-BParGlide/BParGlide-000.kon")?;
+        let glide = Glide::try_from(&Chunk::read(file)?)?;
+        assert_eq!(glide.0.version, 0x50);
+
+        let params = glide.params()?;
+        assert_eq!(params.mode, 1);
+        assert!((params.time - 14.73).abs() < 0.01);
+        assert_eq!(params.u_a, -1.0);
+        Ok(())
+    }
+}
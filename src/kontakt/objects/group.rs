@@ -3,19 +3,30 @@
 use std::io::Cursor;
 
 use crate::{
-    kontakt::{objects::start_criteria_list::StartCriteriaList, StructuredObject},
+    kontakt::{
+        objects::{
+            start_criteria_list::StartCriteriaList, BParSrcMode, BParamArrayBParFX8,
+            ExternalModArray32, InternalModArray16,
+        },
+        Chunk, KontaktError, StructuredObject,
+    },
     read_bytes::ReadBytesExt,
-    Error,
+    write_bytes::WriteBytesExt,
+    Error, NIFileError,
 };
 
+const CHUNK_ID: u16 = 0x04;
+
 /// Type:           Chunk
 /// SerType:        0x04
 /// Kontakt 7:      BGroup?
 /// KontaktIO:      K4PL\_Group
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Group(pub StructuredObject);
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupParams {
     pub name: String,
     pub volume: f32,
@@ -43,6 +54,29 @@ pub struct GroupParams {
     //     BParGroupDynamics 0x4A
 }
 
+impl GroupParams {
+    /// Writes the public params. `start_criteria` is stored as a child and
+    /// is not written, see [`Group::set_params`].
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_widestring_utf16(&self.name)?;
+        writer.write_f32_le(self.volume)?;
+        writer.write_f32_le(self.pan)?;
+        writer.write_f32_le(self.tune)?;
+        writer.write_bool(self.key_tracking)?;
+        writer.write_bool(self.reverse)?;
+        writer.write_bool(self.release_trigger)?;
+        writer.write_bool(self.release_trigger_note_monophonic)?;
+        writer.write_i32_le(self.rls_trig_counter)?;
+        writer.write_i16_le(self.midi_channel)?;
+        writer.write_i32_le(self.voice_group_index)?;
+        writer.write_i32_le(self.fx_idx_amp_split_point)?;
+        writer.write_bool(self.muted)?;
+        writer.write_bool(self.soloed)?;
+        writer.write_i32_le(self.interp_quality)?;
+        Ok(())
+    }
+}
+
 impl Group {
     pub fn params(&self) -> Result<GroupParams, Error> {
         let mut reader = Cursor::new(&self.0.public_data);
@@ -66,4 +100,143 @@ impl Group {
             start_criteria: (&self.0.children[2]).try_into()?,
         })
     }
+
+    /// Replaces the params, keeping any public data stored after them.
+    /// Changes to `start_criteria` are rejected, the list is not written yet.
+    pub fn set_params(&mut self, params: &GroupParams) -> Result<(), Error> {
+        let current_params = self.params()?;
+        if current_params.start_criteria != params.start_criteria {
+            return Err(Error::Static(
+                "Changing group start criteria is not supported",
+            ));
+        }
+
+        let mut current = Vec::new();
+        current_params.write(&mut current)?;
+        let remainder = self.0.public_data[current.len()..].to_vec();
+
+        let mut public_data = Vec::new();
+        params.write(&mut public_data)?;
+        public_data.extend(remainder);
+        self.0.public_data = public_data;
+        Ok(())
+    }
+
+    /// Internal modulation assignments (envelopes, LFOs, ...).
+    pub fn internal_mods(&self) -> Result<Option<InternalModArray16>, Error> {
+        self.0
+            .find_first(0x3B)
+            .map(InternalModArray16::try_from)
+            .transpose()
+    }
+
+    /// External modulation assignments (velocity, pitch bend, midi cc, ...).
+    pub fn external_mods(&self) -> Result<Option<ExternalModArray32>, Error> {
+        self.0
+            .find_first(0x3C)
+            .map(ExternalModArray32::try_from)
+            .transpose()
+    }
+
+    /// Group insert effects, stored in the private data.
+    pub fn fx(&self) -> Result<BParamArrayBParFX8, Error> {
+        let mut reader = self.fx_reader()?;
+        BParamArrayBParFX8::read(&mut reader, 8)
+    }
+
+    /// Source module settings, stored in the private data after the group
+    /// insert effects.
+    pub fn src_mode(&self) -> Result<BParSrcMode, Error> {
+        let mut reader = self.fx_reader()?;
+        BParamArrayBParFX8::read(&mut reader, 8)?;
+        reader.read_bool()?;
+
+        BParSrcMode::read(&mut reader)
+    }
+
+    /// Private data, positioned at the group insert effects.
+    fn fx_reader(&self) -> Result<Cursor<&Vec<u8>>, Error> {
+        if !(0x90..=0x95).contains(&self.0.version) {
+            return Err(NIFileError::Generic(format!(
+                "Unsupported Group version: 0x{:X}",
+                self.0.version
+            )));
+        }
+
+        let mut reader = Cursor::new(&self.0.private_data);
+
+        // 136 sized parameter blocks, followed by 20 bytes of unknown
+        // settings and an i32 that is -1 in all known groups (v90 - v95)
+        for _ in 0..136 {
+            let len = reader.read_u32_le()?;
+            reader.read_bytes(len as usize)?;
+        }
+        reader.read_bytes(20)?;
+        if reader.read_i32_le()? != -1 {
+            return Err(Error::Static(
+                "Unexpected group private data before insert effects",
+            ));
+        }
+
+        Ok(reader)
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for Group {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::Cursor};
+
+    use crate::{
+        kontakt::{objects::SourceMode, KontaktChunks},
+        Error, NIFile,
+    };
+
+    #[test]
+    fn test_group_src_mode() -> Result<(), Error> {
+        let file = NIFile::read(File::open(
+            "tests/data/Containers/NKS/KontaktV42/KontaktV42-000.nki",
+        )?)?;
+        let chunks = KontaktChunks::read(Cursor::new(file.inner_preset()?))?;
+        let program = chunks.program().unwrap()?;
+        let groups = program.group_list().unwrap()?.groups;
+        assert_eq!(groups[0].0.version, 0x90);
+
+        let src_mode = groups[0].src_mode()?;
+        assert_eq!(src_mode.version, 0x100);
+        assert_eq!(src_mode.params.mode, SourceMode::DFD);
+        assert_eq!(src_mode.params.speed, 1.0);
+
+        assert_eq!(groups[1].src_mode()?.params.mode, SourceMode::Sampler);
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_src_mode_unsupported_version() -> Result<(), Error> {
+        let file = NIFile::read(File::open(
+            "tests/data/Containers/NKS/KontaktV42/KontaktV42-000.nki",
+        )?)?;
+        let chunks = KontaktChunks::read(Cursor::new(file.inner_preset()?))?;
+        let program = chunks.program().unwrap()?;
+        let mut group = program.group_list().unwrap()?.groups.remove(0);
+
+        group.0.version = 0x80;
+        assert!(group.src_mode().is_err());
+        assert!(group.fx().is_err());
+        Ok(())
+    }
 }
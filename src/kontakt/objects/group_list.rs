@@ -3,6 +3,7 @@
 use crate::{
     kontakt::{Chunk, KontaktError, StructuredObject},
     read_bytes::ReadBytesExt,
+    write_bytes::WriteBytesExt,
     Error,
 };
 
@@ -15,6 +16,7 @@ pub const KONTAKT_GROUPLIST_ID: u16 = 0x33;
 /// Kontakt 7:      BProgram::readGroups()
 /// KontaktIO:      GroupList
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupList {
     pub groups: Vec<Group>,
 }
@@ -32,6 +34,14 @@ impl GroupList {
 
         Ok(Self { groups })
     }
+
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_u32_le(self.groups.len() as u32)?;
+        for group in &self.groups {
+            group.0.write(&mut writer)?;
+        }
+        Ok(())
+    }
 }
 
 impl std::convert::TryFrom<&Chunk> for GroupList {
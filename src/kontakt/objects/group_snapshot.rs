@@ -0,0 +1,36 @@
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    Error,
+};
+
+const CHUNK_ID: u16 = 0x50;
+
+/// # BGroupSnapshot
+///
+/// The state of a single group stored in a [`BSnapshot`](super::BSnapshot).
+/// No known file contains one, so its data is not decoded.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x50
+/// - Versions:       ?
+/// - Kontakt 7:      BGroupSnapshot
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BGroupSnapshot(pub StructuredObject);
+
+impl std::convert::TryFrom<&Chunk> for BGroupSnapshot {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
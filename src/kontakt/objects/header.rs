@@ -13,6 +13,7 @@ use time::OffsetDateTime;
 use crate::{nks::error::NKSError, read_bytes::ReadBytesExt};
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BPatchHeader {
     BPatchHeaderV1(BPatchHeaderV1),
     BPatchHeaderV2(BPatchHeaderV2),
@@ -32,6 +33,7 @@ impl BPatchHeader {
 
 /// The header of a Kontakt42 NKS File.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BPatchHeaderV42 {
     pub patch_type: PatchType,
     /// Patch version (often higher than the Kontakt version that created it)
@@ -59,6 +61,7 @@ pub struct BPatchHeaderV42 {
     /// Unknown bit flags. Known values: 0, 32, 36, 37, 44
     pub flags: u32,
     /// MD5 checksum of the decompressed chunk data
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub md5_checksum: Vec<u8>,
     /// The final part (patch level) of the authoring app version number.
     /// For example, for Kontakt 5.0.2.5641, the svn revision is 5641.
@@ -71,6 +74,7 @@ pub struct BPatchHeaderV42 {
 
 /// The header of a Kontakt2 NKS File.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BPatchHeaderV2 {
     pub patch_type: PatchType,
     /// Patch version (often higher than the Kontakt version that created it)
@@ -102,6 +106,7 @@ pub struct BPatchHeaderV2 {
 
 /// The header of a Kontakt1 NKS File.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BPatchHeaderV1 {
     pub u_version: u16,
     pub u_a: u32,
@@ -229,6 +234,19 @@ impl BPatchHeaderV2 {
 }
 
 impl BPatchHeaderV42 {
+    /// Offsets in a stored header, starting at the header version.
+    const CRC32_OFFSET: usize = 174;
+    const DECOMPRESSED_LENGTH_OFFSET: usize = 178;
+
+    /// Updates the checksum and length of a stored header, starting at the
+    /// header version. The md5 checksum is kept, it is not known what data
+    /// it is calculated from, so it is stale after the preset changes.
+    pub(crate) fn update_stored(data: &mut [u8], crc32: u32, decompressed_length: u32) {
+        data[Self::CRC32_OFFSET..Self::CRC32_OFFSET + 4].copy_from_slice(&crc32.to_le_bytes());
+        data[Self::DECOMPRESSED_LENGTH_OFFSET..Self::DECOMPRESSED_LENGTH_OFFSET + 4]
+            .copy_from_slice(&decompressed_length.to_le_bytes());
+    }
+
     pub fn read_le<R: ReadBytesExt>(mut reader: R) -> Result<Self, NKSError> {
         let data = reader.read_bytes(212)?; // 222 - 10
         let mut reader = Cursor::new(data);
@@ -336,6 +354,7 @@ impl BPatchHeaderV42 {
 }
 
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NKIAppVersion {
     pub major: u8,
     pub minor_1: u8,
@@ -363,6 +382,7 @@ impl std::fmt::Display for NKIAppVersion {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PatchType {
     NKM,
     NKI,
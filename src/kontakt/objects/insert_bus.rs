@@ -6,6 +6,8 @@ use crate::{
     Error,
 };
 
+use super::BParamArrayBParFX8;
+
 const CHUNK_ID: u16 = 0x45;
 
 /// # InsertBus
@@ -20,14 +22,16 @@ const CHUNK_ID: u16 = 0x45;
 /// - KontaktIO:      ?
 ///
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsertBus(pub StructuredObject);
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsertBusParams {
-    name: String,
-    pan: f32,
-    volume: f32,
-    output: i32,
+    pub name: String,
+    pub pan: f32,
+    pub volume: f32,
+    pub output: i32,
 }
 
 impl InsertBus {
@@ -41,6 +45,14 @@ impl InsertBus {
             output: reader.read_i32_le()?,
         })
     }
+
+    /// The effect chain of the bus.
+    pub fn fx(&self) -> Result<Option<BParamArrayBParFX8>, Error> {
+        self.0
+            .find_first(0x3A)
+            .map(BParamArrayBParFX8::try_from)
+            .transpose()
+    }
 }
 
 impl std::convert::TryFrom<&Chunk> for InsertBus {
@@ -60,27 +72,24 @@ impl std::convert::TryFrom<&Chunk> for InsertBus {
 
 #[cfg(test)]
 mod tests {
-    // use super::*;
-    // use crate::Error;
-    // use std::fs::File;
-    //
-    // #[test]
-    // fn test_bank() -> Result<(), Error> {
-    //     let chunk = Chunk::read(File::open(
-    //         "tests/data/Objects/Kontakt/0x29-ProgramContainer/ProgramContainerV51-000.kon",
-    //     )?)?;
-    //     let pc = InsertBus::try_from(&chunk)?;
-    //
-    //     assert_eq!(pc.0.version, 0x51);
-    //     assert_eq!(pc.0.children.len(), 3);
-    //     assert_eq!(pc.0.children[0].id, 0x2B); // VoiceGroup
-    //     assert_eq!(pc.0.children[1].id, 0x47); // SaveSettings
-    //     assert_eq!(pc.0.children[2].id, 0x36); // ProgramList
-    //
-    //     pc.params()?;
-    //     pc.voice_group()?;
-    //     pc.program_list()?;
-    //     // println!("{:X}", pc.0.children[2].id);
-    //     Ok(())
-    // }
+    use super::*;
+    use crate::Error;
+    use std::fs::File;
+
+    #[test]
+    fn test_insert_bus() -> Result<(), Error> {
+        let chunk = Chunk::read(File::open(
+            "tests/data/Objects/Kontakt/0x45-BInsertBus/BInsertBus-000.kon",
+        )?)?;
+        let bus = InsertBus::try_from(&chunk)?;
+        assert_eq!(bus.0.version, 0x11);
+
+        let params = bus.params()?;
+        assert_eq!(params.name, "Bus 16");
+        assert_eq!(params.output, -1);
+
+        let fx = bus.fx()?.unwrap();
+        assert_eq!(fx.len(), 0);
+        Ok(())
+    }
 }
@@ -1,24 +1,91 @@
+use std::io::Cursor;
+
 use crate::{
     kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
-    Error,
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
 };
 
+use super::{Envelope, Glide, ModBaseParams, LFO};
+
 const CHUNK_ID: u16 = 0x0D;
 
 /// # InternalMod
 ///
-/// ?
+/// A modulation assignment driven by an internal source. The source object
+/// (envelope or LFO) is the first child.
 ///
 /// Type:           Chunk<StructuredObject>
 /// SerType:        0x0D
-/// Versions:       0x81
+/// Versions:       0x80, 0x81
 /// Kontakt 7:      BParInternalMod
 /// KontaktIO:      ?
 ///
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InternalMod(pub StructuredObject);
 
-impl InternalMod {}
+/// Field names follow the `<K2_IntMod>` element of Kontakt 2 XML presets.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InternalModParams {
+    pub base: ModBaseParams,
+    pub routers_open: bool,
+    pub bypass: bool,
+    pub retrigger: bool,
+    pub simulate_k2_dfd_vol_modulation: bool,
+    /// Found 0 (envelope), 3 to 5 (LFO, glide), 0x13
+    pub class_id: i32,
+    /// e.g. "ENV_AHDSR", "LFO_SINE", "<none>"
+    pub source_name: String,
+    /// Found 1 (LFO), 2 (envelope), 5 (glide)
+    pub source_type: u32,
+}
+
+/// The object referenced by an [`InternalMod`].
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModSource {
+    Envelope(Envelope),
+    LFO(LFO),
+    Glide(Glide),
+    Unsupported(u16),
+}
+
+impl InternalMod {
+    pub fn params(&self) -> Result<InternalModParams, Error> {
+        let mut reader = Cursor::new(&self.0.private_data);
+
+        match self.0.version {
+            0x80 | 0x81 => Ok(InternalModParams {
+                base: ModBaseParams::read(&mut reader)?,
+                routers_open: reader.read_bool()?,
+                bypass: reader.read_bool()?,
+                retrigger: reader.read_bool()?,
+                simulate_k2_dfd_vol_modulation: reader.read_bool()?,
+                class_id: reader.read_i32_le()?,
+                source_name: reader.read_sized_utf8()?,
+                source_type: reader.read_u32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported InternalMod version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+
+    pub fn source(&self) -> Result<Option<ModSource>, Error> {
+        Ok(match self.0.children.first() {
+            Some(chunk) => Some(match chunk.id {
+                0x07 => ModSource::Envelope(chunk.try_into()?),
+                0x08 => ModSource::LFO(chunk.try_into()?),
+                0x0B => ModSource::Glide(chunk.try_into()?),
+                id => ModSource::Unsupported(id),
+            }),
+            None => None,
+        })
+    }
+}
 
 impl std::convert::TryFrom<&Chunk> for InternalMod {
     type Error = Error;
@@ -44,11 +111,37 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_bparam_array_v10() -> Result<(), Error> {
+    fn test_internal_mod_env() -> Result<(), Error> {
         let file = File::open("tests/data/Objects/Kontakt/0x0D-InternalMod/InternalMod-000.kon")?;
         let internalmod = InternalMod::try_from(&Chunk::read(file)?)?;
         assert_eq!(internalmod.0.version, 0x80);
         assert_eq!(internalmod.0.children.len(), 1);
+
+        let params = internalmod.params()?;
+        assert_eq!(params.base.targets[0].target, "volume");
+        assert_eq!(params.base.targets[0].intensity, 1.0);
+        assert_eq!(params.source_name, "ENV");
+        assert!(matches!(
+            internalmod.source()?,
+            Some(ModSource::Envelope(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_internal_mod_lfo() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x0D-InternalMod/InternalMod-001.kon")?;
+        let internalmod = InternalMod::try_from(&Chunk::read(file)?)?;
+
+        let params = internalmod.params()?;
+        assert_eq!(params.base.targets[0].target, "pitch");
+        assert_eq!(params.base.targets[0].name, "LFO_SINE_PITCH");
+        assert_eq!(params.source_name, "LFO_SINE");
+
+        let Some(ModSource::LFO(lfo)) = internalmod.source()? else {
+            panic!("Expected LFO source");
+        };
+        assert_eq!(lfo.params()?.waveform, 0);
         Ok(())
     }
 }
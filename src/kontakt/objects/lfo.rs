@@ -0,0 +1,116 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x08;
+
+/// # LFO
+///
+/// Low frequency oscillator. Usually the child of an [`InternalMod`](super::InternalMod).
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x08
+/// - Versions:       0x70
+/// - Kontakt 7:      BParLFO
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LFO(pub StructuredObject);
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LFOParams {
+    /// 0 = sine
+    pub waveform: i32,
+    /// 0 = free running, otherwise a tempo-synced note value?
+    pub sync: i32,
+    /// Frequency in Hz.
+    pub rate: f32,
+    /// Found 0.5
+    pub pulse_width: f32,
+    pub fade_in: f32,
+    pub retrigger: bool,
+    /// Found -1.0
+    pub u_a: f32,
+    /// Found -1.0
+    pub u_b: f32,
+}
+
+impl LFO {
+    pub fn params(&self) -> Result<LFOParams, Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+
+        match self.0.version {
+            0x70 => Ok(LFOParams {
+                waveform: reader.read_i32_le()?,
+                sync: reader.read_i32_le()?,
+                rate: reader.read_f32_le()?,
+                pulse_width: reader.read_f32_le()?,
+                fade_in: reader.read_f32_le()?,
+                retrigger: reader.read_bool()?,
+                u_a: reader.read_f32_le()?,
+                u_b: reader.read_f32_le()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported LFO version: 0x{:X}",
+                self.0.version
+            ))),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for LFO {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_lfo_v70() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x08-BParLFO/BParLFO-000.kon")?;
+        let lfo = LFO::try_from(&Chunk::read(file)?)?;
+        assert_eq!(lfo.0.version, 0x70);
+
+        let params = lfo.params()?;
+        assert_eq!(params.waveform, 0);
+        assert_eq!(params.sync, 0);
+        assert!((params.rate - 4.99).abs() < 0.01);
+        assert_eq!(params.pulse_width, 0.5);
+        assert!(params.retrigger);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lfo_rate() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x08-BParLFO/BParLFO-001.kon")?;
+        let lfo = LFO::try_from(&Chunk::read(file)?)?;
+
+        let params = lfo.params()?;
+        assert_eq!(params.waveform, 0);
+        assert!((params.rate - 12.09).abs() < 0.01);
+        assert_eq!(params.u_a, -1.0);
+        Ok(())
+    }
+}
@@ -11,6 +11,7 @@ use crate::{
 /// Kontakt 7:      array<BLoop>
 /// KontaktIO:      LoopArray
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LoopArray {
     pub items: Vec<Loop>,
 }
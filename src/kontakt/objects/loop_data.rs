@@ -1,13 +1,22 @@
-use crate::{read_bytes::ReadBytesExt, Error};
+use std::io::Cursor;
 
-// TODO: change to Chunk reader
+use crate::{
+    kontakt::{Chunk, KontaktError, StructuredObject},
+    read_bytes::ReadBytesExt,
+    Error,
+};
+
+const CHUNK_ID: u16 = 0x05;
 
 /// Type:           Chunk
 /// SerType:        0x05
 /// Kontakt 7:      BLoop
 /// KontaktIO:      K4PL_Loop
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Loop {
+    /// 0: Off, 1: Until End, 2: Until Release (assumed, only found in
+    /// Kontakt 2 XML presets)
     pub mode: i32,
     pub loop_start: i32,
     pub loop_length: i32,
@@ -30,3 +39,19 @@ impl Loop {
         })
     }
 }
+
+impl std::convert::TryFrom<&Chunk> for Loop {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        let so = StructuredObject::try_from(chunk)?;
+        Self::read(Cursor::new(&so.public_data))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{nks::error::NKSError, read_bytes::ReadBytesExt};
+use crate::{nis::SoundInfo, nks::error::NKSError, read_bytes::ReadBytesExt};
 
 /// The header of the metadata (footer) of a Kontakt42 preset.
 ///
@@ -6,9 +6,10 @@ use crate::{nks::error::NKSError, read_bytes::ReadBytesExt};
 /// |--------|--------|----------|-----------------------------|------------|------------------------------------------|
 /// | 0x00   | 0x04   | uint32_t | metaMagic                   | 0xAEE10EB0 | BE: 0xb00ee1ae                               |
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BPatchMetaInfoHeader {
-    /// An XML SoundInfoItem document.
-    soundinfo: String,
+    /// An XML SoundInfo document.
+    pub soundinfo: String,
 }
 
 impl BPatchMetaInfoHeader {
@@ -34,13 +35,18 @@ impl BPatchMetaInfoHeader {
         let _u_c = reader.read_u16_le()?;
         // assert_eq!(reader.read_u16_le()?, 12);
 
-        // let soundinfo_length = reader.read_u32_le()? as usize;
-        // let soundinfo = reader.read_bytes(soundinfo_length)?;
-        // let soundinfo = String::from_utf8(soundinfo).unwrap();
-        let soundinfo = String::new();
+        let soundinfo_length = reader.read_u32_le()? as usize;
+        let soundinfo = reader.read_bytes(soundinfo_length)?;
+        let soundinfo = String::from_utf8_lossy(&soundinfo).into_owned();
 
         Ok(Self { soundinfo })
     }
+
+    /// Parses the SoundInfo document. The raw document is kept in
+    /// `soundinfo`, so a malformed footer does not fail the container.
+    pub fn sound_info(&self) -> Result<SoundInfo, NKSError> {
+        SoundInfo::from_xml(&self.soundinfo).map_err(|e| NKSError::SoundInfo(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -50,9 +56,31 @@ mod tests {
 
     #[test]
     fn test_kontakt_1() -> Result<(), NKSError> {
-        BPatchMetaInfoHeader::read(File::open(
-            "tests/data/Objects/BPatchMetaInfoHeader/BPatchMetaInfoHeader-000",
+        let header = BPatchMetaInfoHeader::read(File::open(
+            "tests/data/Objects/Kontakt/BPatchMetaInfoHeader/BPatchMetaInfoHeader-000.kon",
         )?)?;
+
+        let info = header.sound_info()?;
+        assert_eq!(info.version, "400");
+        assert_eq!(info.name, "From Another Room");
+        assert_eq!(info.author, "Jürgen Moßgraber");
+        assert!(info.bank_chain.is_empty());
+        assert_eq!(info.attributes.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed_soundinfo() -> Result<(), NKSError> {
+        let xml = b"<soundinfo version=\"400\"><name>";
+        let mut data = Vec::new();
+        data.extend(0xB00EE1AEu32.to_le_bytes());
+        data.extend([1, 1, 12, 0]);
+        data.extend((xml.len() as u32).to_le_bytes());
+        data.extend(xml);
+
+        let header = BPatchMetaInfoHeader::read(std::io::Cursor::new(data))?;
+        assert_eq!(header.soundinfo.as_bytes(), xml.as_slice());
+        assert!(header.sound_info().is_err());
         Ok(())
     }
 }
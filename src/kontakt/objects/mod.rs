@@ -1,23 +1,57 @@
+mod arp;
+mod automation;
 mod bank;
 mod bpar_script;
 mod bparam_array_ext_mod_32;
 mod bparam_array_fx_8;
 mod bparam_array_int_mod_16;
 mod bparfx;
+mod bparfx_ac_box;
+mod bparfx_cabinet;
+mod bparfx_cat;
+mod bparfx_chorus;
+mod bparfx_compressor;
+mod bparfx_cry_wah;
 mod bparfx_delay;
+mod bparfx_distortion;
+mod bparfx_dstortion;
+mod bparfx_filter;
+mod bparfx_gainer;
+mod bparfx_hot_solo;
+mod bparfx_limiter;
+mod bparfx_lofi;
+mod bparfx_phaser;
+mod bparfx_phasis;
+mod bparfx_plate_reverb;
 mod bparfx_rotator;
 mod bparfx_send_level;
+mod bparfx_skreamer;
+mod bparfx_ssl_bus_comp;
+mod bparfx_ssl_geq;
+mod bparfx_stereo_spread;
+mod dyx_morph_group;
+mod dyx_morph_map;
+mod effect;
+mod envelope;
+mod envelope_ahdsr;
+mod external_mod;
 mod filename;
 mod filename_list;
 mod filename_table;
+mod glide;
 mod group;
 mod group_list;
+mod group_snapshot;
 mod header;
 mod insert_bus;
 mod internal_mod;
+mod lfo;
 mod loop_array;
 mod loop_data;
 mod meta_info;
+mod mod_base;
+mod output_configuration;
+mod output_partition;
 mod private_raw_object;
 mod program;
 mod program_container;
@@ -26,6 +60,8 @@ mod program_list;
 mod quick_browse_data;
 mod save_settings;
 mod slot_list;
+mod snapshot;
+mod snapshot_meta_data;
 mod src_mode;
 mod start_criteria;
 mod start_criteria_list;
@@ -35,26 +71,60 @@ mod voice_limit;
 mod zone_data;
 mod zone_list;
 
+pub use arp::*;
+pub use automation::*;
 pub use bank::*;
 pub use bpar_script::*;
 pub use bparam_array_ext_mod_32::*;
 pub use bparam_array_fx_8::*;
 pub use bparam_array_int_mod_16::*;
 pub use bparfx::*;
+pub use bparfx_ac_box::*;
+pub use bparfx_cabinet::*;
+pub use bparfx_cat::*;
+pub use bparfx_chorus::*;
+pub use bparfx_compressor::*;
+pub use bparfx_cry_wah::*;
 pub use bparfx_delay::*;
+pub use bparfx_distortion::*;
+pub use bparfx_dstortion::*;
+pub use bparfx_filter::*;
+pub use bparfx_gainer::*;
+pub use bparfx_hot_solo::*;
+pub use bparfx_limiter::*;
+pub use bparfx_lofi::*;
+pub use bparfx_phaser::*;
+pub use bparfx_phasis::*;
+pub use bparfx_plate_reverb::*;
 pub use bparfx_rotator::*;
 pub use bparfx_send_level::*;
+pub use bparfx_skreamer::*;
+pub use bparfx_ssl_bus_comp::*;
+pub use bparfx_ssl_geq::*;
+pub use bparfx_stereo_spread::*;
+pub use dyx_morph_group::*;
+pub use dyx_morph_map::*;
+pub use effect::*;
+pub use envelope::*;
+pub use envelope_ahdsr::*;
+pub use external_mod::*;
 pub use filename::*;
 pub use filename_list::*;
 pub use filename_table::*;
+pub use glide::*;
 pub use group::*;
 pub use group_list::*;
+pub use group_snapshot::*;
 pub use header::*;
 pub use insert_bus::*;
 pub use internal_mod::*;
+pub use lfo::*;
 pub use loop_array::*;
 pub use loop_data::*;
 pub use meta_info::*;
+pub use mod_base::*;
+pub use output_configuration::*;
+pub use output_partition::*;
 pub use private_raw_object::*;
 pub use program::*;
 pub use program_container::*;
@@ -63,6 +133,9 @@ pub use program_list::*;
 pub use quick_browse_data::*;
 pub use save_settings::*;
 pub use slot_list::*;
+pub use snapshot::*;
+pub use snapshot_meta_data::*;
+pub use src_mode::*;
 pub use start_criteria::*;
 pub use start_criteria_list::*;
 pub use voice_group::*;
@@ -0,0 +1,131 @@
+use crate::{read_bytes::ReadBytesExt, Error, NIFileError};
+
+/// # BParModBase
+///
+/// Common header shared by all modulators. Stored at the start of the private
+/// data of [`InternalMod`](super::InternalMod) and [`ExternalMod`](super::ExternalMod).
+///
+/// - Type:           Data
+/// - SerType:        0x00
+/// - Kontakt 7:      BParModBase
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModBaseParams {
+    pub targets: Vec<ModTarget>,
+}
+
+/// A modulated parameter. Field names follow the `<Target>` element of
+/// Kontakt 2 XML presets.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModTarget {
+    /// Name of the modulated parameter, e.g. "volume", "pitch", "intensity".
+    pub target: String,
+    pub intensity: f32,
+    /// Found -1.
+    pub slot_idx: i16,
+    /// Found 0x10, 0x14.
+    pub flags: u8,
+    /// Lag. Found 0, 250.
+    pub smoothing_coef: u8,
+    pub invert: bool,
+    /// Display name, e.g. "ENV_AHDSR_VOLUME", "LFO_SINE_PITCH", "<none>".
+    pub name: String,
+    /// Index of the object that owns the parameter, e.g. the modulator of an
+    /// "intensity" target. Not stored for group level targets, see
+    /// [`GROUP_TARGETS`].
+    pub target_obj_idx: Option<u8>,
+    /// Whether the modulation shaper table is shown in the editor.
+    pub table_open: bool,
+    /// Only read from Kontakt 2 XML presets. Binary presets with a stored
+    /// table return an error.
+    pub shaper: Option<ModShaper>,
+}
+
+/// Targets that store no `target_obj_idx`.
+pub const GROUP_TARGETS: [&str; 4] = ["volume", "pan", "pitch", "playPos"];
+
+/// Modulation shaper curve, the `<ModTable>` element of Kontakt 2 XML
+/// presets.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModShaper {
+    pub active: bool,
+    /// Breakpoints of the 128 step table as (step, value), with values from
+    /// 0.0 to 1.0.
+    pub points: Vec<(u8, f32)>,
+}
+
+impl ModShaper {
+    /// The value at `step`, linearly interpolated between breakpoints.
+    pub fn value(&self, step: u8) -> f32 {
+        let next = self.points.iter().position(|(s, _)| *s >= step);
+        match next {
+            Some(0) => self.points[0].1,
+            Some(i) => {
+                let (s0, v0) = self.points[i - 1];
+                let (s1, v1) = self.points[i];
+                v0 + (v1 - v0) * (step - s0) as f32 / (s1 - s0) as f32
+            }
+            None => self
+                .points
+                .last()
+                .map(|(_, v)| *v)
+                .unwrap_or(step as f32 / 127.0),
+        }
+    }
+}
+
+impl ModBaseParams {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        let num_targets = reader.read_u32_le()?;
+        let mut targets = Vec::new();
+        for _ in 0..num_targets {
+            targets.push(ModTarget::read(&mut reader)?);
+        }
+        Ok(Self { targets })
+    }
+
+    pub fn target(&self) -> Option<&ModTarget> {
+        self.targets.first()
+    }
+}
+
+impl ModTarget {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        let target = reader.read_sized_utf8()?;
+        let intensity = reader.read_f32_le()?;
+        let slot_idx = reader.read_i16_le()?;
+        let flags = reader.read_u8()?;
+        let smoothing_coef = reader.read_u8()?;
+        let invert = reader.read_bool()?;
+        let name = reader.read_sized_utf8()?;
+        let target_obj_idx = match GROUP_TARGETS.contains(&target.as_str()) {
+            true => None,
+            false => Some(reader.read_u8()?),
+        };
+        let table_open = reader.read_bool()?;
+
+        // no known file stores a shaper table, so its layout is unknown
+        if reader.read_bool()? {
+            return Err(NIFileError::Generic(format!(
+                "Unsupported stored modulation shaper table for \"{target}\""
+            )));
+        }
+
+        Ok(Self {
+            target,
+            intensity,
+            slot_idx,
+            flags,
+            smoothing_coef,
+            invert,
+            name,
+            target_obj_idx,
+            table_open,
+            shaper: None,
+        })
+    }
+}
@@ -0,0 +1,134 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{error::KontaktError, Chunk},
+    read_bytes::ReadBytesExt,
+    Error,
+};
+
+use super::OutputPartition;
+
+const CHUNK_ID: u16 = 0x3E;
+
+/// # BOutputConfiguration
+///
+/// The output section of a multi: output and aux channels.
+///
+/// - Type:           Chunk<Data>
+/// - SerType:        0x3E
+/// - Versions:       0x00
+/// - Kontakt 7:      BOutputConfiguration
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BOutputConfiguration {
+    pub version: u16,
+    /// Assignment of each of the 128 output channels to a host output,
+    /// indexed by channel. Found the identity 0..127 only.
+    pub channel_assignment: Vec<i32>,
+    /// Found 127
+    pub u_b: i32,
+    /// Found 0
+    pub u_c: i32,
+    /// Found 0
+    pub u_d: i32,
+    /// Found 0
+    pub u_e: i32,
+    pub partitions: Vec<OutputPartition>,
+}
+
+impl BOutputConfiguration {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        if reader.read_bool()? {
+            return Err(KontaktError::UnexpectedStructured(CHUNK_ID).into());
+        }
+
+        let version = reader.read_u16_le()?;
+
+        let num_items = reader.read_u32_le()?;
+        let mut channel_assignment = Vec::new();
+        for _ in 0..num_items {
+            channel_assignment.push(reader.read_i32_le()?);
+        }
+
+        let u_b = reader.read_i32_le()?;
+        let u_c = reader.read_i32_le()?;
+        let u_d = reader.read_i32_le()?;
+        let u_e = reader.read_i32_le()?;
+
+        // partitions fill the rest of the data
+        let data = reader.read_all()?;
+        let mut reader = Cursor::new(&data);
+        let mut partitions = Vec::new();
+        while (reader.position() as usize) < data.len() {
+            partitions.push(OutputPartition::read(&mut reader)?);
+        }
+
+        Ok(Self {
+            version,
+            channel_assignment,
+            u_b,
+            u_c,
+            u_d,
+            u_e,
+            partitions,
+        })
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for BOutputConfiguration {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        let reader = Cursor::new(&chunk.data);
+        Self::read(reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_output_configuration() -> Result<(), Error> {
+        let file = File::open(
+            "tests/data/Objects/Kontakt/0x3E-BOutputConfiguration/BOutputConfiguration-000.kon",
+        )?;
+        let config = BOutputConfiguration::try_from(&Chunk::read(file)?)?;
+        assert_eq!(config.channel_assignment.len(), 128);
+        assert!(config
+            .channel_assignment
+            .iter()
+            .enumerate()
+            .all(|(i, c)| *c == i as i32));
+
+        let names: Vec<&str> = config.partitions.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "st. 1",
+                "st. 2",
+                "surr. 5.1",
+                "aux 1",
+                "aux 2",
+                "aux 3",
+                "aux 4"
+            ]
+        );
+        assert_eq!(config.partitions[0].num_channels, 2);
+        assert_eq!(config.partitions[6].aux_index, 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use std::io::Cursor;
+
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+const CHUNK_ID: u16 = 0x02;
+
+/// # OutputPartition
+///
+/// An output or aux channel of the output section.
+///
+/// - Type:           Chunk<Data>
+/// - SerType:        0x02
+/// - Versions:       0x70
+/// - Kontakt 7:      OutputPartition
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputPartition {
+    pub version: u16,
+    /// e.g. "st. 1", "surr. 5.1", "aux 1"
+    pub name: String,
+    /// 1 (mono), 2 (stereo), 6 (5.1 surround)
+    pub num_channels: u16,
+    /// Index of the aux channel, -1 for outputs.
+    pub aux_index: i16,
+    /// Found 1.0
+    pub u_b: f32,
+    /// Found 4 objects, v50
+    pub u_c: Vec<StructuredObject>,
+}
+
+impl OutputPartition {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        if reader.read_bool()? {
+            return Err(KontaktError::UnexpectedStructured(CHUNK_ID).into());
+        }
+
+        let version = reader.read_u16_le()?;
+        if version != 0x70 {
+            return Err(NIFileError::Generic(format!(
+                "Unsupported OutputPartition version: 0x{:x}",
+                version
+            )));
+        }
+
+        let name = reader.read_widestring_utf16()?;
+        let num_channels = reader.read_u16_le()?;
+        let aux_index = reader.read_i16_le()?;
+        let u_b = reader.read_f32_le()?;
+
+        let num_items = reader.read_u32_le()?;
+        let mut u_c = Vec::new();
+        for _ in 0..num_items {
+            u_c.push(StructuredObject::read(&mut reader)?);
+        }
+
+        Ok(Self {
+            version,
+            name,
+            num_channels,
+            aux_index,
+            u_b,
+            u_c,
+        })
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for OutputPartition {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        let reader = Cursor::new(&chunk.data);
+        Self::read(reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_output_partition_v70() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x02-OutputPartition/OutputPartition-000.kon")?;
+        let partition = OutputPartition::try_from(&Chunk::read(file)?)?;
+        assert_eq!(partition.version, 0x70);
+        assert_eq!(partition.name, "surr. 5.1");
+        assert_eq!(partition.num_channels, 6);
+        assert_eq!(partition.aux_index, -1);
+        assert_eq!(partition.u_c.len(), 4);
+        Ok(())
+    }
+}
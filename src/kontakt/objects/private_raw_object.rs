@@ -10,7 +10,10 @@ pub const KONTAKT_PRIVATE_RAW_OBJECT_ID: u16 = 0x35;
 /// Kontakt 7:      ?
 /// KontaktIO:      PrivateRawObject
 #[derive(Debug)]
-pub struct PrivateRawObject(Vec<u8>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrivateRawObject(
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))] Vec<u8>,
+);
 
 impl PrivateRawObject {
     pub fn data(&self) -> &Vec<u8> {
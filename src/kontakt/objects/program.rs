@@ -3,21 +3,31 @@ use std::io::Cursor;
 use crate::{
     kontakt::{chunk::Chunk, error::KontaktError, structured_object::StructuredObject},
     read_bytes::ReadBytesExt,
-    Error,
+    write_bytes::WriteBytesExt,
+    Error, NIFileError,
 };
 
-use super::zone_list::ZoneList;
+use super::{
+    zone_list::ZoneList, BDyxMorphGroup, BDyxMorphMap, BParScript, BParScriptParams,
+    BParamArrayBParFX8, BSnapshot, GroupList, InsertBus, VoiceGroups,
+};
 
 const CHUNK_ID: u16 = 0x28;
 
+/// Kontakt 4.2 programs (v80) store the script slots in the private data,
+/// after the private params.
+const V80_SCRIPTS_OFFSET: u64 = 65;
+
 /// SerType:        0x28
 /// Known Versions: 0x80 .. 0xAF
 /// Kontakt 7:      BProgram
 /// KontaktIO:      K4PL_Program
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Program(pub StructuredObject);
 
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgramPublicParams {
     pub name: String,
     pub num_bytes_samples_total: f64,
@@ -42,13 +52,19 @@ pub struct ProgramPublicParams {
     pub instrument_cat1: i16,
     pub instrument_cat2: i16,
     pub instrument_cat3: i16,
+    /// Index into the special filetable, -1 if unset. `None` for versions
+    /// before 0xA2 and versions with an unknown layout.
     pub resource_container_filename: Option<i32>,
+    /// Index into the other filetable, -1 if unset. `None` like
+    /// `resource_container_filename`.
     pub wallpaper_filename: Option<i32>,
+    /// Found 0, 0, 0 (vA8 - vAC) and 0, 0, 3, 0 (vAF)
+    pub u_a: Vec<i32>,
 }
 
 impl ProgramPublicParams {
     pub fn read<R: ReadBytesExt>(mut reader: R, version: u16) -> Result<Self, Error> {
-        Ok(Self {
+        let mut params = Self {
             name: reader.read_widestring_utf16()?,
             num_bytes_samples_total: reader.read_f64_le()?,
             transpose: reader.read_i8()?,
@@ -72,17 +88,82 @@ impl ProgramPublicParams {
             instrument_cat1: reader.read_i16_le()?,
             instrument_cat2: reader.read_i16_le()?,
             instrument_cat3: reader.read_i16_le()?,
-            resource_container_filename: {
-                match version {
-                    _ => None,
-                }
-            },
-            wallpaper_filename: {
-                match version {
-                    _ => None,
+            resource_container_filename: None,
+            wallpaper_filename: None,
+            u_a: Vec::new(),
+        };
+
+        if let Some(num_unknown) = Self::num_unknown(version) {
+            params.resource_container_filename = Some(reader.read_i32_le()?);
+            for _ in 0..num_unknown {
+                params.u_a.push(reader.read_i32_le()?);
+            }
+            params.wallpaper_filename = Some(reader.read_i32_le()?);
+        }
+
+        Ok(params)
+    }
+
+    /// Number of unknown values between the resource container and
+    /// wallpaper, for the versions known to store them.
+    fn num_unknown(version: u16) -> Option<usize> {
+        match version {
+            0xA2 | 0xA5 => Some(0),
+            0xA8 | 0xAB | 0xAC => Some(3),
+            0xAF => Some(4),
+            _ => None,
+        }
+    }
+
+    /// Writes the params in the layout of the given version.
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W, version: u16) -> Result<(), Error> {
+        writer.write_widestring_utf16(&self.name)?;
+        writer.write_f64_le(self.num_bytes_samples_total)?;
+        writer.write_i8(self.transpose)?;
+        writer.write_f32_le(self.volume)?;
+        writer.write_f32_le(self.pan)?;
+        writer.write_f32_le(self.tune)?;
+        writer.write_u8(self.low_velocity)?;
+        writer.write_u8(self.high_velocity)?;
+        writer.write_u8(self.low_key)?;
+        writer.write_u8(self.high_key)?;
+        writer.write_i16_le(self.default_key_switch)?;
+        writer.write_i32_le(self.dfd_channel_preload_size)?;
+        writer.write_i32_le(self.library_id)?;
+        writer.write_u32_le(self.fingerprint)?;
+        writer.write_u32_le(self.loading_flags)?;
+        writer.write_bool(self.group_solo)?;
+        writer.write_i32_le(self.cat_icon_idx)?;
+        writer.write_widestring_utf16(&self.instrument_credits)?;
+        writer.write_widestring_utf16(&self.instrument_author)?;
+        writer.write_widestring_utf16(&self.instrument_url)?;
+        writer.write_i16_le(self.instrument_cat1)?;
+        writer.write_i16_le(self.instrument_cat2)?;
+        writer.write_i16_le(self.instrument_cat3)?;
+
+        match (
+            Self::num_unknown(version),
+            self.resource_container_filename,
+            self.wallpaper_filename,
+        ) {
+            (Some(num_unknown), Some(resource_container), Some(wallpaper))
+                if self.u_a.len() == num_unknown =>
+            {
+                writer.write_i32_le(resource_container)?;
+                for value in &self.u_a {
+                    writer.write_i32_le(*value)?;
                 }
-            },
-        })
+                writer.write_i32_le(wallpaper)?;
+            }
+            (None, None, None) if self.u_a.is_empty() => {}
+            _ => {
+                return Err(NIFileError::Generic(format!(
+                    "Resource container and wallpaper do not match Program version 0x{:X}",
+                    version
+                )))
+            }
+        }
+        Ok(())
     }
 }
 
@@ -110,18 +191,238 @@ impl Program {
         // }
     }
 
+    /// Replaces the params, keeping any public data stored after them.
+    pub fn set_params(&mut self, params: &ProgramPublicParams) -> Result<(), Error> {
+        let mut reader = Cursor::new(&self.0.public_data);
+        ProgramPublicParams::read(&mut reader, self.0.version)?;
+        let remainder = self.0.public_data[reader.position() as usize..].to_vec();
+
+        let mut public_data = Vec::new();
+        params.write(&mut public_data, self.0.version)?;
+        public_data.extend(remainder);
+        self.0.public_data = public_data;
+        Ok(())
+    }
+
     pub fn zone_list(&self) -> Option<Result<ZoneList, Error>> {
         self.0
             .find_first(0x34)
             .map(|chunk| ZoneList::try_from(chunk))
     }
 
+    /// Program level effect chains, in the order they are stored.
+    pub fn fx_chains(&self) -> Result<Vec<BParamArrayBParFX8>, Error> {
+        self.0
+            .children
+            .iter()
+            .filter(|c| c.id == 0x3A)
+            .map(BParamArrayBParFX8::try_from)
+            .collect()
+    }
+
+    /// Instrument buses (Kontakt 5+).
+    pub fn insert_buses(&self) -> Result<Vec<InsertBus>, Error> {
+        self.0
+            .children
+            .iter()
+            .filter(|c| c.id == 0x45)
+            .map(InsertBus::try_from)
+            .collect()
+    }
+
+    /// Snapshots stored with the instrument (Kontakt 6+).
+    pub fn snapshots(&self) -> Result<Vec<BSnapshot>, Error> {
+        self.0
+            .children
+            .iter()
+            .filter(|c| c.id == 0x4F)
+            .map(BSnapshot::try_from)
+            .collect()
+    }
+
+    /// AET morph groups.
+    pub fn dyx_morph_groups(&self) -> Result<Vec<BDyxMorphGroup>, Error> {
+        self.0
+            .children
+            .iter()
+            .filter(|c| c.id == 0x26)
+            .map(BDyxMorphGroup::try_from)
+            .collect()
+    }
+
+    /// AET morph maps.
+    pub fn dyx_morph_maps(&self) -> Result<Vec<BDyxMorphMap>, Error> {
+        self.0
+            .children
+            .iter()
+            .filter(|c| c.id == 0x27)
+            .map(BDyxMorphMap::try_from)
+            .collect()
+    }
+
     pub fn children(&self) -> &Vec<Chunk> {
         &self.0.children
     }
 
-    // 0x32 VoiceGroups
-    // 0x33 GroupList
+    pub fn voice_groups(&self) -> Option<Result<VoiceGroups, Error>> {
+        self.0.find_first(0x32).map(VoiceGroups::try_from)
+    }
+
+    pub fn group_list(&self) -> Option<Result<GroupList, Error>> {
+        self.0.find_first(0x33).map(GroupList::try_from)
+    }
+
+    /// Replaces the group list chunk.
+    pub fn set_group_list(&mut self, group_list: &GroupList) -> Result<(), Error> {
+        let mut data = Vec::new();
+        group_list.write(&mut data)?;
+        self.set_child(0x33, data)
+    }
+
+    /// Replaces the zone list chunk.
+    pub fn set_zone_list(&mut self, zone_list: &ZoneList) -> Result<(), Error> {
+        let mut data = Vec::new();
+        zone_list.write(&mut data)?;
+        self.set_child(0x34, data)
+    }
+
+    fn set_child(&mut self, id: u16, data: Vec<u8>) -> Result<(), Error> {
+        self.0
+            .children
+            .iter_mut()
+            .find(|c| c.id == id)
+            .ok_or(Error::Static("Could not find Program child"))?
+            .data = data;
+        Ok(())
+    }
+
+    /// Script slots, in the order they are stored. All 5 slots are stored,
+    /// including empty ones, so the index is the slot number. Kontakt 4.2
+    /// programs (v80) store them in the private data instead of chunks.
+    pub fn scripts(&self) -> Result<Vec<BParScript>, Error> {
+        if self.0.version == 0x80 {
+            return Ok(self
+                .private_scripts()?
+                .into_iter()
+                .map(|(_, script)| script)
+                .collect());
+        }
+
+        let scripts = self
+            .0
+            .children
+            .iter()
+            .filter(|c| c.id == 0x06)
+            .map(BParScript::try_from)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        match scripts.len() {
+            0 | 5 => Ok(scripts),
+            n => Err(NIFileError::Generic(format!(
+                "Expected 5 script slots, found {n}"
+            ))),
+        }
+    }
+
+    /// The script slots stored in the private data of v80 programs, with the
+    /// position of each slot.
+    fn private_scripts(&self) -> Result<Vec<(std::ops::Range<usize>, BParScript)>, Error> {
+        let mut reader = Cursor::new(&self.0.private_data);
+        let version = reader.read_u32_le()?;
+        if version != 0 {
+            return Err(NIFileError::Generic(format!(
+                "Unsupported ProgramPrivateParams version: 0x{:X}",
+                version
+            )));
+        }
+
+        reader.set_position(V80_SCRIPTS_OFFSET);
+        let mut scripts = Vec::new();
+        for _ in 0..5 {
+            let start = reader.position() as usize;
+            let script = BParScript::read_embedded(&mut reader)?;
+            scripts.push((start..reader.position() as usize, script));
+        }
+        Ok(scripts)
+    }
+
+    /// Replaces the source of a script slot and marks it as applied. The
+    /// title is only changed if one is given.
+    pub fn set_script(
+        &mut self,
+        slot: usize,
+        text: &str,
+        title: Option<&str>,
+    ) -> Result<(), Error> {
+        let mut params = self
+            .scripts()?
+            .get(slot)
+            .ok_or(Error::Static("Script slot not found"))?
+            .params()?;
+        params.text = Some(text.to_string());
+        params.touched_but_not_applied = false;
+        if let Some(title) = title {
+            params.description = Some(title.to_string());
+        }
+        self.set_script_params(slot, &params)
+    }
+
+    /// Replaces the params of a script slot, see [`Program::set_script`].
+    pub fn set_script_params(
+        &mut self,
+        slot: usize,
+        params: &BParScriptParams,
+    ) -> Result<(), Error> {
+        if self.0.version == 0x80 {
+            let (range, mut script) = self
+                .private_scripts()?
+                .into_iter()
+                .nth(slot)
+                .ok_or(Error::Static("Script slot not found"))?;
+            script.set_params(params)?;
+
+            let mut data = Vec::new();
+            script.0.write(&mut data)?;
+            self.0.private_data.splice(range, data);
+            return Ok(());
+        }
+
+        let chunk = self
+            .0
+            .children
+            .iter_mut()
+            .filter(|c| c.id == 0x06)
+            .nth(slot)
+            .ok_or(Error::Static("Script slot not found"))?;
+
+        let mut script = BParScript::try_from(&*chunk)?;
+        script.set_params(params)?;
+
+        chunk.data.clear();
+        script.0.write(&mut chunk.data)?;
+        Ok(())
+    }
+
+    /// Adds a script to the first empty slot and returns the slot.
+    pub fn add_script(&mut self, text: &str, title: Option<&str>) -> Result<usize, Error> {
+        let slot = self
+            .scripts()?
+            .iter()
+            .map(BParScript::params)
+            .collect::<Result<Vec<_>, Error>>()?
+            .iter()
+            .position(|params| {
+                params.text.as_deref().unwrap_or_default().is_empty()
+                    && params
+                        .textfile_name
+                        .as_deref()
+                        .unwrap_or_default()
+                        .is_empty()
+            })
+            .ok_or(Error::Static("No empty script slot"))?;
+        self.set_script(slot, text, title)?;
+        Ok(slot)
+    }
 }
 
 impl std::convert::TryFrom<&Chunk> for Program {
@@ -141,6 +442,7 @@ impl std::convert::TryFrom<&Chunk> for Program {
 }
 
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgramDataPrivateParams {}
 
 impl ProgramDataPrivateParams {
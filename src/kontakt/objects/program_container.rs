@@ -19,9 +19,11 @@ const CHUNK_ID: u16 = 0x29;
 /// - KontaktIO:      K4PL\_ProgramContainer
 ///
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgramContainer(pub StructuredObject);
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgramContainerParams {
     name: String,
     volume: f32,
@@ -17,6 +17,7 @@ const CHUNK_ID: u16 = 0x36;
 /// Kontakt 7:      ?
 /// KontaktIO:      ProgramList
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgramList {
     pub programs: Vec<Program>,
 }
@@ -19,9 +19,11 @@ const CHUNK_ID: u16 = 0x4E;
 /// Kontakt 7:      QuickBrowseData
 /// KontaktIO:
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QuickBrowseData(pub StructuredObject);
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QuickBrowseDataParams {
     pub unknown: i32,
 }
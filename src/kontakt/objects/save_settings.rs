@@ -1,9 +1,11 @@
 use crate::{
     kontakt::{Chunk, KontaktError},
     read_bytes::ReadBytesExt,
-    Error,
+    Error, NIFileError,
 };
 
+use super::BFileName;
+
 const CHUNK_ID: u16 = 0x47;
 
 /// Type:           Chunk
@@ -12,31 +14,47 @@ const CHUNK_ID: u16 = 0x47;
 /// Kontakt 7:      SaveSettings
 /// KontaktIO:      ?
 #[derive(Debug)]
-pub struct SaveSettings;
-
-// SER::WriteBFNTrns(param_1,(BFileName *)(this + 8),param_2);
-//      i32 numFiles BFileName
-// SER::WriteBFNOrig(param_1,(BFileName *)(this + 0x130),param_2);
-//      i32 numFiles BFileName
-// i32
-// bool
-// bool
-// bool
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SaveSettings {
+    pub version: u16,
+    /// Index into the filename table, -1 if unset.
+    pub filename_trns: i32,
+    /// The original filename, empty if unset.
+    pub filename_orig: BFileName,
+    /// Found 0, 1, 2. Likely the save mode (patch only, with samples, monolith).
+    pub u_a: i32,
+    /// Found false, true
+    pub u_b: bool,
+    /// Found false, true
+    pub u_c: bool,
+    /// Found false, true
+    pub u_d: bool,
+}
 
 impl SaveSettings {
     pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
-        let is_structured = reader.read_bool()?; // is structured
-        assert!(!is_structured);
+        if reader.read_bool()? {
+            return Err(KontaktError::UnexpectedStructured(CHUNK_ID).into());
+        }
 
-        // dbg!(reader.read_u16_le()?);
-        // dbg!(reader.read_i32_le()?);
-        // dbg!(reader.read_i32_le()?);
-        //
-        // dbg!(reader.read_i32_le()?);
-        // dbg!(reader.read_bool()?);
-        // dbg!(reader.read_bool()?);
-        // dbg!(reader.read_bool()?);
-        Ok(Self)
+        let version = reader.read_u16_le()?;
+        match version {
+            0x10 => Ok(Self {
+                version,
+                // SER::WriteBFNTrns
+                filename_trns: reader.read_i32_le()?,
+                // SER::WriteBFNOrig
+                filename_orig: BFileName::read(&mut reader)?,
+                u_a: reader.read_i32_le()?,
+                u_b: reader.read_bool()?,
+                u_c: reader.read_bool()?,
+                u_d: reader.read_bool()?,
+            }),
+            _ => Err(NIFileError::Generic(format!(
+                "Unsupported SaveSettings version: 0x{:x}",
+                version
+            ))),
+        }
     }
 }
 
@@ -63,12 +81,16 @@ mod tests {
     use std::fs::File;
 
     #[test]
-    fn test_bank() -> Result<(), Error> {
+    fn test_save_settings() -> Result<(), Error> {
         let chunk = Chunk::read(File::open(
             "tests/data/Objects/Kontakt/0x47-SaveSettings/SaveSettings-000.kon",
         )?)?;
-        let _s = SaveSettings::try_from(&chunk)?;
+        let s = SaveSettings::try_from(&chunk)?;
 
+        assert_eq!(s.version, 0x10);
+        assert_eq!(s.filename_trns, 0);
+        assert!(s.filename_orig.is_empty());
+        assert_eq!(s.u_a, 2);
         Ok(())
     }
 }
@@ -22,6 +22,7 @@ type Error = crate::NIFileError;
 /// KontaktIO:      SlotList
 ///
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SlotList {
     pub slots: HashMap<u16, ProgramContainer>,
 }
@@ -0,0 +1,92 @@
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    Error,
+};
+
+use super::{BGroupSnapshot, BSnapshotMetaData};
+
+const CHUNK_ID: u16 = 0x4F;
+
+/// # BSnapshot
+///
+/// An instrument snapshot (Kontakt 6+), with its group states and metadata as
+/// children. No known file contains a snapshot, so the stored parameter
+/// values are only available as the raw [`StructuredObject`].
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x4F
+/// - Versions:       ?
+/// - Kontakt 7:      BSnapshot
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BSnapshot(pub StructuredObject);
+
+impl BSnapshot {
+    pub fn metadata(&self) -> Result<Option<BSnapshotMetaData>, Error> {
+        self.0
+            .find_first(0x51)
+            .map(BSnapshotMetaData::try_from)
+            .transpose()
+    }
+
+    pub fn group_snapshots(&self) -> Result<Vec<BGroupSnapshot>, Error> {
+        self.0
+            .children
+            .iter()
+            .filter(|c| c.id == 0x50)
+            .map(BGroupSnapshot::try_from)
+            .collect()
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for BSnapshot {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+
+    use super::*;
+
+    fn chunk(id: u16, children: Vec<Chunk>) -> Result<Chunk, Error> {
+        let mut data = Vec::new();
+        StructuredObject {
+            is_structured: true,
+            version: 0x10,
+            public_data: Vec::new(),
+            private_data: Vec::new(),
+            children,
+        }
+        .write(&mut data)?;
+        Ok(Chunk { id, data })
+    }
+
+    #[test]
+    fn test_snapshot_children() -> Result<(), Error> {
+        let snapshot = BSnapshot::try_from(&chunk(
+            CHUNK_ID,
+            vec![
+                chunk(0x50, Vec::new())?,
+                chunk(0x50, Vec::new())?,
+                chunk(0x51, Vec::new())?,
+            ],
+        )?)?;
+        assert_eq!(snapshot.group_snapshots()?.len(), 2);
+        assert!(snapshot.metadata()?.is_some());
+        Ok(())
+    }
+}
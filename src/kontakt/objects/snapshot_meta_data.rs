@@ -0,0 +1,36 @@
+use crate::{
+    kontakt::{error::KontaktError, structured_object::StructuredObject, Chunk},
+    Error,
+};
+
+const CHUNK_ID: u16 = 0x51;
+
+/// # BSnapshotMetaData
+///
+/// Name, author and category of a [`BSnapshot`](super::BSnapshot). No known
+/// file contains one, so its fields are not decoded.
+///
+/// - Type:           Chunk<StructuredObject>
+/// - SerType:        0x51
+/// - Versions:       ?
+/// - Kontakt 7:      BSnapshotMetaData
+/// - KontaktIO:      ?
+///
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BSnapshotMetaData(pub StructuredObject);
+
+impl std::convert::TryFrom<&Chunk> for BSnapshotMetaData {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
+}
@@ -14,22 +14,24 @@ const CHUNK_ID: u16 = 0x0F;
 /// Kontakt 7:      BParStartCriteria
 /// KontaktIO:      K4PL_StartCriteria
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StartCriteria(pub StructuredObject);
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StartCriteriaParams {
     /// Mode: Always, Start On Key, Start On Controller, Cycle Round Robin, Cycle Random, Slice Trigger
-    mode: i32,
-    next_criteria: i32,
-    key_min: i16,
-    key_max: i16,
-    controller: i16,
-    cc_min: i16,
-    cc_max: i16,
-    cycle_class: i32,
-    slice_zone_idx: i32,
-    slice_zone_slice_idx: i32,
-    sequencer_only: bool,
+    pub mode: i32,
+    pub next_criteria: i32,
+    pub key_min: i16,
+    pub key_max: i16,
+    pub controller: i16,
+    pub cc_min: i16,
+    pub cc_max: i16,
+    pub cycle_class: i32,
+    pub slice_zone_idx: i32,
+    pub slice_zone_slice_idx: i32,
+    pub sequencer_only: bool,
 }
 
 impl StartCriteriaParams {
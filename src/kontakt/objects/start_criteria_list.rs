@@ -18,7 +18,8 @@ const CHUNK_ID: u16 = 0x38;
 /// Kontakt 7:      ?
 /// KontaktIO:      StartCritList
 ///
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StartCriteriaList {
     pub items: Vec<StartCriteriaParams>,
 }
@@ -3,17 +3,51 @@ use std::io::Cursor;
 use crate::{
     kontakt::{error::KontaktError, Chunk},
     read_bytes::ReadBytesExt,
-    Error,
+    Error, NIFileError,
 };
 
+use super::VoiceLimit;
+
 const CHUNK_ID: u16 = 0x2b;
 
+/// A voice group, limiting the polyphony of the groups assigned to it.
+///
+/// - Type:           Chunk<Data>
+/// - SerType:        0x2B
+/// - Known Versions: 0x60
+/// - Kontakt 7:      BVoiceLimit
+/// - KontaktIO:      ?
+///
 #[derive(Debug)]
-pub struct VoiceGroup;
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoiceGroup {
+    pub version: u16,
+    pub voice_limit: VoiceLimit,
+}
 
 impl VoiceGroup {
-    pub fn read<R: ReadBytesExt>(mut _reader: R) -> Result<Self, Error> {
-        Ok(Self)
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        let is_structured = reader.read_bool()?;
+        let version = reader.read_u16_le()?;
+
+        if is_structured {
+            return Err(KontaktError::UnexpectedStructured(CHUNK_ID).into());
+        }
+
+        let voice_limit = match version {
+            0x60 => VoiceLimit::read(&mut reader)?,
+            _ => {
+                return Err(NIFileError::Generic(format!(
+                    "Unsupported VoiceGroup version: 0x{:x}",
+                    version
+                )))
+            }
+        };
+
+        Ok(Self {
+            version,
+            voice_limit,
+        })
     }
 }
 
@@ -3,7 +3,7 @@ use std::io::Cursor;
 use crate::{
     kontakt::{objects::voice_limit::VoiceLimit, Chunk, KontaktError},
     read_bytes::ReadBytesExt,
-    Error,
+    Error, NIFileError,
 };
 
 use super::VoiceGroup;
@@ -18,6 +18,7 @@ const MAX_VOICE_GROUPS: usize = 128;
 /// - KontaktIO:      VoiceGroups
 ///
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VoiceGroups {
     pub voice_limit: VoiceLimit,
     pub groups: Vec<Option<VoiceGroup>>,
@@ -28,31 +29,45 @@ impl VoiceGroups {
         let is_structured = reader.read_bool()?;
         let version = reader.read_u16_le()?;
 
-        assert_eq!(is_structured, false);
+        if is_structured {
+            return Err(KontaktError::UnexpectedStructured(0x32).into());
+        }
 
         let voice_limit = match version {
             0x60 => VoiceLimit::read(&mut reader)?,
-            _ => unimplemented!("Unsupported VoiceGroups version: 0x{:x}", version),
+            _ => {
+                return Err(NIFileError::Generic(format!(
+                    "Unsupported VoiceGroups version: 0x{:x}",
+                    version
+                )))
+            }
         };
 
-        let indexes = reader.read_bytes(8)?;
+        // one bit per voice group
+        let indexes = reader.read_bytes(MAX_VOICE_GROUPS / 8)?;
 
-        // let mut groups: [Option<VoiceGroup>; MAX_VOICE_GROUPS] = [None; MAX_VOICE_GROUPS];
-        let groups = Vec::new();
-        for i in 0..8 {
+        let mut groups = Vec::with_capacity(MAX_VOICE_GROUPS);
+        for i in 0..MAX_VOICE_GROUPS {
             if indexes[i >> 3] & (1 << (i & 7)) != 0 {
-                // groups[i] = None;
-                println!("{}: {:?}", i + 1, reader.read_u8()?);
+                groups.push(Some(VoiceGroup::read(&mut reader)?));
+            } else {
+                groups.push(None);
             }
         }
 
-        // next
-
         Ok(Self {
             voice_limit,
             groups,
         })
     }
+
+    /// The voice groups in use, with their index.
+    pub fn active(&self) -> impl Iterator<Item = (usize, &VoiceGroup)> {
+        self.groups
+            .iter()
+            .enumerate()
+            .filter_map(|(i, g)| g.as_ref().map(|g| (i, g)))
+    }
 }
 
 impl std::convert::TryFrom<&Chunk> for VoiceGroups {
@@ -79,15 +94,41 @@ mod tests {
 
     #[test]
     fn test_voice_groups_v60() -> Result<(), Error> {
-        let mut file = File::open("tests/data/Objects/Kontakt/VoiceGroups/v60/000")?;
-
-        VoiceGroups::read(&mut file)?;
+        let mut file = File::open("tests/data/Objects/Kontakt/VoiceGroups/v60/000.kon")?;
+        let voice_groups = VoiceGroups::read(&mut file)?;
 
         // Ensure the read completed
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
         assert_eq!(buf.len(), 0, "Excess data found: {} bytes", buf.len());
 
+        assert_eq!(voice_groups.voice_limit.name, "<instrument>");
+        assert_eq!(voice_groups.voice_limit.max_num_voices, 8);
+        assert_eq!(voice_groups.groups.len(), MAX_VOICE_GROUPS);
+        assert_eq!(voice_groups.active().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_voice_groups_v60_active() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/VoiceGroups/v60/002.kon")?;
+        let chunk = Chunk::read(file)?;
+        let mut reader = Cursor::new(&chunk.data);
+        let voice_groups = VoiceGroups::read(&mut reader)?;
+        assert_eq!(
+            reader.position() as usize,
+            chunk.data.len(),
+            "Excess data found"
+        );
+
+        let active: Vec<_> = voice_groups.active().collect();
+        assert_eq!(active.len(), 1);
+
+        let (index, group) = active[0];
+        assert_eq!(index, 0);
+        assert_eq!(group.voice_limit.max_num_voices, 7);
+        assert_eq!(group.voice_limit.ms_fade_time, 10);
+        assert_eq!(group.voice_limit.exclusion_group, 0);
         Ok(())
     }
 }
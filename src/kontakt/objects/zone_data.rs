@@ -1,14 +1,25 @@
 use std::io::Cursor;
 
-use crate::{kontakt::structured_object::StructuredObject, read_bytes::ReadBytesExt, Error};
+use crate::{
+    kontakt::{structured_object::StructuredObject, Chunk, KontaktError},
+    read_bytes::ReadBytesExt,
+    write_bytes::WriteBytesExt,
+    Error,
+};
+
+use super::LoopArray;
+
+const CHUNK_ID: u16 = 0x2C;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Zone(pub StructuredObject);
 
 /// Type:           StructuredObject
 /// Kontakt 7:      BZone, BProgram::readZones()
 /// KontaktIO:      K4PL_Zone<K4PO::K4PL_ZoneDataV95>
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ZoneParams {
     pub sample_start: i32,
     pub sample_end: i32,
@@ -25,6 +36,12 @@ pub struct ZoneParams {
     pub zone_volume: f32,
     pub zone_pan: f32,
     pub zone_tune: f32,
+    /// v9A, found 0
+    pub u_a: Option<u8>,
+    /// v9A, found 1
+    pub u_b: Option<u8>,
+    /// v9A, found -1
+    pub u_c: Option<i32>,
     /// The index of the file in the filetable.
     pub filename_id: i32,
     pub sample_data_type: i32,
@@ -42,18 +59,61 @@ pub struct ZoneParams {
     // PrivateRawObject 0x35
 }
 
+impl ZoneParams {
+    /// Writes the params in the layout of the given version.
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W, version: u16) -> Result<(), Error> {
+        writer.write_i32_le(self.sample_start)?;
+        writer.write_i32_le(self.sample_end)?;
+        writer.write_i32_le(self.sample_start_mod_range)?;
+        writer.write_i16_le(self.low_velocity)?;
+        writer.write_i16_le(self.high_velocity)?;
+        writer.write_i16_le(self.low_key)?;
+        writer.write_i16_le(self.high_key)?;
+        writer.write_i16_le(self.fade_low_velocity)?;
+        writer.write_i16_le(self.fade_high_velocity)?;
+        writer.write_i16_le(self.fade_low_key)?;
+        writer.write_i16_le(self.fade_high_key)?;
+        writer.write_i16_le(self.root_key)?;
+        writer.write_f32_le(self.zone_volume)?;
+        writer.write_f32_le(self.zone_pan)?;
+        writer.write_f32_le(self.zone_tune)?;
+        if version >= 0x9A {
+            writer.write_u8(self.u_a.ok_or(Error::Static("ZoneParams: missing u_a"))?)?;
+            writer.write_u8(self.u_b.ok_or(Error::Static("ZoneParams: missing u_b"))?)?;
+            writer.write_i32_le(self.u_c.ok_or(Error::Static("ZoneParams: missing u_c"))?)?;
+        }
+        writer.write_i32_le(self.filename_id)?;
+        writer.write_i32_le(self.sample_data_type)?;
+        writer.write_i32_le(self.sample_rate)?;
+        writer.write_u8(self.num_channels)?;
+        writer.write_i32_le(self.num_frames)?;
+        writer.write_i32_le(self.reserved1)?;
+        if version < 0x96 {
+            writer.write_i32_le(
+                self.reserved2
+                    .ok_or(Error::Static("ZoneParams: missing reserved2"))?,
+            )?;
+        }
+        writer.write_i32_le(self.root_note)?;
+        writer.write_f32_le(self.tuning)?;
+        writer.write_bool(self.reserved3)?;
+        writer.write_i32_le(self.reserved4)?;
+        Ok(())
+    }
+}
+
 impl Zone {
     pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
         Ok(Self(StructuredObject::read(&mut reader)?))
     }
 
+    pub fn loops(&self) -> Option<Result<LoopArray, Error>> {
+        self.0.find_first(0x39).map(LoopArray::try_from)
+    }
+
     pub fn params(&self) -> Result<ZoneParams, Error> {
         let mut reader = Cursor::new(&self.0.public_data);
 
-        for chunk in &self.0.children {
-            println!("{:?} {:x}", chunk.into_object()?, chunk.id);
-        }
-
         Ok(ZoneParams {
             sample_start: reader.read_i32_le()?,
             sample_end: reader.read_i32_le()?,
@@ -70,6 +130,18 @@ impl Zone {
             zone_volume: reader.read_f32_le()?,
             zone_pan: reader.read_f32_le()?,
             zone_tune: reader.read_f32_le()?,
+            u_a: match self.0.version {
+                0x9A.. => Some(reader.read_u8()?),
+                _ => None,
+            },
+            u_b: match self.0.version {
+                0x9A.. => Some(reader.read_u8()?),
+                _ => None,
+            },
+            u_c: match self.0.version {
+                0x9A.. => Some(reader.read_i32_le()?),
+                _ => None,
+            },
             filename_id: reader.read_i32_le()?,
             sample_data_type: reader.read_i32_le()?,
             sample_rate: reader.read_i32_le()?,
@@ -89,6 +161,34 @@ impl Zone {
             reserved4: reader.read_i32_le()?,
         })
     }
+
+    /// Replaces the params, keeping any public data stored after them.
+    pub fn set_params(&mut self, params: &ZoneParams) -> Result<(), Error> {
+        let mut current = Vec::new();
+        self.params()?.write(&mut current, self.0.version)?;
+        let remainder = self.0.public_data[current.len()..].to_vec();
+
+        let mut public_data = Vec::new();
+        params.write(&mut public_data, self.0.version)?;
+        public_data.extend(remainder);
+        self.0.public_data = public_data;
+        Ok(())
+    }
+}
+
+impl std::convert::TryFrom<&Chunk> for Zone {
+    type Error = Error;
+
+    fn try_from(chunk: &Chunk) -> Result<Self, Self::Error> {
+        if chunk.id != CHUNK_ID {
+            return Err(KontaktError::IncorrectID {
+                expected: CHUNK_ID,
+                got: chunk.id,
+            }
+            .into());
+        }
+        Ok(Self(chunk.try_into()?))
+    }
 }
 
 #[cfg(test)]
@@ -104,7 +204,11 @@ mod tests {
             File::open("tests/data/Objects/Kontakt/ZoneData/ZoneDataV9A/ZoneDataV9A-000.kon")?;
         let zone = Zone::read(file)?;
         assert_eq!(zone.0.version, 0x9A);
-        zone.params()?;
+
+        let params = zone.params()?;
+        assert_eq!(params.u_c, Some(-1));
+        assert_eq!(params.sample_rate, 44100);
+        assert!(!params.reserved3);
         Ok(())
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
     kontakt::{chunk::Chunk, error::KontaktError},
     read_bytes::ReadBytesExt,
+    write_bytes::WriteBytesExt,
     Error,
 };
 
@@ -18,23 +19,50 @@ const CHUNK_ID: u16 = 0x34;
 /// - KontaktIO:      ZoneList<K4PL_Zone<K4PO::K4PL_ZoneDataV95>>
 ///
 #[derive(Debug)]
-pub struct ZoneList(Vec<Zone>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZoneList {
+    zones: Vec<Zone>,
+    group_indices: Vec<u32>,
+}
 
 impl ZoneList {
     pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
         let num_zones = reader.read_u32_le()?;
         let mut zones = Vec::new();
+        let mut group_indices = Vec::new();
 
         for _ in 0..num_zones {
-            let _ = reader.read_u32_le()?; // mostly 0, sometimes 1, 2, etc
+            group_indices.push(reader.read_u32_le()?);
             zones.push(Zone::read(&mut reader)?);
         }
 
-        Ok(Self(zones))
+        Ok(Self {
+            zones,
+            group_indices,
+        })
+    }
+
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_u32_le(self.zones.len() as u32)?;
+        for (zone, group_index) in self.zones.iter().zip(&self.group_indices) {
+            writer.write_u32_le(*group_index)?;
+            zone.0.write(&mut writer)?;
+        }
+        Ok(())
     }
 
     pub fn zones(&self) -> &Vec<Zone> {
-        &self.0
+        &self.zones
+    }
+
+    pub fn zones_mut(&mut self) -> &mut Vec<Zone> {
+        &mut self.zones
+    }
+
+    /// The index in the [`GroupList`](super::GroupList) of the group each
+    /// zone belongs to.
+    pub fn group_indices(&self) -> &Vec<u32> {
+        &self.group_indices
     }
 }
 
@@ -61,6 +89,16 @@ mod tests {
     use super::*;
     use crate::Error;
 
+    #[test]
+    fn test_zone_list_group_indices() -> Result<(), Error> {
+        let file = File::open("tests/data/Objects/Kontakt/0x34-ZoneList/ZoneList-001.kon")?;
+        let zonelist = ZoneList::try_from(&Chunk::read(file)?)?;
+        assert_eq!(zonelist.zones().len(), 414);
+        assert_eq!(zonelist.group_indices()[0], 0);
+        assert_eq!(zonelist.group_indices()[413], 5);
+        Ok(())
+    }
+
     #[test]
     fn test_zone_list_001() -> Result<(), Error> {
         let file = File::open("tests/data/Objects/Kontakt/0x34-ZoneList/old/ZoneList-001.kon")?;
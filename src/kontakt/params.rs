@@ -0,0 +1,197 @@
+use crate::Error;
+
+use super::{
+    objects::{BParScriptParams, GroupParams, ProgramPublicParams, ZoneParams},
+    KontaktChunks,
+};
+
+/// The public params of a program and its groups, zones and script slots.
+///
+/// With the `serde` feature this can be edited as JSON and written back
+/// with [`KontaktChunks::set_params`].
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InstrumentParams {
+    pub program: ProgramPublicParams,
+    pub groups: Vec<GroupParams>,
+    pub zones: Vec<ZoneParams>,
+    pub scripts: Vec<BParScriptParams>,
+}
+
+impl KontaktChunks {
+    pub fn params(&self) -> Result<InstrumentParams, Error> {
+        let program = self
+            .program()
+            .ok_or(Error::Static("Could not find Program"))??;
+
+        let groups = match program.group_list() {
+            Some(list) => list?
+                .groups
+                .iter()
+                .map(|group| group.params())
+                .collect::<Result<_, Error>>()?,
+            None => Vec::new(),
+        };
+
+        let zones = match program.zone_list() {
+            Some(list) => list?
+                .zones()
+                .iter()
+                .map(|zone| zone.params())
+                .collect::<Result<_, Error>>()?,
+            None => Vec::new(),
+        };
+
+        let scripts = program
+            .scripts()?
+            .iter()
+            .map(|script| script.params())
+            .collect::<Result<_, Error>>()?;
+
+        Ok(InstrumentParams {
+            program: program.params()?,
+            groups,
+            zones,
+            scripts,
+        })
+    }
+
+    /// Re-encodes the params into the public data of each object, in the
+    /// layout of its version. Private data and children are kept as they
+    /// are. The number of groups, zones and scripts must not change.
+    ///
+    /// ```no_run
+    /// use std::{fs::File, io::Cursor};
+    /// use ni_file::{kontakt::KontaktChunks, NIFile};
+    ///
+    /// let mut file = NIFile::read(File::open("instrument.nki")?)?;
+    /// let mut chunks = KontaktChunks::read(Cursor::new(file.inner_preset()?))?;
+    ///
+    /// let mut params = chunks.params()?;
+    /// params.program.volume = 0.5;
+    /// chunks.set_params(&params)?;
+    ///
+    /// let mut data = Vec::new();
+    /// chunks.write(&mut data)?;
+    /// file.set_inner_preset(&data)?;
+    /// file.write(File::create("instrument.nki")?)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_params(&mut self, params: &InstrumentParams) -> Result<(), Error> {
+        let mut program = self
+            .program()
+            .ok_or(Error::Static("Could not find Program"))??;
+        program.set_params(&params.program)?;
+
+        if let Some(list) = program.group_list() {
+            let mut list = list?;
+            if list.groups.len() != params.groups.len() {
+                return Err(Error::Static("Number of groups does not match"));
+            }
+            for (group, group_params) in list.groups.iter_mut().zip(&params.groups) {
+                group.set_params(group_params)?;
+            }
+            program.set_group_list(&list)?;
+        }
+
+        if let Some(list) = program.zone_list() {
+            let mut list = list?;
+            if list.zones().len() != params.zones.len() {
+                return Err(Error::Static("Number of zones does not match"));
+            }
+            for (zone, zone_params) in list.zones_mut().iter_mut().zip(&params.zones) {
+                zone.set_params(zone_params)?;
+            }
+            program.set_zone_list(&list)?;
+        }
+
+        let slots = program.scripts()?.len();
+        if slots != params.scripts.len() {
+            return Err(Error::Static("Number of scripts does not match"));
+        }
+        for (slot, script_params) in params.scripts.iter().enumerate() {
+            program.set_script_params(slot, script_params)?;
+        }
+
+        self.set_program(&program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::Cursor};
+
+    use crate::NIFile;
+
+    use super::*;
+
+    const PATH: &str = "tests/data/Containers/NIS/files/kontakt/5.8.1.43/5.8.1.43-ncw.nki";
+
+    #[test]
+    fn test_set_params() -> Result<(), Error> {
+        let file = NIFile::read(File::open(PATH)?)?;
+        let preset = file.inner_preset()?;
+        let mut chunks = KontaktChunks::read(Cursor::new(&preset))?;
+
+        // unchanged params are written byte for byte
+        let mut params = chunks.params()?;
+        chunks.set_params(&params)?;
+        let mut data = Vec::new();
+        chunks.write(&mut data)?;
+        assert_eq!(data, preset);
+
+        params.program.name = "Renamed".into();
+        params.groups[0].volume = 0.5;
+        params.zones[0].zone_tune = 2.0;
+        chunks.set_params(&params)?;
+
+        let mut data = Vec::new();
+        chunks.write(&mut data)?;
+        let params = KontaktChunks::read(Cursor::new(data))?.params()?;
+        assert_eq!(params.program.name, "Renamed");
+        assert_eq!(params.groups[0].volume, 0.5);
+        assert_eq!(params.zones[0].zone_tune, 2.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_params_versioned() -> Result<(), Error> {
+        let file = NIFile::read(File::open(PATH)?)?;
+        let mut chunks = KontaktChunks::read(Cursor::new(file.inner_preset()?))?;
+
+        let mut params = chunks.params()?;
+        assert_eq!(params.program.resource_container_filename, Some(0));
+        assert_eq!(params.program.wallpaper_filename, Some(-1));
+
+        params.program.wallpaper_filename = Some(1);
+        chunks.set_params(&params)?;
+        let params = chunks.params()?;
+        assert_eq!(params.program.wallpaper_filename, Some(1));
+
+        let mut changed = chunks.params()?;
+        changed.program.wallpaper_filename = None;
+        assert!(chunks.set_params(&changed).is_err());
+
+        let mut changed = chunks.params()?;
+        changed.groups[0].start_criteria.items.clear();
+        assert!(chunks.set_params(&changed).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_params_json() -> Result<(), Error> {
+        let file = NIFile::read(File::open(PATH)?)?;
+        let preset = file.inner_preset()?;
+        let mut chunks = KontaktChunks::read(Cursor::new(&preset))?;
+
+        let json = serde_json::to_string_pretty(&chunks.params()?).unwrap();
+        let params: InstrumentParams = serde_json::from_str(&json).unwrap();
+        chunks.set_params(&params)?;
+
+        let mut data = Vec::new();
+        chunks.write(&mut data)?;
+        assert_eq!(data, preset);
+        Ok(())
+    }
+}
@@ -8,8 +8,10 @@ use super::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KontaktPatch {
     pub header: BPatchHeaderV42,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub data: Vec<u8>,
     // pub sound_info: SoundInfoItem,
 }
@@ -29,6 +31,7 @@ impl KontaktPatch {
             KontaktPreset::KontaktV1(_) | KontaktPreset::KontaktV2(_) => None,
             KontaktPreset::KontaktV42(p) => Some(p.program),
             KontaktPreset::Kon5(p) => Some(p.program),
+            KontaktPreset::Kon5PreK51(p) => Some(p.program),
             KontaktPreset::Kon6(p) => Some(p.program),
             KontaktPreset::Kon7(p) => Some(p.program),
             KontaktPreset::NKM(_) => None,
@@ -1,4 +1,5 @@
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum BProgram {
     ProgramDataV80(ProgramDataV80),
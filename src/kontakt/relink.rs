@@ -0,0 +1,470 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use time::OffsetDateTime;
+
+use crate::Error;
+
+use super::{
+    objects::{BFileName, BFileNameSegment, FNTableImpl},
+    SampleLocation, SampleResolver,
+};
+
+/// The table of a [`FNTableImpl`] a file is listed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FileTable {
+    /// Resources (nkr) and search paths
+    Special,
+    /// Samples (wav, ncw)
+    Sample,
+    /// Instruments (nki) and internal files (ir samples, wallpapers)
+    Other,
+}
+
+/// A file found below the library root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LibraryFile {
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LinkStatus {
+    /// The file exists on disk.
+    Found(PathBuf),
+    /// The file is missing. Possible replacements, best match first.
+    Missing(Vec<LibraryFile>),
+    /// The library container (nkx, nkr) the file is stored in is missing.
+    /// Possible replacements of the container, best match first.
+    MissingLibrary(Vec<LibraryFile>),
+    /// Stored in an existing library container, a monolith or a location
+    /// managed by Kontakt, and cannot be checked on disk.
+    Skipped(SampleLocation),
+}
+
+/// A single entry of a filetable and its state on disk.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileLink {
+    pub table: FileTable,
+    pub filename_id: u32,
+    /// The path as stored in the filetable.
+    pub path: BFileName,
+    pub status: LinkStatus,
+}
+
+impl FileLink {
+    pub fn is_missing(&self) -> bool {
+        matches!(
+            self.status,
+            LinkStatus::Missing(_) | LinkStatus::MissingLibrary(_)
+        )
+    }
+
+    /// The replacement for a missing file, if there is a single one.
+    ///
+    /// Candidates of the best match that have the same size are considered
+    /// copies of one file.
+    pub fn relink(&self) -> Option<&LibraryFile> {
+        let LinkStatus::Missing(candidates) = &self.status else {
+            return None;
+        };
+        let name = self.path.file_name()?;
+        let best = candidates.first()?;
+        let rank = match_rank(name, &best.path);
+        candidates
+            .iter()
+            .take_while(|c| match_rank(name, &c.path) == rank)
+            .all(|c| c.size == best.size)
+            .then_some(best)
+    }
+}
+
+/// Every file referenced by a filetable.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinkReport {
+    pub files: Vec<FileLink>,
+}
+
+impl LinkReport {
+    pub fn missing(&self) -> impl Iterator<Item = &FileLink> {
+        self.files.iter().filter(|f| f.is_missing())
+    }
+
+    /// Points every missing file with a single replacement to it. Paths are
+    /// stored relative to `instrument_dir` where possible, sample timestamps
+    /// are updated from the replacement.
+    ///
+    /// Returns the number of relinked files.
+    pub fn apply(&self, filetable: &mut FNTableImpl, instrument_dir: &Path) -> usize {
+        let mut relinked = 0;
+        for file in &self.files {
+            let Some(target) = file.relink() else {
+                continue;
+            };
+            let path = BFileName::from_path(&target.path, instrument_dir);
+            match file.table {
+                FileTable::Special => filetable.special_filetable.insert(file.filename_id, path),
+                FileTable::Sample => {
+                    if let Ok(modified) = std::fs::metadata(&target.path).and_then(|m| m.modified())
+                    {
+                        filetable
+                            .sample_timestamp_table
+                            .insert(file.filename_id, OffsetDateTime::from(modified));
+                    }
+                    filetable.sample_filetable.insert(file.filename_id, path)
+                }
+                FileTable::Other => filetable.other_filetable.insert(file.filename_id, path),
+            };
+            relinked += 1;
+        }
+        relinked
+    }
+}
+
+/// An index of the files below a library root, used to find missing files.
+///
+/// ```no_run
+/// use std::{fs::File, io::Cursor, path::Path};
+/// use ni_file::{
+///     kontakt::{KontaktChunks, LibraryIndex, SampleResolver},
+///     NIFile,
+/// };
+///
+/// let mut file = NIFile::read(File::open("Library/Instruments/instrument.nki")?)?;
+/// let mut filetable = SampleResolver::try_from(&file)?.filetable;
+///
+/// let index = LibraryIndex::new("Library")?;
+/// let report = index.check(&filetable, Path::new("Library/Instruments"));
+/// for file in report.missing() {
+///     println!("missing: {} relink: {:?}", file.path, file.relink());
+/// }
+/// report.apply(&mut filetable, Path::new("Library/Instruments"));
+///
+/// let mut chunks = KontaktChunks::read(Cursor::new(file.inner_preset()?))?;
+/// chunks.set_filename_tables(&filetable)?;
+/// let mut data = Vec::new();
+/// chunks.write(&mut data)?;
+/// file.set_inner_preset(&data)?;
+/// file.write(File::create("Library/Instruments/instrument.nki")?)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LibraryIndex {
+    pub root: PathBuf,
+    /// Files by lowercase name without extension.
+    files: HashMap<String, Vec<LibraryFile>>,
+}
+
+impl LibraryIndex {
+    pub fn new<P: AsRef<Path>>(root: P) -> Result<Self, Error> {
+        let root = root.as_ref().to_path_buf();
+        let mut files: HashMap<String, Vec<LibraryFile>> = HashMap::new();
+
+        let mut dirs = vec![root.clone()];
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(dir)? {
+                let entry = entry?;
+                let metadata = entry.metadata()?;
+                if metadata.is_dir() {
+                    dirs.push(entry.path());
+                    continue;
+                }
+                let path = entry.path();
+                if let Some(key) = path
+                    .file_name()
+                    .and_then(|n| index_key(&n.to_string_lossy()))
+                {
+                    files.entry(key).or_default().push(LibraryFile {
+                        path,
+                        size: metadata.len(),
+                    });
+                }
+            }
+        }
+
+        Ok(Self { root, files })
+    }
+
+    /// Files matching the name of `path`, ignoring case and swapping wav and
+    /// ncw. Best match first.
+    pub fn find(&self, path: &BFileName) -> Vec<LibraryFile> {
+        let Some(name) = path.file_name() else {
+            return Vec::new();
+        };
+        let Some(key) = index_key(name) else {
+            return Vec::new();
+        };
+        let mut found: Vec<LibraryFile> = self
+            .files
+            .get(&key)
+            .into_iter()
+            .flatten()
+            .filter(|file| match_rank(name, &file.path).is_some())
+            .cloned()
+            .collect();
+        found.sort_by_key(|file| {
+            (
+                match_rank(name, &file.path),
+                std::cmp::Reverse(shared_dirs(path, &file.path)),
+            )
+        });
+        found
+    }
+
+    /// Checks every file of the filetable for an instrument stored in
+    /// `instrument_dir`.
+    pub fn check(&self, filetable: &FNTableImpl, instrument_dir: &Path) -> LinkReport {
+        let resolver = SampleResolver::new(filetable.clone());
+        let search_paths: Vec<PathBuf> = resolver
+            .search_paths()
+            .into_iter()
+            .map(|path| path.to_path(instrument_dir))
+            .collect();
+
+        let mut files = Vec::new();
+        for (table, entries) in [
+            (FileTable::Special, &filetable.special_filetable),
+            (FileTable::Sample, &filetable.sample_filetable),
+            (FileTable::Other, &filetable.other_filetable),
+        ] {
+            let mut entries: Vec<_> = entries.iter().collect();
+            entries.sort_by_key(|(id, _)| **id);
+
+            for (id, path) in entries {
+                if path.is_empty() {
+                    continue;
+                }
+                let status = match resolver.location(path) {
+                    location @ SampleLocation::Library { .. } => {
+                        match self.status(&library_path(path), instrument_dir, &search_paths) {
+                            LinkStatus::Missing(candidates) => {
+                                LinkStatus::MissingLibrary(candidates)
+                            }
+                            _ => LinkStatus::Skipped(location),
+                        }
+                    }
+                    location @ (SampleLocation::SpecialLocation | SampleLocation::Monolith) => {
+                        LinkStatus::Skipped(location)
+                    }
+                    _ => self.status(path, instrument_dir, &search_paths),
+                };
+                files.push(FileLink {
+                    table,
+                    filename_id: *id,
+                    path: path.clone(),
+                    status,
+                });
+            }
+        }
+
+        LinkReport { files }
+    }
+
+    fn status(
+        &self,
+        path: &BFileName,
+        instrument_dir: &Path,
+        search_paths: &[PathBuf],
+    ) -> LinkStatus {
+        let expected = path.to_path(instrument_dir);
+        let mut candidates = vec![expected.clone()];
+        if let Some(name) = path.file_name() {
+            candidates.extend(search_paths.iter().map(|dir| dir.join(name)));
+        }
+        if let Some(found) = candidates.into_iter().find(|p| p.exists()) {
+            return LinkStatus::Found(found);
+        }
+        let Some(name) = path.file_name() else {
+            return LinkStatus::Missing(Vec::new());
+        };
+
+        // files next to the expected location, e.g. with a different case
+        let mut relinks: Vec<LibraryFile> = expected
+            .parent()
+            .and_then(|dir| std::fs::read_dir(dir).ok())
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| match_rank(name, &entry.path()).is_some())
+            .filter_map(|entry| {
+                Some(LibraryFile {
+                    path: entry.path(),
+                    size: entry.metadata().ok()?.len(),
+                })
+            })
+            .collect();
+        relinks.sort_by_key(|file| match_rank(name, &file.path));
+
+        for file in self.find(path) {
+            if !relinks.iter().any(|r| r.path == file.path) {
+                relinks.push(file);
+            }
+        }
+        LinkStatus::Missing(relinks)
+    }
+}
+
+/// The path of the library container a file is stored in.
+fn library_path(path: &BFileName) -> BFileName {
+    let mut segments = Vec::new();
+    for segment in &path.segments {
+        if let BFileNameSegment::Library(name) = segment {
+            segments.push(BFileNameSegment::FileName(name.clone()));
+            break;
+        }
+        segments.push(segment.clone());
+    }
+    BFileName {
+        segments,
+        unset: false,
+    }
+}
+
+/// Lowercase file name without the extension.
+fn index_key(name: &str) -> Option<String> {
+    let stem = Path::new(name).file_stem()?.to_string_lossy();
+    Some(stem.to_lowercase())
+}
+
+/// How well a file on disk matches a filetable file name, lower is better.
+/// `None` if the names differ.
+fn match_rank(name: &str, candidate: &Path) -> Option<u8> {
+    let candidate = candidate.file_name()?.to_string_lossy();
+    if candidate == name {
+        return Some(0);
+    }
+    if candidate.eq_ignore_ascii_case(name) {
+        return Some(1);
+    }
+
+    let (stem, ext) = split_extension(name);
+    let (candidate_stem, candidate_ext) = split_extension(&candidate);
+    let swapped = matches!(
+        (
+            ext.to_lowercase().as_str(),
+            candidate_ext.to_lowercase().as_str()
+        ),
+        ("wav", "ncw") | ("ncw", "wav")
+    );
+    match swapped && stem.eq_ignore_ascii_case(candidate_stem) {
+        true => Some(2),
+        false => None,
+    }
+}
+
+fn split_extension(name: &str) -> (&str, &str) {
+    name.rsplit_once('.').unwrap_or((name, ""))
+}
+
+/// Number of parent directories the candidate shares with the path.
+fn shared_dirs(path: &BFileName, candidate: &Path) -> usize {
+    let dirs = path.segments.iter().rev().skip(1).map(|s| s.name());
+    let candidate_dirs = candidate
+        .parent()
+        .into_iter()
+        .flat_map(|p| p.components().rev())
+        .map(|c| c.as_os_str().to_string_lossy().to_lowercase());
+    dirs.zip(candidate_dirs)
+        .take_while(|(a, b)| a.to_lowercase() == *b)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, fs};
+
+    use super::*;
+
+    fn filetable(samples: &[&str]) -> FNTableImpl {
+        FNTableImpl {
+            special_filetable: HashMap::new(),
+            sample_filetable: (0..)
+                .zip(samples.iter().map(|s| BFileName::from(*s)))
+                .collect(),
+            sample_timestamp_table: HashMap::new(),
+            other_filetable: HashMap::new(),
+            u_a: HashMap::new(),
+            u_b: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_relink() -> Result<(), Error> {
+        let root = std::env::temp_dir().join("ni-file-test-relink");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("Instruments"))?;
+        fs::create_dir_all(root.join("Samples/Moved"))?;
+        fs::write(root.join("Samples/found.wav"), [0; 4])?;
+        fs::write(root.join("Samples/Moved/moved.wav"), [0; 4])?;
+        fs::write(root.join("Samples/Moved/CASE.wav"), [0; 4])?;
+        fs::write(root.join("Samples/Moved/compressed.ncw"), [0; 4])?;
+
+        let instrument_dir = root.join("Instruments");
+        let mut table = filetable(&[
+            "../Samples/found.wav",
+            "../Samples/moved.wav",
+            "../Samples/case.wav",
+            "../Samples/compressed.wav",
+            "../Samples/gone.wav",
+        ]);
+        let index = LibraryIndex::new(&root)?;
+        let report = index.check(&table, &instrument_dir);
+
+        assert!(matches!(report.files[0].status, LinkStatus::Found(_)));
+        assert_eq!(report.missing().count(), 4);
+        assert!(report.files[4].relink().is_none());
+
+        assert_eq!(report.apply(&mut table, &instrument_dir), 3);
+        assert_eq!(
+            table.sample_filetable[&1].to_string(),
+            "../Samples/Moved/moved.wav"
+        );
+        assert_eq!(
+            table.sample_filetable[&2].to_string(),
+            "../Samples/Moved/CASE.wav"
+        );
+        assert_eq!(
+            table.sample_filetable[&3].to_string(),
+            "../Samples/Moved/compressed.ncw"
+        );
+        assert!(table.sample_timestamp_table.contains_key(&1));
+
+        let report = index.check(&table, &instrument_dir);
+        assert_eq!(report.missing().count(), 1);
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_relink_library() -> Result<(), Error> {
+        let root = std::env::temp_dir().join("ni-file-test-relink-library");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("Instruments"))?;
+        fs::create_dir_all(root.join("Samples/Moved"))?;
+        fs::write(root.join("Samples/found.nkx"), [0; 4])?;
+        fs::write(root.join("Samples/Moved/moved.nkx"), [0; 4])?;
+
+        let instrument_dir = root.join("Instruments");
+        let table = filetable(&["../Samples/found.nkx/a.wav", "../Samples/moved.nkx/b.wav"]);
+        let report = LibraryIndex::new(&root)?.check(&table, &instrument_dir);
+
+        assert!(matches!(report.files[0].status, LinkStatus::Skipped(_)));
+        let LinkStatus::MissingLibrary(candidates) = &report.files[1].status else {
+            panic!("expected a missing library");
+        };
+        assert!(candidates[0].path.ends_with("Samples/Moved/moved.nkx"));
+        assert!(report.files[1].relink().is_none());
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,361 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use time::OffsetDateTime;
+
+use crate::{file_container::NIFileContainer, Error, NIFile};
+
+use super::{
+    objects::{BFileName, BFileNameSegment, FNTableImpl},
+    schemas::KontaktPreset,
+    KontaktInstrument,
+};
+
+/// Where a sample referenced by a filetable is stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SampleLocation {
+    /// Relative to the directory of the instrument.
+    Relative,
+    /// Starts with a drive or volume root.
+    Absolute,
+    /// Inside a Kontakt library container (nkx).
+    Library { container: String },
+    /// Relative to a location managed by Kontakt, e.g. the factory content.
+    SpecialLocation,
+    /// Embedded in the monolith the instrument was read from.
+    Monolith,
+}
+
+/// A sample referenced by an instrument.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SampleReference {
+    /// Index into the sample filetable.
+    pub filename_id: u32,
+    /// The path as stored in the filetable.
+    pub path: BFileName,
+    pub location: SampleLocation,
+    /// Modification time of the sample when the instrument was saved.
+    pub timestamp: Option<OffsetDateTime>,
+    /// Indices into [`KontaktInstrument::zones`] using this sample.
+    pub zones: Vec<usize>,
+}
+
+/// Maps zones to the samples of a Kontakt filetable.
+///
+/// ```no_run
+/// use ni_file::{
+///     kontakt::{KontaktInstrument, SampleResolver},
+///     NIFile,
+/// };
+///
+/// let file = NIFile::read(std::fs::File::open("instrument.nki")?)?;
+/// let instrument = KontaktInstrument::try_from(&file)?;
+/// let resolver = SampleResolver::try_from(&file)?;
+///
+/// for sample in resolver.samples(&instrument) {
+///     println!("{} {:?}", sample.path.to_posix(), sample.location);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SampleResolver {
+    pub filetable: FNTableImpl,
+    /// Files stored in the monolith the instrument was read from.
+    container_files: HashSet<String>,
+}
+
+impl SampleResolver {
+    pub fn new(filetable: FNTableImpl) -> Self {
+        Self {
+            filetable,
+            container_files: HashSet::new(),
+        }
+    }
+
+    /// Marks samples stored in a monolith as [`SampleLocation::Monolith`].
+    pub fn with_container(mut self, container: &NIFileContainer) -> Self {
+        self.container_files = container
+            .items
+            .iter()
+            .filter_map(|item| file_name(&item.filename))
+            .map(str::to_lowercase)
+            .collect();
+        self
+    }
+
+    /// Resolves a single entry of the sample filetable.
+    pub fn resolve(&self, filename_id: u32) -> Option<SampleReference> {
+        let path = self.filetable.sample_filetable.get(&filename_id)?;
+        Some(SampleReference {
+            filename_id,
+            path: path.clone(),
+            location: self.location(path),
+            timestamp: self
+                .filetable
+                .sample_timestamp_table
+                .get(&filename_id)
+                .copied(),
+            zones: Vec::new(),
+        })
+    }
+
+    /// Resolves the sample of every zone, `None` for zones without a sample.
+    pub fn zones(&self, instrument: &KontaktInstrument) -> Vec<Option<SampleReference>> {
+        instrument
+            .zones
+            .iter()
+            .enumerate()
+            .map(|(index, zone)| {
+                let mut sample = self.resolve(u32::try_from(zone.filename_id).ok()?)?;
+                sample.zones.push(index);
+                Some(sample)
+            })
+            .collect()
+    }
+
+    /// Every sample used by the instrument, ordered by filetable index.
+    pub fn samples(&self, instrument: &KontaktInstrument) -> Vec<SampleReference> {
+        let mut samples: BTreeMap<u32, SampleReference> = BTreeMap::new();
+        for sample in self.zones(instrument).into_iter().flatten() {
+            match samples.get_mut(&sample.filename_id) {
+                Some(existing) => existing.zones.extend(sample.zones),
+                None => {
+                    samples.insert(sample.filename_id, sample);
+                }
+            }
+        }
+        samples.into_values().collect()
+    }
+
+    /// The path of the instrument when it was saved, from the other filetable.
+    pub fn instrument_path(&self) -> Option<&BFileName> {
+        let mut other: Vec<_> = self.filetable.other_filetable.iter().collect();
+        other.sort_by_key(|(id, _)| **id);
+        other.into_iter().map(|(_, path)| path).find(|path| {
+            path.file_name()
+                .and_then(|name| Path::new(name).extension())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("nki"))
+        })
+    }
+
+    /// Directories Kontakt searches for missing samples, from the special
+    /// filetable. Resource containers (nkr) are skipped.
+    pub fn search_paths(&self) -> Vec<&BFileName> {
+        let mut special: Vec<_> = self.filetable.special_filetable.iter().collect();
+        special.sort_by_key(|(id, _)| **id);
+        special
+            .into_iter()
+            .map(|(_, path)| path)
+            .filter(|path| {
+                matches!(
+                    path.segments.last(),
+                    Some(BFileNameSegment::Directory(_) | BFileNameSegment::Drive(_))
+                )
+            })
+            .collect()
+    }
+
+    /// Possible locations of a sample on disk, for an instrument stored in
+    /// `instrument_dir`.
+    pub fn candidates(&self, sample: &SampleReference, instrument_dir: &Path) -> Vec<PathBuf> {
+        let mut candidates = Vec::new();
+        match sample.location {
+            SampleLocation::Absolute => candidates.push(sample.path.to_path(instrument_dir)),
+            SampleLocation::Relative => {
+                candidates.push(sample.path.to_path(instrument_dir));
+                for search_path in self.search_paths() {
+                    let dir = search_path.to_path(instrument_dir);
+                    candidates.push(sample.path.to_path(&dir));
+                    if let Some(name) = sample.path.file_name() {
+                        candidates.push(dir.join(name));
+                    }
+                }
+            }
+            SampleLocation::Library { .. }
+            | SampleLocation::SpecialLocation
+            | SampleLocation::Monolith => {}
+        }
+        candidates
+    }
+
+    /// The first candidate that exists on disk.
+    pub fn locate(&self, sample: &SampleReference, instrument_dir: &Path) -> Option<PathBuf> {
+        self.candidates(sample, instrument_dir)
+            .into_iter()
+            .find(|path| path.is_file())
+    }
+
+    pub(crate) fn location(&self, path: &BFileName) -> SampleLocation {
+        if let Some(container) = path.library() {
+            return SampleLocation::Library {
+                container: container.to_string(),
+            };
+        }
+
+        match path.segments.first() {
+            Some(BFileNameSegment::SpecialLocation) => return SampleLocation::SpecialLocation,
+            Some(BFileNameSegment::Drive(_)) => return SampleLocation::Absolute,
+            _ => {}
+        }
+
+        if path
+            .file_name()
+            .is_some_and(|name| self.container_files.contains(&name.to_lowercase()))
+        {
+            return SampleLocation::Monolith;
+        }
+
+        SampleLocation::Relative
+    }
+}
+
+impl std::convert::TryFrom<&KontaktPreset> for SampleResolver {
+    type Error = Error;
+
+    fn try_from(preset: &KontaktPreset) -> Result<Self, Self::Error> {
+        let filetable = match preset {
+            KontaktPreset::KontaktV42(p) => p.filetable.clone().try_into()?,
+            KontaktPreset::Kon5(p) => p.filetable.clone(),
+            KontaktPreset::Kon5PreK51(p) => p.filetable.clone().try_into()?,
+            KontaktPreset::Kon6(p) => p.filetable.clone(),
+            KontaktPreset::Kon7(p) => p.filetable.clone(),
+            KontaktPreset::NKM(p) => p.filetable.clone(),
+            KontaktPreset::KontaktV1(_) | KontaktPreset::KontaktV2(_) => {
+                return Err(Error::Static(
+                    "Kontakt 1 and 2 XML presets are not supported",
+                ))
+            }
+            KontaktPreset::Unsupported(chunks) => chunks
+                .filename_tables()?
+                .ok_or(Error::Static("Could not find filetable"))?,
+        };
+        Ok(Self::new(filetable))
+    }
+}
+
+impl std::convert::TryFrom<&NIFile> for SampleResolver {
+    type Error = Error;
+
+    fn try_from(file: &NIFile) -> Result<Self, Self::Error> {
+        let resolver = Self::try_from(&KontaktPreset::try_from(file)?)?;
+        Ok(match file {
+            NIFile::Monolith(container) => resolver.with_container(container),
+            _ => resolver,
+        })
+    }
+}
+
+fn file_name(path: &str) -> Option<&str> {
+    path.rsplit(['/', '\\'])
+        .next()
+        .filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::Cursor};
+
+    use super::*;
+    use crate::{
+        file_container::FileContainerItem,
+        kontakt::{objects::FileNameListPreK51, Chunk},
+    };
+
+    fn read_table(path: &str) -> Result<FNTableImpl, Error> {
+        let chunk = Chunk::read(File::open(path)?)?;
+        match chunk.id {
+            0x3D => FileNameListPreK51::read(Cursor::new(chunk.data))?.try_into(),
+            _ => FNTableImpl::try_from(&chunk),
+        }
+    }
+
+    #[test]
+    fn test_sample_locations() -> Result<(), Error> {
+        let resolver = SampleResolver::new(read_table(
+            "tests/data/Objects/Kontakt/0x3D-FNTablePreK51/FNTablePreK51-003.kon",
+        )?);
+        let sample = resolver.resolve(0).unwrap();
+        assert_eq!(sample.path.to_string(), "../Samples/stringodyne.ncw");
+        assert_eq!(sample.location, SampleLocation::Relative);
+        assert_eq!(sample.timestamp.unwrap().unix_timestamp(), 1374180912);
+        assert_eq!(
+            resolver.candidates(&sample, Path::new("/lib/Instruments"))[0],
+            PathBuf::from("/lib/Samples/stringodyne.ncw")
+        );
+
+        let mut table =
+            read_table("tests/data/Objects/Kontakt/0x4B-FNTableImpl/FNTableImpl-007.kon")?;
+        table.sample_filetable = table.other_filetable.clone();
+        let resolver = SampleResolver::new(table);
+        assert_eq!(
+            resolver.resolve(1).unwrap().location,
+            SampleLocation::Library {
+                container: "K4IR.nkx".into()
+            }
+        );
+
+        let table = read_table("tests/data/Objects/Kontakt/0x4B-FNTableImpl/FNTableImpl-000.kon")?;
+        let resolver = SampleResolver::new(table);
+        assert_eq!(
+            resolver.instrument_path().unwrap().to_windows(),
+            r"C:\Users\Administrator\Desktop\TESTINSTRUMENTBEST.nki"
+        );
+        assert_eq!(
+            resolver.search_paths()[0].to_string(),
+            "C:/Users/Administrator/Desktop"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_monolith() -> Result<(), Error> {
+        let file = NIFile::read(File::open(
+            "tests/data/Containers/FileContainer/files/000-default.nki",
+        )?)?;
+        let resolver = SampleResolver::try_from(&file)?;
+        assert!(resolver.container_files.contains("patch.nki"));
+
+        let container = NIFileContainer {
+            file_section_offset: 0,
+            items: vec![FileContainerItem {
+                index: 1,
+                filename: "Samples/Beep.wav".into(),
+                file_start_offset: 0,
+                file_size: 0,
+            }],
+            preset_data: None,
+        };
+        let resolver = resolver.with_container(&container);
+        assert_eq!(
+            resolver.location(&BFileName::from("Samples/beep.wav")),
+            SampleLocation::Monolith
+        );
+        assert_eq!(
+            resolver.location(&BFileName::from("Samples/other.wav")),
+            SampleLocation::Relative
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_resolver_zones() -> Result<(), Error> {
+        let file = NIFile::read(File::open(
+            "tests/data/Containers/NKS/KontaktV42/KontaktV42-000.nki",
+        )?)?;
+        let instrument = KontaktInstrument::try_from(&file)?;
+        let resolver = SampleResolver::try_from(&file)?;
+
+        let zones = resolver.zones(&instrument);
+        assert_eq!(zones.len(), 414);
+        assert!(zones.iter().all(Option::is_some));
+
+        let samples = resolver.samples(&instrument);
+        assert_eq!(samples.iter().map(|s| s.zones.len()).sum::<usize>(), 414);
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use crate::{read_bytes::ReadBytesExt, Error};
 use super::XMLDocument;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KontaktV1 {
     pub preset: XMLDocument,
 }
@@ -4,6 +4,7 @@ use super::XMLDocument;
 
 /// Basically adds monolith (resource) support to Kontakt1
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KontaktV2 {
     pub preset: XMLDocument,
 }
@@ -17,6 +17,7 @@ use crate::{
 // 0x3d FileNameListPreK1
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KontaktV42 {
     pub program: Program,
     pub filetable: FileNameListPreK51,
@@ -10,17 +10,18 @@
 //  0x33    GroupList
 //  0x34    ZoneList
 // 0x47 SaveSettings
-// 0x4B FNTableImpl
+// 0x4B FNTableImpl (0x3D FileNameListPreK51 before 5.1)
 
 use crate::{
     kontakt::{
-        objects::{FNTableImpl, Program},
+        objects::{FNTableImpl, FileNameListPreK51, Program},
         KontaktChunks,
     },
     Error,
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Kon5 {
     pub program: Program,
     pub filetable: FNTableImpl,
@@ -42,3 +43,28 @@ impl std::convert::TryFrom<KontaktChunks> for Kon5 {
         })
     }
 }
+
+/// Kontakt 5.0 presets share the Kon5 layout but still store the pre 5.1 filetable.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Kon5PreK51 {
+    pub program: Program,
+    pub filetable: FileNameListPreK51,
+}
+
+impl std::convert::TryFrom<KontaktChunks> for Kon5PreK51 {
+    type Error = Error;
+
+    fn try_from(chunks: KontaktChunks) -> Result<Self, Self::Error> {
+        Ok(Self {
+            program: chunks
+                .first()
+                .ok_or(Error::Static("Could not find Program"))?
+                .try_into()?,
+            filetable: chunks
+                .last()
+                .ok_or(Error::Static("Could not find FileNameListPreK51"))?
+                .try_into()?,
+        })
+    }
+}
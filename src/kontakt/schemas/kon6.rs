@@ -21,6 +21,7 @@ use crate::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Kon6 {
     pub program: Program,
     pub filetable: FNTableImpl,
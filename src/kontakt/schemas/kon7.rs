@@ -16,17 +16,69 @@
 use crate::{
     kontakt::{
         chunk_set::KontaktChunks,
-        objects::{FNTableImpl, Program},
+        objects::{BParamArrayBParFX8, FNTableImpl, InsertBus, Program, SaveSettings},
     },
     Error,
 };
 
+const FX_CHAIN_ID: u16 = 0x3A;
+const QUICK_BROWSE_DATA_ID: u16 = 0x4E;
+
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Kon7 {
     pub program: Program,
+    pub save_settings: Option<SaveSettings>,
     pub filetable: FNTableImpl,
 }
 
+/// Program level effects and buses.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalFlow {
+    pub insert_fx: BParamArrayBParFX8,
+    pub send_fx: BParamArrayBParFX8,
+    /// 16 instrument buses, each with its own effect chain.
+    pub insert_buses: Vec<InsertBus>,
+    /// Effect chain stored after QuickBrowseData, new in Kontakt 7.
+    pub u_fx: Option<BParamArrayBParFX8>,
+}
+
+impl SignalFlow {
+    /// Kontakt writes the program children in a fixed order: insert FX and send
+    /// FX are always the first two children, the optional third chain directly
+    /// follows QuickBrowseData (0x4E).
+    pub fn read(program: &Program) -> Result<Self, Error> {
+        let children = program.children();
+
+        let chain_at = |index: usize, name: &'static str| match children.get(index) {
+            Some(chunk) if chunk.id == FX_CHAIN_ID => BParamArrayBParFX8::try_from(chunk),
+            _ => Err(Error::Static(name)),
+        };
+
+        let u_fx = children
+            .iter()
+            .position(|c| c.id == QUICK_BROWSE_DATA_ID)
+            .and_then(|i| children.get(i + 1))
+            .filter(|c| c.id == FX_CHAIN_ID)
+            .map(BParamArrayBParFX8::try_from)
+            .transpose()?;
+
+        Ok(Self {
+            insert_fx: chain_at(0, "Could not find insert FX")?,
+            send_fx: chain_at(1, "Could not find send FX")?,
+            insert_buses: program.insert_buses()?,
+            u_fx,
+        })
+    }
+}
+
+impl Kon7 {
+    pub fn signal_flow(&self) -> Result<SignalFlow, Error> {
+        SignalFlow::read(&self.program)
+    }
+}
+
 impl std::convert::TryFrom<KontaktChunks> for Kon7 {
     type Error = Error;
 
@@ -36,6 +88,10 @@ impl std::convert::TryFrom<KontaktChunks> for Kon7 {
                 .first()
                 .ok_or(Error::Static("Could not find Program".into()))?
                 .try_into()?,
+            save_settings: chunks
+                .find_first(0x47)
+                .map(SaveSettings::try_from)
+                .transpose()?,
             filetable: chunks
                 .last()
                 .ok_or(Error::Static("Could not find FNTableImpl".into()))?
@@ -43,3 +99,48 @@ impl std::convert::TryFrom<KontaktChunks> for Kon7 {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kontakt::Chunk;
+    use std::fs::File;
+
+    fn read_program(path: &str) -> Result<Program, Error> {
+        Program::try_from(&Chunk::read(File::open(path)?)?)
+    }
+
+    #[test]
+    fn test_signal_flow_va5() -> Result<(), Error> {
+        let program =
+            read_program("tests/data/Objects/Kontakt/0x28-Program/ProgramVA5/ProgramVA5-000.kon")?;
+        let flow = SignalFlow::read(&program)?;
+
+        let ids = |chain: &BParamArrayBParFX8| -> Result<Vec<u16>, Error> {
+            Ok(chain
+                .fx_slots()?
+                .iter()
+                .filter_map(|fx| fx.effect().map(|c| c.id))
+                .collect())
+        };
+        assert_eq!(ids(&flow.insert_fx)?, vec![0x17]);
+        assert_eq!(ids(&flow.send_fx)?, vec![0x10]);
+        assert_eq!(flow.insert_buses.len(), 16);
+        assert!(flow.u_fx.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_signal_flow_vaf() -> Result<(), Error> {
+        let program = read_program(
+            "tests/data/Objects/Kontakt/0x28-Program/ProgramVAF/ProgramVAF-001-insert-fx.kon",
+        )?;
+        let flow = SignalFlow::read(&program)?;
+
+        assert_eq!(flow.insert_fx.len(), 8);
+        assert_eq!(flow.send_fx.len(), 0);
+        assert_eq!(flow.insert_buses.len(), 16);
+        assert_eq!(flow.u_fx.map(|fx| fx.len()), Some(0));
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KontaktMulti {
     pub bank: Bank,
     pub filetable: FNTableImpl,
@@ -3,20 +3,27 @@ use crate::{
         chunk_set::KontaktChunks,
         objects::{NKIAppVersion, PatchType},
     },
+    nis::schema::{NISObject, Repository},
     read_bytes::ReadBytesExt,
-    Error,
+    Error, NIFile,
 };
 
 use super::{
-    kon5::Kon5, kon6::Kon6, kon7::Kon7, multi::KontaktMulti, KontaktV1, KontaktV2, KontaktV42,
+    kon5::{Kon5, Kon5PreK51},
+    kon6::Kon6,
+    kon7::Kon7,
+    multi::KontaktMulti,
+    KontaktV1, KontaktV2, KontaktV42,
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KontaktPreset {
     KontaktV1(KontaktV1),
     KontaktV2(KontaktV2),
     KontaktV42(KontaktV42),
     Kon5(Kon5),
+    Kon5PreK51(Kon5PreK51),
     Kon6(Kon6),
     Kon7(Kon7),
     NKM(KontaktMulti),
@@ -36,6 +43,10 @@ impl KontaktPreset {
         Ok(match patch_type {
             PatchType::NKI => match id {
                 "Kon4" => Self::KontaktV42(chunks.try_into()?),
+                // Kontakt 5.0 still stores the pre 5.1 filetable
+                "Kon5" if chunks.last().is_some_and(|c| c.id == 0x3D) => {
+                    Self::Kon5PreK51(chunks.try_into()?)
+                }
                 "Kon5" => Self::Kon5(chunks.try_into()?),
                 "Kon6" => Self::Kon6(chunks.try_into()?),
                 "Kon7" => Self::Kon7(chunks.try_into()?),
@@ -71,3 +82,26 @@ impl KontaktPreset {
         // })
     }
 }
+
+impl std::convert::TryFrom<&NIFile> for KontaktPreset {
+    type Error = Error;
+
+    fn try_from(file: &NIFile) -> Result<Self, Self::Error> {
+        match file {
+            NIFile::NKSContainer(nks) => nks.preset(),
+            NIFile::NISoundContainer(container) => {
+                match Repository::from(container.clone()).infer_schema() {
+                    NISObject::BNISoundPreset(preset) => {
+                        if preset.encryption_item()?.is_encrypted {
+                            return Err(Error::Static("Preset is encrypted"));
+                        }
+                        preset.patch()?.preset()
+                    }
+                    _ => Err(Error::Static("NISound document is not a Kontakt preset")),
+                }
+            }
+            NIFile::Monolith(container) => Self::try_from(&container.preset()?),
+            _ => Err(Error::Static("File does not contain a Kontakt preset")),
+        }
+    }
+}
@@ -1,10 +1,22 @@
 use flate2::bufread::ZlibDecoder;
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::io::Read;
 
-use crate::nks::error::NKSError;
+use crate::{
+    kontakt::{
+        objects::{
+            AutomationParams, BFileName, BFileNameSegment, EnvelopeAHDSRParams, Loop, SourceMode,
+        },
+        InstrumentGroup, InstrumentMetadata, InstrumentZone, KontaktInstrument, Modulator,
+        ModulatorSource,
+    },
+    nks::error::NKSError,
+    Error, NIFileError,
+};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XMLDocument(String);
 
 impl XMLDocument {
@@ -23,6 +35,178 @@ impl XMLDocument {
             String::from_utf8(decompressed).expect("convert xml to string"),
         ))
     }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub(crate) fn parse(&self) -> Result<roxmltree::Document<'_>, Error> {
+        // some presets are null terminated
+        roxmltree::Document::parse(self.0.trim_end_matches('\0'))
+            .map_err(|e| NIFileError::Generic(format!("Invalid Kontakt XML: {e}")))
+    }
+
+    /// Host automation and MIDI controller assignments of the first program.
+    pub fn automation(&self) -> Result<Vec<AutomationParams>, Error> {
+        let doc = self.parse()?;
+        let Some(program) = first_program(&doc) else {
+            return Ok(Vec::new());
+        };
+
+        let mut automation = Vec::new();
+        for node in program
+            .children()
+            .filter(|n| n.has_tag_name("Automation"))
+            .flat_map(|n| n.children())
+            .filter(|n| n.has_tag_name("AutObj"))
+        {
+            let v = values(node);
+            automation.push(AutomationParams {
+                address_mode: v.get("adressMode").unwrap_or(&"").to_string(),
+                soft_take_over: parse_bool(&v, "softTakeOver"),
+                program_send_fx_is_in_insert_chain: parse_bool(&v, "programSendFXIsInInsertChain"),
+                address: parse_num(&v, "adress")?,
+                par_tag: v.get("parTag").unwrap_or(&"").to_string(),
+                obj_idx: parse_num(&v, "objIdx")?,
+                range_from: parse_num(&v, "rangeFrom")?,
+                range_to: parse_num(&v, "rangeTo")?,
+            });
+        }
+        Ok(automation)
+    }
+
+    /// Groups, zones and modulators of the first program.
+    ///
+    /// Kontakt 1 stores pan as 0.0 - 1.0, Kontakt 2 stores the group and
+    /// program tune in semitones. Both are converted to the conventions of
+    /// later presets, volumes are kept as stored. Effects, scripts and start
+    /// criteria are not read.
+    pub fn instrument(&self) -> Result<KontaktInstrument, Error> {
+        let doc = self.parse()?;
+        let root = doc.root_element();
+        if root.has_tag_name("NiSS_Bank")
+            || root
+                .attribute("type")
+                .is_some_and(|t| t != "single_program")
+        {
+            return Err(Error::Static("Multis contain more than one instrument"));
+        }
+        let program = first_program(&doc).ok_or(Error::Static("Could not find Program"))?;
+        let kontakt1 = program.has_tag_name("NiSS_Program");
+
+        let pan = |v: &HashMap<&str, &str>, name| -> Result<f32, Error> {
+            let pan: f32 = parse_num(v, name)?;
+            Ok(if kontakt1 { pan * 2.0 - 1.0 } else { pan })
+        };
+        let tune = |v: &HashMap<&str, &str>, name| -> Result<f32, Error> {
+            let tune: f32 = parse_num(v, name)?;
+            Ok(if kontakt1 {
+                tune
+            } else {
+                2f32.powf(tune / 12.0)
+            })
+        };
+
+        let v = child_values(program, "Parameters");
+        let metadata = InstrumentMetadata {
+            author: v.get("instrumentAuthor").unwrap_or(&"").to_string(),
+            url: v.get("instrumentURL").unwrap_or(&"").to_string(),
+            credits: v.get("instrumentCredits").unwrap_or(&"").to_string(),
+            categories: [
+                parse_num(&v, "instrumentCat1")?,
+                parse_num(&v, "instrumentCat2")?,
+                parse_num(&v, "instrumentCat3")?,
+            ],
+            library_id: parse_num(&v, "libraryID")?,
+            volume: parse_num(&v, if kontakt1 { "masterVolume" } else { "volume" })?,
+            pan: pan(&v, if kontakt1 { "masterPan" } else { "pan" })?,
+            tune: tune(&v, if kontakt1 { "masterTune" } else { "tune" })?,
+            wallpaper: match v.get("wallpaperFile") {
+                Some(path) if !path.is_empty() => Some(parse_file_ex2(path)?),
+                _ => None,
+            },
+        };
+
+        let mut groups = Vec::new();
+        for group in child_elements(program, "Groups") {
+            let v = child_values(group, "Parameters");
+            let modulators = modulators(group)?;
+            let amp_envelope = modulators.iter().find_map(|m| match &m.source_params {
+                ModulatorSource::EnvelopeAHDSR(env) if m.target == "volume" && !m.bypass => {
+                    Some(env.clone())
+                }
+                _ => None,
+            });
+            let streaming = child_values(group, "PlaybackMode").get("type") == Some(&"streaming");
+
+            groups.push(InstrumentGroup {
+                name: group.attribute("name").unwrap_or_default().to_string(),
+                volume: parse_num(&v, "volume")?,
+                pan: pan(&v, "pan")?,
+                tune: tune(&v, "tune")?,
+                muted: parse_bool(&v, "muted"),
+                release_trigger: parse_bool(&v, "releaseTrigger"),
+                start_criteria: Vec::new(),
+                source_mode: match streaming {
+                    true => SourceMode::DFD,
+                    false => SourceMode::Sampler,
+                },
+                effects: Vec::new(),
+                modulators,
+                amp_envelope,
+            });
+        }
+
+        let mut zones = Vec::new();
+        for zone in child_elements(program, "Zones") {
+            let v = child_values(zone, "Parameters");
+            let sample = child_values(zone, "Sample");
+            let sample = match (sample.get("file_ex2"), sample.get("file")) {
+                (Some(path), _) => Some(parse_file_ex2(path)?),
+                (None, Some(path)) => Some(BFileName::from(*path)),
+                (None, None) => None,
+            };
+
+            zones.push(InstrumentZone {
+                group: zone
+                    .attribute("groupIdx")
+                    .unwrap_or("0")
+                    .parse()
+                    .map_err(|_| {
+                        NIFileError::Generic("Invalid Kontakt XML value for groupIdx".into())
+                    })?,
+                sample,
+                filename_id: -1,
+                root_key: parse_num(&v, "rootKey")?,
+                low_key: parse_num(&v, "lowKey")?,
+                high_key: parse_num(&v, "highKey")?,
+                low_velocity: parse_num(&v, "lowVelocity")?,
+                high_velocity: parse_num(&v, "highVelocity")?,
+                volume: parse_num(&v, "zoneVolume")?,
+                pan: pan(&v, "zonePan")?,
+                tune: parse_num(&v, "zoneTune")?,
+                sample_start: parse_num(&v, "sampleStart")?,
+                sample_end: parse_num(&v, "sampleEnd")?,
+                sample_rate: 0,
+                num_channels: 0,
+                num_frames: 0,
+                loops: child_elements(zone, "Loops")
+                    .into_iter()
+                    .map(|node| parse_loop(&values(node)))
+                    .collect::<Result<Vec<_>, Error>>()?,
+            });
+        }
+
+        Ok(KontaktInstrument {
+            name: program.attribute("name").unwrap_or_default().to_string(),
+            metadata,
+            groups,
+            zones,
+            scripts: Vec::new(),
+            insert_effects: Vec::new(),
+            send_effects: Vec::new(),
+        })
+    }
 }
 
 impl Display for XMLDocument {
@@ -30,3 +214,238 @@ impl Display for XMLDocument {
         f.write_fmt(format_args!("{}", self.0))
     }
 }
+
+/// The root element of Kontakt 1 presets, or the first `K2_Program`.
+pub(crate) fn first_program<'a, 'input>(
+    doc: &'a roxmltree::Document<'input>,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    doc.descendants()
+        .find(|n| n.has_tag_name("NiSS_Program") || n.has_tag_name("K2_Program"))
+}
+
+/// The values of the first child element with the given tag.
+fn child_values<'a>(node: roxmltree::Node<'a, '_>, tag: &str) -> HashMap<&'a str, &'a str> {
+    node.children()
+        .find(|n| n.has_tag_name(tag))
+        .map(values)
+        .unwrap_or_default()
+}
+
+/// Child elements other than values of the first child with the given tag,
+/// e.g. the groups of `<Groups>`.
+fn child_elements<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    tag: &str,
+) -> Vec<roxmltree::Node<'a, 'input>> {
+    node.children()
+        .find(|n| n.has_tag_name(tag))
+        .map(|n| {
+            n.children()
+                .filter(|n| n.is_element() && !n.has_tag_name("V"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Internal and external modulators of a group, one per target.
+fn modulators(group: roxmltree::Node) -> Result<Vec<Modulator>, Error> {
+    let mut modulators = Vec::new();
+    for tag in ["IntModulators", "ExtModulators"] {
+        for m in child_elements(group, tag) {
+            let v = values(m);
+            let envelope = m.children().find(|n| n.has_tag_name("Envelope"));
+
+            let source_params = match (envelope, v.get("source")) {
+                (Some(env), _) if env.attribute("type") == Some("ahdsr") => {
+                    let env = values(env);
+                    ModulatorSource::EnvelopeAHDSR(EnvelopeAHDSRParams {
+                        attack_curve: parse_num(&env, "atkCurving")?,
+                        attack: parse_num(&env, "attack")?,
+                        hold: parse_num(&env, "hold")?,
+                        decay: parse_num(&env, "decay")?,
+                        release: parse_num(&env, "release")?,
+                        sustain: parse_num(&env, "sustain")?,
+                        note_off_less_mode: parse_bool(&env, "noteOffLessMode"),
+                        u_a: Vec::new(),
+                    })
+                }
+                // see ExternalModParams::source
+                (_, Some(&"pitchBend")) => ModulatorSource::External {
+                    source: 1,
+                    cc_number: None,
+                },
+                (_, Some(&"midiCC")) => ModulatorSource::External {
+                    source: 4,
+                    cc_number: Some(parse_num(&v, "ccNumber")?),
+                },
+                (_, Some(&"velocity")) => ModulatorSource::External {
+                    source: 6,
+                    cc_number: None,
+                },
+                _ => ModulatorSource::Unknown,
+            };
+            let source = match (v.get("name"), v.get("source"), &source_params) {
+                (Some(name), _, _) => name.to_string(),
+                (None, Some(source), _) => source.to_string(),
+                (None, None, ModulatorSource::EnvelopeAHDSR(_)) => "ENV_AHDSR".to_string(),
+                (None, None, _) => String::new(),
+            };
+
+            // Kontakt 1 stores a single target on the modulator itself
+            let targets: Vec<_> = match m.children().any(|n| n.has_tag_name("Targets")) {
+                true => child_elements(m, "Targets")
+                    .into_iter()
+                    .map(values)
+                    .collect(),
+                false => vec![v.clone()],
+            };
+            for target in targets {
+                modulators.push(Modulator {
+                    source: source.clone(),
+                    source_params: source_params.clone(),
+                    target: target.get("target").unwrap_or(&"").to_string(),
+                    intensity: parse_num(&target, "intensity")?,
+                    bypass: parse_bool(&v, "bypass"),
+                });
+            }
+        }
+    }
+    Ok(modulators)
+}
+
+fn parse_loop(v: &HashMap<&str, &str>) -> Result<Loop, Error> {
+    Ok(Loop {
+        // until_release is not found in binary presets, see Loop::mode
+        mode: match v.get("mode") {
+            Some(&"until_end") => 1,
+            Some(&"until_release") => 2,
+            Some(mode) => {
+                return Err(NIFileError::Generic(format!(
+                    "Unsupported Kontakt XML loop mode: {mode}"
+                )))
+            }
+            None => 0,
+        },
+        loop_start: parse_num(v, "loopStart")?,
+        loop_length: parse_num(v, "loopLength")?,
+        loop_count: parse_num(v, "loopCount")?,
+        alternating_loop: parse_bool(v, "alternatingLoop"),
+        loop_tuning: parse_num(v, "loopTuning")?,
+        x_fade_length: parse_num(v, "xfadeLength")?,
+    })
+}
+
+/// Kontakt 2 sample paths, e.g. "@bd007SamplesF00000010000sample.wav".
+///
+/// - `@`: start of the path, relative to the preset
+/// - `b`: parent directory
+/// - `d` + 3 digit length: directory
+/// - `F` + 5 characters (found "00000", "-0001") + 3 digit length + "000": filename
+pub(crate) fn parse_file_ex2(path: &str) -> Result<BFileName, Error> {
+    let invalid = || NIFileError::Generic(format!("Invalid Kontakt XML sample path: {path}"));
+    let mut rest = path.strip_prefix('@').ok_or_else(invalid)?;
+
+    let mut segments = Vec::new();
+    while let Some(tag) = rest.chars().next() {
+        let (skip, len_start) = match tag {
+            'b' => {
+                segments.push(BFileNameSegment::Parent);
+                rest = &rest[1..];
+                continue;
+            }
+            'd' => (4, 1),
+            'F' => (12, 6),
+            _ => return Err(invalid()),
+        };
+        let len: usize = rest
+            .get(len_start..len_start + 3)
+            .and_then(|len| len.parse().ok())
+            .ok_or_else(invalid)?;
+        // lengths count characters, not bytes
+        let name: String = rest
+            .get(skip..)
+            .ok_or_else(invalid)?
+            .chars()
+            .take(len)
+            .collect();
+        if name.chars().count() != len {
+            return Err(invalid());
+        }
+        rest = &rest[skip + name.len()..];
+        segments.push(match tag {
+            'd' => BFileNameSegment::Directory(name),
+            _ => BFileNameSegment::FileName(name),
+        });
+    }
+    Ok(BFileName {
+        segments,
+        unset: false,
+    })
+}
+
+/// The `<V name=".." value=".."/>` children of a node.
+pub(crate) fn values<'a>(node: roxmltree::Node<'a, '_>) -> HashMap<&'a str, &'a str> {
+    node.children()
+        .filter(|n| n.has_tag_name("V"))
+        .filter_map(|n| Some((n.attribute("name")?, n.attribute("value")?)))
+        .collect()
+}
+
+/// "yes" or "no", missing values are false.
+pub(crate) fn parse_bool(values: &HashMap<&str, &str>, name: &str) -> bool {
+    values.get(name).is_some_and(|v| *v == "yes")
+}
+
+/// A number value, missing values are the default.
+pub(crate) fn parse_num<T: std::str::FromStr + Default>(
+    values: &HashMap<&str, &str>,
+    name: &str,
+) -> Result<T, Error> {
+    match values.get(name) {
+        Some(v) => v.parse().map_err(|_| {
+            NIFileError::Generic(format!("Invalid Kontakt XML value for {name}: {v}"))
+        }),
+        None => Ok(T::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::*;
+    use crate::{kontakt::schemas::KontaktPreset, Error, NIFile};
+
+    #[test]
+    fn test_parse_file_ex2() -> Result<(), Error> {
+        assert_eq!(
+            parse_file_ex2("@bd007SamplesF00000010000sample.wav")?.to_string(),
+            "../Samples/sample.wav"
+        );
+        assert_eq!(
+            parse_file_ex2("@bbd005KicksF-000101100085_Kick.wav")?.to_string(),
+            "../../Kicks/85_Kick.wav"
+        );
+        assert!(parse_file_ex2("@d010Samples").is_err());
+        assert!(parse_file_ex2("Samples/sample.wav").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_xml_automation() -> Result<(), Error> {
+        let file = NIFile::read(File::open(
+            "tests/data/Containers/NKS/KontaktV2/kokiriko_all_fx.nki",
+        )?)?;
+        let KontaktPreset::KontaktV2(preset) = KontaktPreset::try_from(&file)? else {
+            panic!("expected a Kontakt 2 preset");
+        };
+
+        let automation = preset.preset.automation()?;
+        assert_eq!(automation.len(), 1);
+        assert_eq!(automation[0].address_mode, "midi_ctrl");
+        assert_eq!(automation[0].address, 1);
+        assert_eq!(automation[0].par_tag, "pts_script_knob_0_1");
+        assert_eq!(automation[0].range_to, 1.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,298 @@
+use std::path::Path;
+
+use crate::Error;
+
+use super::{KontaktChunks, KontaktInstrument};
+
+/// A KSP script slot of an instrument.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScriptSlot {
+    /// Index of the slot, starting at 0. This is the stored position, see
+    /// [`Program::scripts`](super::objects::Program::scripts).
+    pub slot: usize,
+    /// The title shown in the script editor.
+    pub title: Option<String>,
+    /// The script source, `None` if the slot only links to a text file.
+    pub text: Option<String>,
+    pub bypass: bool,
+    /// The script is locked with a password (`password_hash` is set).
+    pub password_protected: bool,
+    /// External file the source is loaded from.
+    pub textfile_name: Option<String>,
+}
+
+impl ScriptSlot {
+    /// File name for the extracted script, e.g. `1 - Legato.ksp`. Characters
+    /// which are invalid in file names are replaced.
+    pub fn file_name(&self) -> String {
+        let title = self
+            .title
+            .as_deref()
+            .filter(|t| !t.trim().is_empty())
+            .unwrap_or("Script");
+        let title: String = title
+            .trim()
+            .chars()
+            .map(|c| match c {
+                '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+                c if c.is_control() => '_',
+                c => c,
+            })
+            .collect();
+        format!("{} - {title}.ksp", self.slot + 1)
+    }
+}
+
+impl KontaktInstrument {
+    /// Script slots with a source or a linked text file. Empty slots are
+    /// skipped.
+    pub fn script_slots(&self) -> Vec<ScriptSlot> {
+        self.scripts
+            .iter()
+            .enumerate()
+            .map(|(slot, script)| ScriptSlot {
+                slot,
+                title: script.description.clone(),
+                text: script.text.clone().filter(|t| !t.is_empty()),
+                bypass: script.bypass,
+                password_protected: !script.password_hash.is_empty(),
+                textfile_name: script.textfile_name.clone().filter(|t| !t.is_empty()),
+            })
+            .filter(|slot| slot.text.is_some() || slot.textfile_name.is_some())
+            .collect()
+    }
+
+    /// Writes the source of each script slot to a `.ksp` file in `dir`, see
+    /// [`ScriptSlot::file_name`]. Slots that only link to a text file are
+    /// returned without writing a file.
+    ///
+    /// ```no_run
+    /// use ni_file::{kontakt::KontaktInstrument, NIFile};
+    ///
+    /// let file = NIFile::read(std::fs::File::open("instrument.nki")?)?;
+    /// let instrument = KontaktInstrument::try_from(&file)?;
+    ///
+    /// for slot in instrument.extract_scripts(std::path::Path::new("scripts"))? {
+    ///     println!("{} protected: {}", slot.file_name(), slot.password_protected);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn extract_scripts(&self, dir: &Path) -> Result<Vec<ScriptSlot>, Error> {
+        let slots = self.script_slots();
+        if slots.iter().any(|slot| slot.text.is_some()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        for slot in &slots {
+            if let Some(text) = &slot.text {
+                std::fs::write(dir.join(slot.file_name()), text)?;
+            }
+        }
+        Ok(slots)
+    }
+}
+
+impl KontaktChunks {
+    /// Replaces the source of a script slot of the program, see
+    /// [`Program::set_script`](super::objects::Program::set_script).
+    ///
+    /// ```no_run
+    /// use std::{fs::File, io::Cursor};
+    /// use ni_file::{kontakt::KontaktChunks, NIFile};
+    ///
+    /// let mut file = NIFile::read(File::open("instrument.nki")?)?;
+    /// let mut chunks = KontaktChunks::read(Cursor::new(file.inner_preset()?))?;
+    /// chunks.set_script(0, &std::fs::read_to_string("library.ksp")?, None)?;
+    ///
+    /// let mut data = Vec::new();
+    /// chunks.write(&mut data)?;
+    /// file.set_inner_preset(&data)?;
+    /// file.write(File::create("instrument.nki")?)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_script(
+        &mut self,
+        slot: usize,
+        text: &str,
+        title: Option<&str>,
+    ) -> Result<(), Error> {
+        let mut program = self
+            .program()
+            .ok_or(Error::Static("Could not find Program"))??;
+        program.set_script(slot, text, title)?;
+        self.set_program(&program)
+    }
+
+    /// Adds a script to the first empty slot of the program and returns the
+    /// slot.
+    pub fn add_script(&mut self, text: &str, title: Option<&str>) -> Result<usize, Error> {
+        let mut program = self
+            .program()
+            .ok_or(Error::Static("Could not find Program"))??;
+        let slot = program.add_script(text, title)?;
+        self.set_program(&program)?;
+        Ok(slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::{self, File},
+        io::Cursor,
+    };
+
+    use crate::NIFile;
+
+    use super::*;
+
+    #[test]
+    fn test_extract_scripts() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NIS/files/kontakt/5.8.1.43/5.8.1.43-ncw.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        let dir = std::env::temp_dir().join("ni-file-test-scripts");
+        let _ = fs::remove_dir_all(&dir);
+        let slots = instrument.extract_scripts(&dir)?;
+
+        assert_eq!(slots.len(), 1);
+        assert_eq!(slots[0].file_name(), "1 - EQ.ksp");
+        assert!(!slots[0].password_protected);
+        assert!(slots[0].textfile_name.is_none());
+
+        let text = fs::read_to_string(dir.join("1 - EQ.ksp"))?;
+        fs::remove_dir_all(&dir)?;
+        assert_eq!(Some(text), slots[0].text);
+        Ok(())
+    }
+
+    #[test]
+    fn test_script_slots_kontakt_v42() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NKS/KontaktV42/KontaktV42-000.nki")?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(file)?)?;
+
+        let slots = instrument.script_slots();
+        let titles: Vec<_> = slots.iter().map(|s| s.title.as_deref().unwrap()).collect();
+        assert_eq!(
+            titles,
+            [
+                "The Dulcitone",
+                "Tone Bars",
+                "Instrument",
+                "Delay Engine",
+                "Round Robin"
+            ]
+        );
+        assert!(slots[0].text.as_deref().unwrap().starts_with("\non init\n"));
+        assert!(slots[0].password_protected);
+        assert_eq!(instrument.scripts[0].password_hash.len(), 16);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_script_kontakt_v42() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NKS/KontaktV42/KontaktV42-000.nki")?;
+        let mut chunks = KontaktChunks::read(Cursor::new(NIFile::read(file)?.inner_preset()?))?;
+
+        // slots with persistent variables keep them
+        chunks.set_script(2, "on init\nend on", None)?;
+        let program = chunks.program().unwrap()?;
+        let scripts = program.scripts()?;
+        assert_eq!(
+            scripts[2].params()?.text.as_deref(),
+            Some("on init\nend on")
+        );
+        assert_eq!(
+            scripts[2].params()?.description.as_deref(),
+            Some("Instrument")
+        );
+        assert_eq!(
+            scripts[4].params()?.description.as_deref(),
+            Some("Round Robin")
+        );
+        assert!(String::from_utf8_lossy(&scripts[2].0.public_data).ends_with("$cabinet_reverb 6"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_script_nis() -> Result<(), Error> {
+        let path = "tests/data/Containers/NIS/files/kontakt/5.8.1.43/5.8.1.43-ncw.nki";
+        let mut file = NIFile::read(File::open(path)?)?;
+
+        let preset = file.inner_preset()?;
+        let mut chunks = KontaktChunks::read(Cursor::new(&preset))?;
+        let mut data = Vec::new();
+        chunks.write(&mut data)?;
+        assert_eq!(data, preset);
+
+        chunks.set_script(0, "on init\nend on", Some("Library"))?;
+        assert_eq!(chunks.add_script("on note\nend on", None)?, 1);
+
+        let mut data = Vec::new();
+        chunks.write(&mut data)?;
+        file.set_inner_preset(&data)?;
+
+        let mut written = Vec::new();
+        file.write(&mut written)?;
+        let instrument = KontaktInstrument::try_from(&NIFile::read(Cursor::new(written))?)?;
+
+        let slots = instrument.script_slots();
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].title.as_deref(), Some("Library"));
+        assert_eq!(slots[0].text.as_deref(), Some("on init\nend on"));
+        assert_eq!(slots[1].text.as_deref(), Some("on note\nend on"));
+        assert!(!instrument.scripts[0].touched_but_not_applied);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_preset_nks() -> Result<(), Error> {
+        let path = "tests/data/Containers/NKS/KontaktV42/4.2.4.5316-000.nki";
+        let mut file = NIFile::read(File::open(path)?)?;
+        let zones = KontaktInstrument::try_from(&file)?.zones.len();
+
+        assert!(KontaktInstrument::try_from(&file)?
+            .script_slots()
+            .is_empty());
+        let mut chunks = KontaktChunks::read(Cursor::new(file.inner_preset()?))?;
+        chunks.set_script(0, "on init\nend on", Some("Library"))?;
+        assert_eq!(chunks.add_script("on note\nend on", None)?, 1);
+
+        let mut data = Vec::new();
+        chunks.write(&mut data)?;
+        file.set_inner_preset(&data)?;
+
+        let mut written = Vec::new();
+        file.write(&mut written)?;
+        let file = NIFile::read(Cursor::new(written))?;
+        assert_eq!(file.inner_preset()?, data);
+
+        // Kontakt 4.2 stores scripts in the program, not as children
+        let program = chunks.program().unwrap()?;
+        assert!(program.children().iter().all(|c| c.id != 0x06));
+
+        let instrument = KontaktInstrument::try_from(&file)?;
+        assert_eq!(instrument.zones.len(), zones);
+        assert_eq!(instrument.scripts.len(), 5);
+
+        let slots = instrument.script_slots();
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].title.as_deref(), Some("Library"));
+        assert_eq!(slots[0].text.as_deref(), Some("on init\nend on"));
+        assert_eq!(slots[1].text.as_deref(), Some("on note\nend on"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_script_file_name() {
+        let slot = ScriptSlot {
+            slot: 4,
+            title: Some("A/B: Mix".into()),
+            text: None,
+            bypass: false,
+            password_protected: false,
+            textfile_name: None,
+        };
+        assert_eq!(slot.file_name(), "5 - A_B_ Mix.ksp");
+    }
+}
@@ -1,14 +1,19 @@
 use std::fmt::Debug;
 use std::io::Cursor;
 
-use crate::{read_bytes::ReadBytesExt, Error, NIFileError};
+use crate::{read_bytes::ReadBytesExt, write_bytes::WriteBytesExt, Error, NIFileError};
 
 use super::chunk::Chunk;
 
 #[doc = include_str!("../../doc/presets/Kontakt/StructuredObject.md")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructuredObject {
+    /// Unstructured objects only store public data.
+    pub is_structured: bool,
     pub version: u16,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub public_data: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub private_data: Vec<u8>,
     pub children: Vec<Chunk>,
 }
@@ -20,6 +25,7 @@ impl StructuredObject {
 
         if !is_data_structured {
             return Ok(Self {
+                is_structured: false,
                 public_data: reader.read_all()?,
                 version,
                 private_data: Vec::new(),
@@ -61,6 +67,7 @@ impl StructuredObject {
         }
 
         Ok(Self {
+            is_structured: true,
             private_data,
             version,
             public_data,
@@ -68,6 +75,29 @@ impl StructuredObject {
         })
     }
 
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_bool(self.is_structured)?;
+        writer.write_u16_le(self.version)?;
+
+        if !self.is_structured {
+            writer.write_bytes(&self.public_data)?;
+            return Ok(());
+        }
+
+        writer.write_u32_le(self.private_data.len() as u32)?;
+        writer.write_bytes(&self.private_data)?;
+        writer.write_u32_le(self.public_data.len() as u32)?;
+        writer.write_bytes(&self.public_data)?;
+
+        let mut children = Vec::new();
+        for chunk in &self.children {
+            chunk.write(&mut children)?;
+        }
+        writer.write_u32_le(children.len() as u32)?;
+        writer.write_bytes(&children)?;
+        Ok(())
+    }
+
     pub fn find_first(&self, id: u16) -> Option<&Chunk> {
         self.children.iter().find(|c| c.id == id)
     }
@@ -110,4 +140,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_structured_object_write() -> Result<(), Error> {
+        let file =
+            File::open("tests/data/Objects/Kontakt/0x28-Program/ProgramV80/ProgramV80-000.kon")?;
+        let chunk = Chunk::read(file)?;
+        let obj = StructuredObject::try_from(&chunk)?;
+
+        let mut written = Vec::new();
+        obj.write(&mut written)?;
+        assert_eq!(written, chunk.data);
+        Ok(())
+    }
 }
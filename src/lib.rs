@@ -23,5 +23,8 @@ pub mod kontakt;
 mod byte_reader; // for reading bytestreams
 mod detect; // detect filetype
 mod read_bytes; // for reading bytestreams
+#[cfg(feature = "serde")]
+mod serde_base64; // byte blobs as base64 strings
 mod string_reader;
 mod utils; // various utils for logging etc
+mod write_bytes; // for writing bytestreams
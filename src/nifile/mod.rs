@@ -1,18 +1,26 @@
 use crate::{
-    detect::NIFileType, file_container::NIFileContainer, nis::ItemContainer,
-    nks::container::NKSContainer, read_bytes::*, Error,
+    detect::NIFileType,
+    file_container::NIFileContainer,
+    nis::{schema::BNISoundPreset, ItemContainer, ItemType, SoundInfo, SoundInfoItem},
+    nkr::NKRContainer,
+    nks::container::NKSContainer,
+    read_bytes::*,
+    write_bytes::WriteBytesExt,
+    Error,
 };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NIFile {
     NKSContainer(NKSContainer),
     NISoundContainer(ItemContainer),
     Monolith(NIFileContainer),
-    KontaktResource,
+    KontaktResource(NKRContainer),
     NICompressedWave,
     NICache,
     FM8Preset,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NIPreset {
     KontaktInstrument,
 }
@@ -30,7 +38,7 @@ impl NIFile {
             NIFileType::NKSContainer(_) | NIFileType::KontaktMultiV1 => {
                 NIFile::NKSContainer(NKSContainer::read(reader)?)
             }
-            NIFileType::KontaktResource => NIFile::KontaktResource,
+            NIFileType::KontaktResource => NIFile::KontaktResource(NKRContainer::read(reader)?),
             NIFileType::KontaktCache => todo!(),
             NIFileType::NKSArchive => todo!(),
             NIFileType::NICache => NIFile::NICache,
@@ -43,9 +51,59 @@ impl NIFile {
     /// Extract raw preset data from this container (if applicable).
     pub fn inner_preset(&self) -> Result<Vec<u8>, Error> {
         match self {
-            Self::NKSContainer(_nks) => unimplemented!(),
-            Self::NISoundContainer(_nis) => unimplemented!(),
-            _ => panic!("No preset detected."),
+            Self::NKSContainer(nks) => nks.decompressed_preset(),
+            Self::NISoundContainer(nis) => Ok(Self::sound_preset(nis)?.patch()?.data),
+            Self::Monolith(container) => container.preset()?.inner_preset(),
+            _ => Err(Error::Static("No preset detected")),
         }
     }
+
+    /// Browser metadata of the preset, see [`SoundInfo`].
+    pub fn sound_info(&self) -> Result<SoundInfo, Error> {
+        match self {
+            Self::NKSContainer(nks) => Ok(nks
+                .meta_info
+                .as_ref()
+                .ok_or(Error::Static("No SoundInfo"))?
+                .sound_info()?),
+            Self::NISoundContainer(nis) => Ok(nis
+                .find_item::<SoundInfoItem>(&ItemType::SoundInfoItem)
+                .ok_or(Error::Static("No SoundInfoItem"))??
+                .sound_info),
+            _ => Err(Error::Static("No SoundInfo")),
+        }
+    }
+
+    /// Replaces the raw preset data of this container. NKS containers must
+    /// be Kontakt 4.2 or later.
+    pub fn set_inner_preset(&mut self, data: &[u8]) -> Result<(), Error> {
+        match self {
+            Self::NKSContainer(nks) => nks.set_preset(data),
+            Self::NISoundContainer(nis) => {
+                let item = nis
+                    .find_mut(&ItemType::BNISoundPreset)
+                    .ok_or(Error::Static("No BNISoundPreset"))?;
+                let mut preset = BNISoundPreset::from(item.clone());
+                preset.set_patch_data(data)?;
+                *item = preset.item().clone();
+                Ok(())
+            }
+            _ => Err(Error::Static("No preset detected")),
+        }
+    }
+
+    /// Writes NKS and NISound containers.
+    pub fn write<W: WriteBytesExt>(&self, writer: W) -> Result<(), Error> {
+        match self {
+            Self::NKSContainer(nks) => nks.write(writer),
+            Self::NISoundContainer(nis) => nis.write(writer),
+            _ => Err(Error::Static("Writing is not supported for this file type")),
+        }
+    }
+
+    fn sound_preset(nis: &ItemContainer) -> Result<BNISoundPreset, Error> {
+        nis.find(&ItemType::BNISoundPreset)
+            .map(|item| BNISoundPreset::from(item.clone()))
+            .ok_or(Error::Static("No BNISoundPreset"))
+    }
 }
@@ -1,15 +1,19 @@
 use std::io::Cursor;
 
-use crate::{read_bytes::ReadBytesExt, Error, NIFileError};
+use crate::{read_bytes::ReadBytesExt, write_bytes::WriteBytesExt, Error, NIFileError};
 
 use super::{ItemData, ItemHeader, ItemType};
 
 /// NISound documents are made up of nested [`Item`]s.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItemContainer {
     pub header: ItemHeader,
     pub data: ItemData,
     pub children: Vec<ItemContainer>,
+    /// Sibling index stored by the parent, 0 for the root. SoundInfoItems use
+    /// 1001 so they are read last.
+    pub index: u32,
 }
 
 impl ItemContainer {
@@ -22,9 +26,34 @@ impl ItemContainer {
             header,
             data: ItemData::read(&mut chunk_data)?,
             children: ItemContainer::read_children(&mut chunk_data)?,
+            index: 0,
         })
     }
 
+    /// Writes the item and its children. Lengths are updated from the data.
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        let mut body = Vec::new();
+        self.data.write(&mut body)?;
+
+        body.write_u32_le(1)?;
+        body.write_u32_le(self.children.len() as u32)?;
+        for child in &self.children {
+            let mut domain_id = child.data.header.domain_id;
+            domain_id.reverse();
+
+            body.write_u32_le(child.index)?;
+            body.write_bytes(&domain_id)?;
+            body.write_u32_le(child.data.header.item_id)?;
+            child.write(&mut body)?;
+        }
+
+        let mut header = self.header.clone();
+        header.length = body.len() as u64 + 40;
+        header.write(&mut writer)?;
+        writer.write_bytes(&body)?;
+        Ok(())
+    }
+
     pub fn first_child(&self) -> Option<&ItemContainer> {
         self.children.get(0)
     }
@@ -48,6 +77,16 @@ impl ItemContainer {
         None
     }
 
+    /// Returns the first instance of Item by ItemID within child Items.
+    pub fn find_mut(&mut self, kind: &ItemType) -> Option<&mut ItemContainer> {
+        if &self.data.header.item_type() == kind {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|item| item.find_mut(kind))
+    }
+
     /// Returns the first instance of Item by ItemID within child Items.
     pub fn find_data(&self, kind: &ItemType) -> Option<&ItemData> {
         // Check this Item first
@@ -63,6 +102,16 @@ impl ItemContainer {
         None
     }
 
+    /// Returns the first instance of Item by ItemID within child Items.
+    pub fn find_data_mut(&mut self, kind: &ItemType) -> Option<&mut ItemData> {
+        if &self.data.header.item_type() == kind {
+            return Some(&mut self.data);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|item| item.find_data_mut(kind))
+    }
+
     /// Find the first Item of type ItemID in the document and return it
     pub fn find_item<'a, I>(&'a self, kind: &'a ItemType) -> Option<Result<I, Error>>
     where
@@ -81,7 +130,7 @@ impl ItemContainer {
         if num_children > 0 {
             for _ in 0..num_children {
                 // note: siblingIndex for soundinfoitem is 1001 to ensure it is last
-                let _index = buf.read_u32_le()?;
+                let index = buf.read_u32_le()?;
 
                 // childs domain id
                 let _domain_id = buf.read_u32_le()?;
@@ -96,7 +145,9 @@ impl ItemContainer {
 
                 let data = Cursor::new(buf.read_bytes(len)?);
 
-                children.push(ItemContainer::read(data)?);
+                let mut child = ItemContainer::read(data)?;
+                child.index = index;
+                children.push(child);
             }
         }
         Ok(children)
@@ -123,4 +174,32 @@ mod tests {
         assert_eq!(item.children.len(), 1);
         Ok(())
     }
+
+    #[test]
+    fn test_item_write() -> Result<(), Error> {
+        let data =
+            std::fs::read("tests/data/Containers/NIS/files/kontakt/5.8.1.43/5.8.1.43-ncw.nki")?;
+        let item = ItemContainer::read(Cursor::new(&data))?;
+
+        let mut written = Vec::new();
+        item.write(&mut written)?;
+        assert_eq!(written, data);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_item_serde() -> Result<(), Error> {
+        let data =
+            std::fs::read("tests/data/Containers/NIS/files/kontakt/5.8.1.43/5.8.1.43-ncw.nki")?;
+        let item = ItemContainer::read(Cursor::new(&data))?;
+
+        let json = serde_json::to_string(&item).unwrap();
+        let item: ItemContainer = serde_json::from_str(&json).unwrap();
+
+        let mut written = Vec::new();
+        item.write(&mut written)?;
+        assert_eq!(written, data);
+        Ok(())
+    }
 }
@@ -1,7 +1,8 @@
-use crate::{nis::ItemType, read_bytes::ReadBytesExt, NIFileError};
+use crate::{nis::ItemType, read_bytes::ReadBytesExt, write_bytes::WriteBytesExt, NIFileError};
 
 /// 20 bytes
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItemDataHeader {
     pub length: u64,
     pub domain_id: [u8; 4],
@@ -35,6 +36,17 @@ impl ItemDataHeader {
         })
     }
 
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), NIFileError> {
+        let mut domain_id = self.domain_id;
+        domain_id.reverse();
+
+        writer.write_u64_le(self.length)?;
+        writer.write_bytes(&domain_id)?;
+        writer.write_u32_le(self.item_id)?;
+        writer.write_u32_le(self.version)?;
+        Ok(())
+    }
+
     pub fn item_type(&self) -> ItemType {
         let domain_id = std::str::from_utf8(&self.domain_id).expect("Not UTF-8");
         ItemType::new(self.item_id, domain_id)
@@ -1,5 +1,6 @@
 /// ID representing an Item type.
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ItemType {
     AppSpecific,
     AudioSampleItem,
@@ -4,13 +4,15 @@ pub mod item_type;
 pub use item_data_header::*;
 pub use item_type::*;
 
-use crate::{read_bytes::ReadBytesExt, Error};
+use crate::{read_bytes::ReadBytesExt, write_bytes::WriteBytesExt, Error};
 use std::io::{Cursor, Read};
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItemData {
     pub header: ItemDataHeader,
     pub inner: Option<Box<ItemData>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub data: Vec<u8>,
 }
 
@@ -19,6 +21,14 @@ impl ItemData {
         self.inner.as_ref().map(Box::as_ref)
     }
 
+    /// Returns the first frame of type `kind`, starting with this frame.
+    pub fn find_mut(&mut self, kind: &ItemType) -> Option<&mut ItemData> {
+        if &self.header.item_type() == kind {
+            return Some(self);
+        }
+        self.inner.as_mut().and_then(|inner| inner.find_mut(kind))
+    }
+
     pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
         let header = ItemDataHeader::read(&mut reader)?;
         let length = header.length as usize - 20;
@@ -47,6 +57,22 @@ impl ItemData {
             }
         }
     }
+
+    /// Writes the frame and its inner frames. Lengths are updated from the
+    /// data.
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        let mut inner = Vec::new();
+        if let Some(frame) = &self.inner {
+            frame.write(&mut inner)?;
+        }
+
+        let mut header = self.header.clone();
+        header.length = (20 + inner.len() + self.data.len()) as u64;
+        header.write(&mut writer)?;
+        writer.write_bytes(&inner)?;
+        writer.write_bytes(&self.data)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::{read_bytes::ReadBytesExt, Error, NIFileError};
+use crate::{read_bytes::ReadBytesExt, write_bytes::WriteBytesExt, Error, NIFileError};
 
 /// The header chunk of an [`Item`](crate::nisound::Item).
 /// 40 bytes
@@ -12,15 +12,19 @@ use crate::{read_bytes::ReadBytesExt, Error, NIFileError};
 /// | 20     | 4      | ItemUuid* | itemUuid                     |  |                                          |
 ///
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItemHeader {
     /// Size in bytes of the entire [`Item`](super::Item).
     pub length: u64,
     /// Integer that resolves to a [`DomainID`](super::DomainID).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub magic: Vec<u8>, // (+0xC, uint, 'hsin')
     pub header_flags: u32, // (0x10, uint)
+    pub u_a: u32,
     /// # Boost-compatible GUID
     ///
     /// https://www.boost.org/doc/libs/1\_43\_0/libs/uuid/index.html
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub uuid: Vec<u8>, // (0x14, 16 bytes, randomly generated)
 }
 
@@ -30,7 +34,7 @@ impl ItemHeader {
         let version = reader.read_u32_le()?;
         let magic = reader.read_bytes(4)?;
         let header_flags = reader.read_u32_le()?;
-        let _unknown = reader.read_u32_le()?;
+        let u_a = reader.read_u32_le()?;
         let uuid = reader.read_bytes(16)?;
 
         if magic != b"hsin" {
@@ -48,10 +52,21 @@ impl ItemHeader {
             length,
             magic,
             header_flags,
+            u_a,
             uuid,
         })
     }
 
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_u64_le(self.length)?;
+        writer.write_u32_le(1)?;
+        writer.write_bytes(&self.magic)?;
+        writer.write_u32_le(self.header_flags)?;
+        writer.write_u32_le(self.u_a)?;
+        writer.write_bytes(&self.uuid)?;
+        Ok(())
+    }
+
     /// Returns the only known header flag.
     pub fn deferred_flag(&self) -> u32 {
         self.header_flags & 0x00000001
@@ -16,6 +16,7 @@ use std::fmt::Display;
 use crate::{read_bytes::ReadBytesExt, NIFileError};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Uuid {
     time_low: u32,
     time_mid: u16,
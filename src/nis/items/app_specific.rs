@@ -5,6 +5,7 @@ use crate::{
 
 use super::SubtreeItemItem;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AppSpecificItem(ItemContainer);
 
 impl AppSpecificItem {
@@ -5,6 +5,7 @@ use crate::{
 
 use super::{AppSpecificItem, PresetContainer};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepositoryRootContainer(pub ItemContainer);
 
 impl RepositoryRootContainer {
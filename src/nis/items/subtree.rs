@@ -3,6 +3,7 @@ use crate::{
     Error,
 };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubtreeItemItem(ItemContainer);
 
 impl SubtreeItemItem {
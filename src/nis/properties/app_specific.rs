@@ -12,6 +12,7 @@ use crate::{
 use super::preset::AuthoringApplication;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AppSpecificProperties {
     pub subtree_item: SubtreeItem,
     pub authoring_app: AuthoringApplication,
@@ -4,4 +4,5 @@
 //! This could have been a NIS object intended to migrate the NKS multi object; BBlock,
 //! but it never seems to have made it here.
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bank;
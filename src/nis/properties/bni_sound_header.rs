@@ -9,6 +9,7 @@ use crate::{
 
 /// Kontakt header
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BNISoundHeader(pub BPatchHeaderV42);
 
 impl BNISoundHeader {
@@ -10,6 +10,7 @@ use super::preset::Preset;
 /// Wraps a Preset as an NISoundPreset so extra header and
 /// soundinfoitem data can be included as children of the Preset.
 /// Only used in Kontakt (so far?).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BNISoundPresetProperties {
     pub preset: Preset,
 }
@@ -10,6 +10,7 @@ use super::subtree_item::SubtreeItem;
 
 /// A container for compressed presets.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EncryptionItem {
     pub subtree: SubtreeItem,
     pub is_encrypted: bool,
@@ -31,7 +31,12 @@ pub mod module_bank;
 pub mod picture_item;
 pub mod preset_container;
 pub mod resources;
-pub mod sound_info_item;
+
+mod sound_info;
+pub use sound_info::SoundInfo;
+
+mod sound_info_item;
+pub use sound_info_item::SoundInfoItem;
 
 mod subtree_item;
 pub use subtree_item::SubtreeItem;
@@ -22,6 +22,7 @@ use crate::nis::{ItemData, ItemType};
 use crate::read_bytes::ReadBytesExt;
 use crate::Error;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Preset {
     pub is_factory_preset: bool,
     pub authoring_app: AuthoringApplication,
@@ -30,6 +31,7 @@ pub struct Preset {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 pub enum AuthoringApplication {
     GuitarRig,
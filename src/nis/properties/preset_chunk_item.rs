@@ -8,7 +8,10 @@ use crate::{
 
 /// Typically contains the binary chunk for the inner NISound document.
 #[derive(Debug, Clone)]
-pub struct PresetChunkItemProperties(pub Vec<u8>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PresetChunkItemProperties(
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))] pub Vec<u8>,
+);
 
 impl std::convert::TryFrom<&ItemData> for PresetChunkItemProperties {
     type Error = NIFileError;
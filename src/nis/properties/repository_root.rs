@@ -8,6 +8,7 @@ use crate::{
 
 /// Usually the top-level [`Item`][crate::nisound::Item] of a repository. Contains NISound version information.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepositoryRoot {
     pub nisound_version: RepositoryVersion,
     /// Found values: 0
@@ -18,6 +19,7 @@ pub struct RepositoryRoot {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepositoryVersion {
     pub major: u32,
     pub minor: u32,
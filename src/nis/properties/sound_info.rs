@@ -14,3 +14,114 @@
 // sound-info-load-time
 // sound-info-num-inputs
 // sound-info-num-outputs
+
+use std::collections::BTreeMap;
+
+use crate::{Error, NIFileError};
+
+/// Browser metadata of a preset, stored as an XML document in the footer of
+/// NKS (Kontakt 4.2) presets and as a [`SoundInfoItem`](super::SoundInfoItem)
+/// in NIS presets.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoundInfo {
+    /// `400` for XML documents, `2.1` for NIS items.
+    pub version: String,
+    pub name: String,
+    pub author: String,
+    pub vendor: String,
+    pub comment: String,
+    /// Bank hierarchy, e.g. `Kontakt / 07 Synth / Lead`.
+    pub bank_chain: Vec<String>,
+    /// Tags such as `Synth Lead` or `KontaktInstrument`.
+    pub attributes: Vec<String>,
+    /// Only found in XML documents. NIS items store no category list, the
+    /// category tags are part of `attributes`.
+    pub categories: Vec<String>,
+    pub modes: Vec<String>,
+    /// Other properties, e.g. `\@verl` in NIS items or `tempo` in XML
+    /// documents.
+    pub properties: BTreeMap<String, String>,
+}
+
+impl SoundInfo {
+    /// Parses a `<soundinfo>` XML document.
+    pub fn from_xml(xml: &str) -> Result<Self, Error> {
+        let doc = roxmltree::Document::parse(xml)
+            .map_err(|e| NIFileError::Generic(format!("Invalid SoundInfo XML: {e}")))?;
+        let root = doc.root_element();
+        if !root.has_tag_name("soundinfo") {
+            return Err(NIFileError::Generic(format!(
+                "Expected <soundinfo>, got <{}>",
+                root.tag_name().name()
+            )));
+        }
+
+        let mut info = SoundInfo {
+            version: root.attribute("version").unwrap_or_default().to_string(),
+            ..Default::default()
+        };
+
+        for node in root.children().filter(|n| n.is_element()) {
+            match node.tag_name().name() {
+                "properties" => {
+                    for property in node.children().filter(|n| n.is_element()) {
+                        let value = property.text().unwrap_or_default().to_string();
+                        match property.tag_name().name() {
+                            "name" => info.name = value,
+                            "author" => info.author = value,
+                            "vendor" => info.vendor = value,
+                            "comment" => info.comment = value,
+                            key => {
+                                info.properties.insert(key.to_string(), value);
+                            }
+                        }
+                    }
+                }
+                "banks" => info.bank_chain = values(node),
+                "attributes" => info.attributes = values(node),
+                "categories" => info.categories = values(node),
+                "modes" => info.modes = values(node),
+                _ => {}
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+/// Text of each child element, or of its `<value>` element.
+fn values(node: roxmltree::Node) -> Vec<String> {
+    node.children()
+        .filter(|n| n.is_element())
+        .map(|n| {
+            n.children()
+                .find(|c| c.has_tag_name("value"))
+                .unwrap_or(n)
+                .text()
+                .unwrap_or_default()
+                .trim()
+                .to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sound_info_from_xml() -> Result<(), Error> {
+        let data = std::fs::read("tests/data/Containers/NKS/KontaktV2/NKSv2-NKG-Kon3.nkg")?;
+        let start = data.windows(5).position(|w| w == b"<?xml").unwrap();
+        let end = data.windows(12).position(|w| w == b"</soundinfo>").unwrap() + 12;
+        let info = SoundInfo::from_xml(std::str::from_utf8(&data[start..end]).unwrap())?;
+
+        assert_eq!(info.version, "321");
+        assert_eq!(info.name, "Low Whistle");
+        assert_eq!(info.vendor, "Native Instruments");
+        assert_eq!(info.bank_chain, vec!["Kontakt 4 World"]);
+        assert_eq!(info.attributes, vec!["Flute", "KontaktGroup", "Whistle"]);
+        Ok(())
+    }
+}
@@ -17,3 +17,146 @@
 // - name
 // - vendor
 // - author
+
+use std::io::Cursor;
+
+use crate::{
+    nis::{ItemData, ItemType},
+    read_bytes::ReadBytesExt,
+    Error, NIFileError,
+};
+
+use super::sound_info::SoundInfo;
+
+/// Browser metadata of a NIS preset.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoundInfoItem {
+    /// Found 1.
+    pub version: u32,
+    pub sound_info: SoundInfo,
+    /// Found 0.
+    pub u_a: u32,
+    pub icon: i32,
+    /// -1 if not set.
+    pub tempo: i32,
+    pub cpu_usage: i32,
+    pub mem_usage: i32,
+    pub load_time: i32,
+    pub num_inputs: i32,
+    pub num_outputs: i32,
+    /// 1 for instruments, 2 for effects. Also stored as the
+    /// `\@devicetypeflags` property.
+    pub device_type_flags: i32,
+    /// Found 1.
+    pub u_b: i32,
+}
+
+impl SoundInfoItem {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        let version = reader.read_u32_le()?;
+        let major_version = reader.read_u32_le()?;
+        let minor_version = reader.read_u32_le()?;
+        let u_a = reader.read_u32_le()?;
+
+        let name = reader.read_widestring_utf16()?;
+        let author = reader.read_widestring_utf16()?;
+        let vendor = reader.read_widestring_utf16()?;
+        let comment = reader.read_widestring_utf16()?;
+
+        let icon = reader.read_i32_le()?;
+        let tempo = reader.read_i32_le()?;
+        let cpu_usage = reader.read_i32_le()?;
+        let mem_usage = reader.read_i32_le()?;
+        let load_time = reader.read_i32_le()?;
+        let num_inputs = reader.read_i32_le()?;
+        let num_outputs = reader.read_i32_le()?;
+        let device_type_flags = reader.read_i32_le()?;
+        let u_b = reader.read_i32_le()?;
+
+        let bank_chain = read_string_list(&mut reader)?;
+        let attributes = read_string_list(&mut reader)?;
+        let modes = read_string_list(&mut reader)?;
+
+        let mut properties = std::collections::BTreeMap::new();
+        for _ in 0..reader.read_u32_le()? {
+            let key = reader.read_widestring_utf16()?;
+            let value = reader.read_widestring_utf16()?;
+            properties.insert(key, value);
+        }
+
+        Ok(Self {
+            version,
+            sound_info: SoundInfo {
+                version: format!("{major_version}.{minor_version}"),
+                name,
+                author,
+                vendor,
+                comment,
+                bank_chain,
+                attributes,
+                // All item data is read above, there is no category list.
+                categories: Vec::new(),
+                modes,
+                properties,
+            },
+            u_a,
+            icon,
+            tempo,
+            cpu_usage,
+            mem_usage,
+            load_time,
+            num_inputs,
+            num_outputs,
+            device_type_flags,
+            u_b,
+        })
+    }
+}
+
+fn read_string_list<R: ReadBytesExt>(mut reader: R) -> Result<Vec<String>, Error> {
+    let count = reader.read_u32_le()?;
+    let mut list = Vec::new();
+    for _ in 0..count {
+        list.push(reader.read_widestring_utf16()?);
+    }
+    Ok(list)
+}
+
+impl std::convert::TryFrom<&ItemData> for SoundInfoItem {
+    type Error = NIFileError;
+
+    fn try_from(frame: &ItemData) -> Result<Self, NIFileError> {
+        debug_assert_eq!(frame.header.item_type(), ItemType::SoundInfoItem);
+        Self::read(Cursor::new(&frame.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::nis::ItemContainer;
+
+    use super::*;
+
+    #[test]
+    fn test_sound_info_item_read() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NIS/files/fm8/1.2.0.1010/003-fm8.nfm8")?;
+        let item: SoundInfoItem = ItemContainer::read(file)?
+            .find_item(&ItemType::SoundInfoItem)
+            .unwrap()?;
+
+        let info = &item.sound_info;
+        assert_eq!(info.version, "2.1");
+        assert_eq!(info.name, "Lasergun");
+        assert_eq!(info.author, "Summa");
+        assert_eq!(info.vendor, "Native Instruments");
+        assert_eq!(info.comment, "Modwheel -> Filter");
+        assert_eq!(info.bank_chain, vec!["FM8", "FM8 Factory"]);
+        assert_eq!(info.attributes.len(), 6);
+        assert_eq!(info.properties["\\@verl"], "1.7.4");
+        assert_eq!(item.device_type_flags, 1);
+        Ok(())
+    }
+}
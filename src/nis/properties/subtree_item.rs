@@ -48,10 +48,13 @@ use std::io::Cursor;
 
 use crate::nis::{ItemContainer, ItemData, ItemType};
 use crate::read_bytes::ReadBytesExt;
+use crate::write_bytes::WriteBytesExt;
 use crate::Error;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubtreeItem {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub inner_data: Vec<u8>,
 }
 
@@ -99,6 +102,18 @@ impl SubtreeItem {
         Ok(SubtreeItem { inner_data })
     }
 
+    /// Writes the properties with the inner data compressed.
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        let compressed = crate::utils::fastlz_compress(&self.inner_data)?;
+
+        writer.write_u32_le(1)?;
+        writer.write_bool(true)?;
+        writer.write_u32_le(self.inner_data.len() as u32)?;
+        writer.write_u32_le(compressed.len() as u32)?;
+        writer.write_bytes(&compressed)?;
+        Ok(())
+    }
+
     pub fn item(&self) -> Result<ItemContainer, Error> {
         let container = ItemContainer::read(Cursor::new(&self.inner_data))?;
         Ok(container)
@@ -128,4 +143,16 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_subtree() -> Result<(), Error> {
+        let data = File::open("tests/data/Containers/NIS/objects/SubtreeItem/SubtreeItem-000")?;
+        let subtree = SubtreeItem::read(data)?;
+
+        let mut written = Vec::new();
+        subtree.write(&mut written)?;
+        let read = SubtreeItem::read(Cursor::new(written))?;
+        assert_eq!(read.inner_data, subtree.inner_data);
+        Ok(())
+    }
 }
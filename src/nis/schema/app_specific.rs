@@ -1,6 +1,7 @@
 use crate::nis::ItemContainer;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AppSpecific(ItemContainer);
 
 impl AppSpecific {
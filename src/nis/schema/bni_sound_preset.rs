@@ -1,12 +1,19 @@
+use flate2::Crc;
+
 use crate::{
     kontakt::{objects::BPatchHeaderV42, KontaktPatch},
-    nis::{BNISoundHeader, BNISoundPresetProperties, EncryptionItem, ItemContainer, ItemType},
+    nis::{
+        BNISoundHeader, BNISoundPresetProperties, EncryptionItem, ItemContainer, ItemType,
+        SoundInfoItem, SubtreeItem,
+    },
+    write_bytes::WriteBytesExt,
     Error,
 };
 
 use super::PresetChunkItem;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BNISoundPreset(ItemContainer);
 
 impl BNISoundPreset {
@@ -32,15 +39,69 @@ impl BNISoundPreset {
         })
     }
 
+    /// Replaces the raw internal preset data, see [`KontaktPatch::data`].
+    pub fn set_patch_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        if self.encryption_item()?.is_encrypted {
+            return Err(Error::Static("Preset is encrypted"));
+        }
+
+        let subtree_frame = self
+            .0
+            .find_data_mut(&ItemType::EncryptionItem)
+            .and_then(|frame| frame.find_mut(&ItemType::SubtreeItem))
+            .ok_or(Error::Static("No SubtreeItem"))?;
+        let mut preset_chunks = SubtreeItem::try_from(&*subtree_frame)?.item()?;
+
+        let chunk_frame = preset_chunks
+            .find_data_mut(&ItemType::PresetChunkItem)
+            .ok_or(Error::Static("No PresetChunkItem"))?;
+        // keep the version and auth checksum, replace the binary chunk
+        let mut chunk_data = chunk_frame
+            .data
+            .get(..8)
+            .ok_or(Error::Static("Invalid PresetChunkItem"))?
+            .to_vec();
+        chunk_data.write_u32_le(1)?;
+        chunk_data.write_u64_le(data.len() as u64)?;
+        chunk_data.write_bytes(data)?;
+        chunk_frame.data = chunk_data;
+
+        let mut inner_data = Vec::new();
+        preset_chunks.write(&mut inner_data)?;
+        subtree_frame.data.clear();
+        SubtreeItem { inner_data }.write(&mut subtree_frame.data)?;
+
+        // the header checksum covers the uncompressed chunk
+        let mut crc = Crc::new();
+        crc.update(data);
+        let header_frame = self
+            .0
+            .find_data_mut(&ItemType::BNISoundHeader)
+            .ok_or(Error::Static("No BNISoundHeader"))?;
+        if header_frame.data.len() < 222 {
+            return Err(Error::Static("Invalid BNISoundHeader"));
+        }
+        BPatchHeaderV42::update_stored(&mut header_frame.data[8..], crc.sum(), 0);
+        Ok(())
+    }
+
     pub fn properties(&self) -> Result<BNISoundPresetProperties, Error> {
         (&self.0.data).try_into()
     }
 
-    // pub fn sound_info_item(&self) -> Result<SoundInfoItem, Error> {}
+    pub fn sound_info_item(&self) -> Result<SoundInfoItem, Error> {
+        self.0
+            .find_item::<SoundInfoItem>(&ItemType::SoundInfoItem)
+            .ok_or(Error::Static("No SoundInfoItem"))?
+    }
 
     pub fn children(&self) -> &Vec<ItemContainer> {
         &self.0.children
     }
+
+    pub fn item(&self) -> &ItemContainer {
+        &self.0
+    }
 }
 
 impl From<ItemContainer> for BNISoundPreset {
@@ -2,6 +2,7 @@ use crate::nis::{ItemContainer, ItemType};
 
 use super::{AppSpecific, BNISoundPreset, Preset, PresetChunkItem, Repository};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NISObject {
     AppSpecific(AppSpecific),
     Repository(Repository),
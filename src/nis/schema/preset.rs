@@ -1,6 +1,10 @@
-use crate::nis::ItemContainer;
+use crate::{
+    nis::{ItemContainer, ItemType, SoundInfoItem},
+    Error,
+};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Preset(ItemContainer);
 
 impl Preset {
@@ -8,7 +12,11 @@ impl Preset {
     //     unimplemented!()
     // }
 
-    // pub fn sound_info_item(&self) -> Result<SoundInfoItem, Error> {}
+    pub fn sound_info_item(&self) -> Result<SoundInfoItem, Error> {
+        self.0
+            .find_item::<SoundInfoItem>(&ItemType::SoundInfoItem)
+            .ok_or(Error::Static("No SoundInfoItem"))?
+    }
 
     pub fn children(&self) -> &Vec<ItemContainer> {
         &self.0.children
@@ -4,6 +4,7 @@ use crate::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PresetChunkItem(ItemContainer);
 
 impl PresetChunkItem {
@@ -6,6 +6,7 @@ use crate::{
 use super::nis_object::NISObject;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Repository(ItemContainer);
 
 impl Repository {
@@ -67,6 +67,7 @@ impl ItemContainer {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BNISoundPresetContainer(ItemContainer);
 
 impl BNISoundPresetContainer {
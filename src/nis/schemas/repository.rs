@@ -12,9 +12,11 @@ use std::convert::TryFrom;
 
 /// High level wrapper for NISound containers
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Repository(ItemContainer);
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RepositoryType {
     KontaktPreset,
     AppSpecific,
@@ -1,23 +1,91 @@
+use crate::{read_bytes::ReadBytesExt, string_reader::StringReader, Error, NIFileError};
+
+pub(crate) const NKR_DIRECTORY_MAGIC: u32 = 0x5E70AC54;
+pub(crate) const NKR_FILE_MAGIC: u32 = 0x2AE905FA;
+
+/// A directory of the resource tree.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NKRItem {
-    header: NKRHeader,
-    chunk: NKRChunk,
+    pub header: NKRHeader,
+    pub chunks: Vec<NKRChunk>,
 }
 
+/// Header of a directory, or of a file stored in the container.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NKRHeader {
-    magic: u32,
-    version: u16,
-    a: u32,
-    b: u32,
-    num_items: u32,
-    c: u32,
+    pub magic: u32,
+    /// Found 0x110 (Kontakt 2), 0x111
+    pub version: u16,
+    /// Found 0
+    pub a: u32,
+    /// Found 0xFF
+    pub b: u32,
+    /// Child count for directories, size in bytes for files.
+    pub num_items: u32,
+    /// Found 0 (files), 1 (directories)
+    pub c: u32,
 }
 
+/// An entry of a directory.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NKRChunk {
-    length: u16,
-    ref_ptr: u32,
-    chunk_type: u16,
-    data: Vec<u8>,
+    /// Size of the entry in bytes, including this field.
+    pub length: u16,
+    /// Offset of the item from the start of the container.
+    pub ref_ptr: u32,
+    /// 1: directory, 2: sample, 3: instrument, 4: file
+    pub chunk_type: u16,
+    pub name: String,
+}
+
+impl NKRItem {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        let header = NKRHeader::read(&mut reader)?;
+        if header.magic != NKR_DIRECTORY_MAGIC {
+            return Err(NIFileError::Generic(format!(
+                "Invalid NKR directory magic: 0x{:X}",
+                header.magic
+            )));
+        }
+
+        let mut chunks = Vec::new();
+        for _ in 0..header.num_items {
+            chunks.push(NKRChunk::read(&mut reader)?);
+        }
+        Ok(Self { header, chunks })
+    }
+}
+
+impl NKRHeader {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        Ok(Self {
+            magic: reader.read_u32_le()?,
+            version: reader.read_u16_le()?,
+            a: reader.read_u32_le()?,
+            b: reader.read_u32_le()?,
+            num_items: reader.read_u32_le()?,
+            c: reader.read_u32_le()?,
+        })
+    }
+}
+
+impl NKRChunk {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        let length = reader.read_u16_le()?;
+        let ref_ptr = reader.read_u32_le()?;
+        let chunk_type = reader.read_u16_le()?;
+
+        let name = reader.read_bytes((length as usize).saturating_sub(8))?;
+        let name = std::io::Cursor::new(name).read_nullterminated_utf16()?;
+
+        Ok(Self {
+            length,
+            ref_ptr,
+            chunk_type,
+            name,
+        })
+    }
 }
@@ -1 +1,177 @@
 pub mod item;
+
+use std::{collections::HashSet, io::SeekFrom};
+
+use crate::{kontakt::objects::BFileName, read_bytes::ReadBytesExt, Error, NIFileError};
+
+use item::{NKRHeader, NKRItem, NKR_FILE_MAGIC};
+
+/// Kontakt resource container (nkr). Stores the scripts, pictures, impulse
+/// responses and other files of a library as a tree of directories.
+///
+/// Kontakt 2 monoliths use the same tree, with samples stored after it.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NKRContainer {
+    pub files: Vec<NKRFile>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NKRFile {
+    /// Directories and file name joined with "/", e.g. "Resources/info/library.json"
+    pub path: String,
+    /// `None` for files stored outside the tree, e.g. samples in a monolith.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64::option"))]
+    pub data: Option<Vec<u8>>,
+}
+
+impl NKRContainer {
+    pub fn read<R: ReadBytesExt>(mut reader: R) -> Result<Self, Error> {
+        let mut files = Vec::new();
+        read_directory(&mut reader, 0, "", &mut HashSet::new(), &mut files)?;
+        Ok(Self { files })
+    }
+
+    pub fn find(&self, path: &str) -> Option<&NKRFile> {
+        self.files
+            .iter()
+            .find(|file| file.path.eq_ignore_ascii_case(path))
+    }
+
+    /// The stored file for the wallpaper of an instrument, see
+    /// [`InstrumentMetadata::wallpaper`](crate::kontakt::instrument::InstrumentMetadata::wallpaper).
+    /// Matched on the trailing directories of the path, then on the file name.
+    pub fn wallpaper(&self, path: &BFileName) -> Option<&NKRFile> {
+        let path = path.to_posix().to_lowercase();
+        let stored = || self.files.iter().filter(|file| file.data.is_some());
+        stored()
+            .find(|file| {
+                let name = file.path.to_lowercase();
+                path == name || path.ends_with(&format!("/{name}"))
+            })
+            .or_else(|| {
+                let name = path.rsplit('/').next().unwrap_or_default();
+                stored().find(|file| file.file_name().eq_ignore_ascii_case(name))
+            })
+    }
+}
+
+impl NKRFile {
+    pub fn file_name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or_default()
+    }
+
+    /// The name of the directory containing the file.
+    pub fn directory(&self) -> &str {
+        self.path.rsplit('/').nth(1).unwrap_or_default()
+    }
+}
+
+fn read_directory<R: ReadBytesExt>(
+    reader: &mut R,
+    offset: u64,
+    path: &str,
+    visited: &mut HashSet<u64>,
+    files: &mut Vec<NKRFile>,
+) -> Result<(), Error> {
+    if !visited.insert(offset) {
+        return Err(NIFileError::Generic(format!(
+            "NKR directory at 0x{offset:X} is referenced twice"
+        )));
+    }
+    reader.seek(SeekFrom::Start(offset))?;
+    let directory = NKRItem::read(&mut *reader)?;
+
+    for chunk in directory.chunks {
+        let path = match path {
+            "" => chunk.name,
+            path => format!("{path}/{}", chunk.name),
+        };
+        match chunk.chunk_type {
+            1 => read_directory(reader, chunk.ref_ptr as u64, &path, visited, files)?,
+            4 => {
+                reader.seek(SeekFrom::Start(chunk.ref_ptr as u64))?;
+                let header = NKRHeader::read(&mut *reader)?;
+                if header.magic != NKR_FILE_MAGIC {
+                    return Err(NIFileError::Generic(format!(
+                        "Invalid NKR file magic: 0x{:X}",
+                        header.magic
+                    )));
+                }
+                files.push(NKRFile {
+                    path,
+                    data: Some(reader.read_bytes(header.num_items as usize)?),
+                });
+            }
+            _ => files.push(NKRFile { path, data: None }),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, path::Path};
+
+    use super::*;
+
+    #[test]
+    fn test_nkr_container() -> Result<(), Error> {
+        let file = File::open("tests/data/Containers/NKR/000.nkr")?;
+        let nkr = NKRContainer::read(file)?;
+
+        assert_eq!(nkr.files.len(), 1);
+        let json = nkr.find("Resources/info/library.json").unwrap();
+        assert!(String::from_utf8_lossy(json.data.as_ref().unwrap())
+            .contains("\"kontaktMinVersion\": \"7.0.2\""));
+        Ok(())
+    }
+
+    #[test]
+    fn test_nkr_directory_loop() {
+        // A directory "a" that points back to itself.
+        let mut data = Vec::new();
+        data.extend(item::NKR_DIRECTORY_MAGIC.to_le_bytes());
+        data.extend(1u16.to_le_bytes());
+        data.extend([0u32, 0, 1, 0].iter().flat_map(|v| v.to_le_bytes()));
+        data.extend(12u16.to_le_bytes());
+        data.extend(0u32.to_le_bytes());
+        data.extend(1u16.to_le_bytes());
+        data.extend([b'a', 0, 0, 0]);
+
+        let err = NKRContainer::read(std::io::Cursor::new(data)).unwrap_err();
+        assert!(err.to_string().contains("referenced twice"));
+    }
+
+    #[test]
+    fn test_nkr_wallpaper() {
+        let file = |path: &str| NKRFile {
+            path: path.into(),
+            data: Some(Vec::new()),
+        };
+        let nkr = NKRContainer {
+            files: vec![
+                file("Resources/pictures/wallpaper.png"),
+                file("Samples/Wallpaper/kor_wp_01.tga"),
+                file("Other/Wallpaper/kor_wp_01.tga"),
+            ],
+        };
+        let path = |path: &str| BFileName::from_path(Path::new(path), Path::new(""));
+        assert_eq!(
+            nkr.wallpaper(&path("Samples/Wallpaper/kor_wp_01.tga"))
+                .unwrap()
+                .path,
+            "Samples/Wallpaper/kor_wp_01.tga"
+        );
+        assert_eq!(
+            nkr.wallpaper(&path("../Resources/pictures/Wallpaper.png"))
+                .unwrap()
+                .path,
+            "Resources/pictures/wallpaper.png"
+        );
+        assert!(nkr
+            .wallpaper(&path("Resources/pictures/knob.png"))
+            .is_none());
+    }
+}
@@ -1,6 +1,6 @@
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Read, SeekFrom};
 
-use flate2::read::ZlibDecoder;
+use flate2::{read::ZlibDecoder, Crc};
 
 use crate::{
     kontakt::{
@@ -9,16 +9,27 @@ use crate::{
         KontaktPatch,
     },
     read_bytes::ReadBytesExt,
+    write_bytes::WriteBytesExt,
     Error,
 };
 
 use super::error::NKSError;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NKSContainer {
     pub header: BPatchHeader,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
     pub compressed_data: Vec<u8>,
     pub meta_info: Option<BPatchMetaInfoHeader>,
+    magic: u32,
+    /// The compressed length, or the offset of the zlib data for Kontakt 1.
+    length_field: u32,
+    /// The header as stored, from the header version.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
+    header_data: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_base64"))]
+    footer_data: Vec<u8>,
 }
 
 impl NKSContainer {
@@ -36,6 +47,10 @@ impl NKSContainer {
         let compressed_length = reader.read_u32_le()? as usize;
         let header = BPatchHeader::read_le(&mut reader)?;
 
+        let header_end = reader.stream_position()?;
+        reader.seek(SeekFrom::Start(8))?;
+        let header_data = reader.read_bytes(header_end as usize - 8)?;
+
         let compressed_data = match header {
             BPatchHeader::BPatchHeaderV1(_) => reader.read_all()?,
             BPatchHeader::BPatchHeaderV2(ref h) => match h.is_monolith {
@@ -58,12 +73,12 @@ impl NKSContainer {
 
         // std::fs::write("compressed", &compressed_data)?;
 
-        let footer_raw = reader.read_all()?;
+        let footer_data = reader.read_all()?;
         let meta_info = match header {
             BPatchHeader::BPatchHeaderV1(_) => None,
             BPatchHeader::BPatchHeaderV2(_) => None,
             BPatchHeader::BPatchHeaderV42(_) => {
-                Some(BPatchMetaInfoHeader::read(&mut Cursor::new(footer_raw))?)
+                Some(BPatchMetaInfoHeader::read(&mut Cursor::new(&footer_data))?)
             }
         };
 
@@ -75,9 +90,49 @@ impl NKSContainer {
             header,
             compressed_data,
             meta_info,
+            magic,
+            length_field: compressed_length as u32,
+            header_data,
+            footer_data,
         })
     }
 
+    /// Writes the container. Unchanged containers are written as they were
+    /// read.
+    pub fn write<W: WriteBytesExt>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_u32_le(self.magic)?;
+        writer.write_u32_le(self.length_field)?;
+        writer.write_bytes(&self.header_data)?;
+        writer.write_bytes(&self.compressed_data)?;
+        writer.write_bytes(&self.footer_data)?;
+        Ok(())
+    }
+
+    /// Compresses and stores raw internal preset data. Only Kontakt 4.2 and
+    /// later presets are supported.
+    ///
+    /// The crc32 and length in the header are updated, the md5 checksum is
+    /// not: it is not the hash of any part of the file or of the decompressed
+    /// data, so it can't be recalculated. Kontakt might reject a preset with a
+    /// stale checksum, this is not tested.
+    pub fn set_preset(&mut self, data: &[u8]) -> Result<(), Error> {
+        let BPatchHeader::BPatchHeaderV42(header) = &mut self.header else {
+            return Err(Error::Static("Only Kontakt 4.2+ presets can be written"));
+        };
+
+        let compressed = crate::utils::fastlz_compress(data)?;
+
+        let mut crc = Crc::new();
+        crc.update(&compressed);
+
+        header.crc32_fast = crc.sum().to_be_bytes();
+        header.decompressed_length = data.len() as u32;
+        BPatchHeaderV42::update_stored(&mut self.header_data, crc.sum(), data.len() as u32);
+        self.length_field = compressed.len() as u32;
+        self.compressed_data = compressed;
+        Ok(())
+    }
+
     /// Decompress raw internal preset data
     pub fn decompressed_preset(&self) -> Result<Vec<u8>, Error> {
         assert!(self.compressed_data.len() > 0, "no compressed data");
@@ -187,4 +242,23 @@ mod tests {
         // let _preset = nks.preset().unwrap();
         Ok(())
     }
+
+    #[test]
+    fn test_nksfile_write_v42() -> Result<(), Error> {
+        let data = std::fs::read("tests/data/Containers/NKS/KontaktV42/4.2.4.5316-000.nki")?;
+        let mut nks = NKSContainer::read(Cursor::new(&data))?;
+
+        let mut written = Vec::new();
+        nks.write(&mut written)?;
+        assert_eq!(written, data);
+
+        let preset = nks.decompressed_preset()?;
+        nks.set_preset(&preset)?;
+        let mut written = Vec::new();
+        nks.write(&mut written)?;
+
+        let nks = NKSContainer::read(Cursor::new(written))?;
+        assert_eq!(nks.decompressed_preset()?, preset);
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 /// Supported NKS filetypes.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NKSFileType {
     NKSv1LE,
     NKSv1BE,
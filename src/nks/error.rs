@@ -13,4 +13,7 @@ pub enum NKSError {
 
     #[error("Decompression error: {0}")]
     Decompression(String),
+
+    #[error("SoundInfo error: {0}")]
+    SoundInfo(String),
 }
@@ -2,6 +2,18 @@ use std::path::PathBuf;
 
 use crate::NIFileError;
 
+/// Compresses data with fastlz, as stored in NKS containers and NIS
+/// subtree items.
+pub(crate) fn fastlz_compress(data: &[u8]) -> Result<Vec<u8>, NIFileError> {
+    // fastlz needs at least 5% more space for incompressible data
+    let mut compressed = vec![0_u8; (data.len() + data.len() / 20).max(66)];
+    let length = fastlz::compress(data, &mut compressed)
+        .map_err(|_| NIFileError::Generic("fastlz".into()))?
+        .len();
+    compressed.truncate(length);
+    Ok(compressed)
+}
+
 #[allow(dead_code)]
 pub(crate) fn get_test_files() -> Result<Vec<PathBuf>, NIFileError> {
     let path = "tests/data/files/**/*.*";
@@ -0,0 +1,104 @@
+use std::io::{self, Write};
+
+/// Extensions to io::Write for simplifying writing bytes.
+pub trait WriteBytesExt: Write {
+    fn write_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.write_all(bytes)
+    }
+
+    fn write_bool(&mut self, value: bool) -> io::Result<()> {
+        self.write_u8(value as u8)
+    }
+
+    fn write_u8(&mut self, value: u8) -> io::Result<()> {
+        self.write_all(&[value])
+    }
+
+    fn write_i8(&mut self, value: i8) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_u16_le(&mut self, value: u16) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_i16_le(&mut self, value: i16) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_u32_le(&mut self, value: u32) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_i32_le(&mut self, value: i32) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_f32_le(&mut self, value: f32) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_f64_le(&mut self, value: f64) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_u64_le(&mut self, value: u64) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    /// Write a u32 byte count followed by UTF-8 bytes.
+    fn write_sized_utf8(&mut self, value: &str) -> io::Result<()> {
+        self.write_u32_le(value.len() as u32)?;
+        self.write_all(value.as_bytes())
+    }
+
+    /// Write a sized UTF-8 string, or 0xFFFFFFFF for `None`.
+    fn write_optional_sized_utf8(&mut self, value: Option<&str>) -> io::Result<()> {
+        match value {
+            Some(value) => self.write_sized_utf8(value),
+            None => self.write_u32_le(0xFFFFFFFF),
+        }
+    }
+
+    /// Write a u32 character count followed by UTF-16LE characters.
+    fn write_widestring_utf16(&mut self, value: &str) -> io::Result<()> {
+        let chars: Vec<u16> = value.encode_utf16().collect();
+        self.write_u32_le(chars.len() as u32)?;
+        for c in chars {
+            self.write_all(&c.to_le_bytes())?;
+        }
+        Ok(())
+    }
+}
+impl<W: Write + ?Sized> WriteBytesExt for W {}
+
+#[cfg(test)]
+mod tests {
+    use super::WriteBytesExt;
+    use crate::read_bytes::ReadBytesExt;
+    use std::io;
+
+    #[test]
+    fn test_write_widestring_utf16() {
+        let mut bytes = Vec::new();
+        bytes.write_widestring_utf16("Samples").unwrap();
+        assert_eq!(bytes.len(), 4 + 7 * 2);
+
+        let mut cursor = io::Cursor::new(bytes);
+        assert_eq!(cursor.read_widestring_utf16().unwrap(), "Samples");
+    }
+
+    #[test]
+    fn test_write_optional_sized_utf8() {
+        let mut bytes = Vec::new();
+        bytes.write_optional_sized_utf8(Some("on init")).unwrap();
+        bytes.write_optional_sized_utf8(None).unwrap();
+
+        let mut cursor = io::Cursor::new(bytes);
+        assert_eq!(
+            cursor.read_optional_sized_utf8().unwrap().as_deref(),
+            Some("on init")
+        );
+        assert_eq!(cursor.read_optional_sized_utf8().unwrap(), None);
+    }
+}